@@ -465,6 +465,10 @@ where T: Into<Complex64> + Copy, U: Into<f64> {
 /// let res = i(1.2, 0);
 /// assert!((res.re - 1.39373).abs() < 1.0e-4 && res.im == 0.0);
 /// 
+/// // Integer orders use an exact factorial
+/// assert_eq!(i(0.0, 0).re, 1.0);
+/// assert_eq!(i(2.5, -2), i(2.5, 2));
+/// 
 /// let c = Complex64::new(-1.2, 0.5);
 /// let r2 = i(c, -1.6);
 /// assert!((r2.re - 0.549831).abs() < 1.0e-5 && (r2.im - -0.123202).abs() < 1.0e-5);
@@ -480,13 +484,25 @@ pub fn i<T, U>(x: T, order: U) -> Complex64
 where T: Into<Complex64>, U: Into<f64> + Copy {
     
     let n: f64 = order.into();
+    let integer: bool = n.fract() == 0.0;
+
+    // For integer orders, I is symmetric and the gamma function reduces to an exact factorial
+    if integer && n < 0.0 {
+        return i(x, -n);
+    }
 
     let x2: Complex64 = x.into() / 2.0;             // Halving x
     let mut k: f64 = 0.0;                           // Order counter
     let mut d1: f64 = 1.0;                          // First div
-    let mut d2: f64 = basic::gamma(n + 1.0);        // Second div
+    let mut d2: f64 = match integer {               // Second div
+        true => (1..=n as usize).fold(1.0, |res, val| res * val as f64),
+        false => basic::gamma(n + 1.0)
+    };
 
-    let mut term: Complex64 = x2.powf(n) / d2;      // The term at each step
+    // Power of x/2, exact for integer orders
+    let pow = |e: f64| -> Complex64 { if integer { x2.powi(e as i32) } else { x2.powf(e) } };
+
+    let mut term: Complex64 = pow(n) / d2;          // The term at each step
     let mut res: Complex64 = Complex64::default();  // The result of the operation
     
     // If the first term is already too small we exit directly
@@ -506,7 +522,7 @@ where T: Into<Complex64>, U: Into<f64> + Copy {
         k += 1.0;                       // Incrementing value
         d1 *= k;                        // Next value in the n! term
         d2 *= n + k;                    // Next value in the gamma(n+k+1) term
-        term = x2.powf(n + 2.0 * k) / (d1 * d2);
+        term = pow(n + 2.0 * k) / (d1 * d2);
     }
 
     res
@@ -12,8 +12,12 @@ pub mod bessel;
 
 pub mod distribution;
 
+pub mod orthogonal;
+
 pub mod polynomial;
 
+pub mod quadrature;
+
 pub mod series;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
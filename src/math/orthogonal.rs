@@ -0,0 +1,289 @@
+//!
+//! # Orthogonal polynomials
+//! 
+//! Evaluation of the classical [orthogonal polynomials](https://en.wikipedia.org/wiki/Orthogonal_polynomials)
+//! through their three-term recurrence relation:
+//! $$
+//! P_{k+1}(x) = (a_kx + b_k)P_k(x) - c_kP_{k-1}(x),~ P_{-1} = 0,~ P_0 = 1
+//! $$
+//! 
+//! Unlike the expanded monomial form, given by the `Poly` constructors, the recurrence does not suffer from the
+//! cancellation between large coefficients of alternating signs, and stays accurate at high degrees.
+//! 
+//! The available families are Legendre, Chebyshev of the four kinds, Jacobi, Gegenbauer, Laguerre and
+//! Hermite, in both the physicists' and probabilists' normalizations. The Zernike radial polynomials are
+//! evaluated through their relation to the Jacobi polynomials.
+//! 
+//! ```
+//! # use scilib::math::orthogonal::Family;
+//! # use scilib::math::polynomial::Poly;
+//! // T_n(cos(t)) = cos(nt), even at a high degree
+//! let t: f64 = 0.3;
+//! assert!((Family::ChebyshevT.compute(t.cos(), 200) - (200.0 * t).cos()).abs() < 1.0e-12);
+//! 
+//! // Same values as the expanded polynomials at low degree
+//! let p = Poly::jacobi(4, 0.5, 1.5);
+//! let f = Family::Jacobi { alpha: 0.5, beta: 1.5 };
+//! assert!((f.compute(0.3, 4) - p.compute(0.3)).abs() < 1.0e-12);
+//! ```
+//! 
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use super::polynomial::Poly;    // Expanded polynomials
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Families of orthogonal polynomials
+/// 
+/// Each family is defined by its three-term recurrence, in its usual normalization.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Family {
+    /// Legendre polynomials $P_n$, orthogonal on $[-1, 1]$ for the weight $1$
+    Legendre,
+    /// Chebyshev polynomials of the first kind $T_n$, for the weight $(1-x^2)^{-1/2}$
+    ChebyshevT,
+    /// Chebyshev polynomials of the second kind $U_n$, for the weight $(1-x^2)^{1/2}$
+    ChebyshevU,
+    /// Chebyshev polynomials of the third kind $V_n$, for the weight $\sqrt{(1+x)/(1-x)}$
+    ChebyshevV,
+    /// Chebyshev polynomials of the fourth kind $W_n$, for the weight $\sqrt{(1-x)/(1+x)}$
+    ChebyshevW,
+    /// Jacobi polynomials $P_n^{(\alpha,\beta)}$, for the weight $(1-x)^\alpha(1+x)^\beta$ with $\alpha, \beta > -1$
+    Jacobi { alpha: f64, beta: f64 },
+    /// Gegenbauer polynomials $C_n^{(\alpha)}$, for the weight $(1-x^2)^{\alpha-1/2}$ with $\alpha > -1/2$ and $\alpha \neq 0$
+    Gegenbauer { alpha: f64 },
+    /// Generalized Laguerre polynomials $L_n^{(\alpha)}$, orthogonal on $[0, \infty)$ for the weight $x^\alpha e^{-x}$ with $\alpha > -1$
+    Laguerre { alpha: f64 },
+    /// Physicists' Hermite polynomials $H_n$, orthogonal on $\mathbb{R}$ for the weight $e^{-x^2}$
+    Hermite,
+    /// Probabilists' Hermite polynomials $He_n$, orthogonal on $\mathbb{R}$ for the weight $e^{-x^2/2}$
+    HermiteProb
+}
+
+impl Family {
+
+    /// # Recurrence coefficients
+    /// 
+    /// ## Definition
+    /// Gives the coefficients of the three-term recurrence of the family:
+    /// $$
+    /// P_{k+1}(x) = (a_kx + b_k)P_k(x) - c_kP_{k-1}(x)
+    /// $$
+    /// 
+    /// ## Inputs
+    /// - `k`: the degree of $P_k$
+    /// 
+    /// Returns the coefficients `(a_k, b_k, c_k)`, with $c_0 = 0$.
+    /// 
+    /// ## Example
+    /// ```
+    /// # use scilib::math::orthogonal::Family;
+    /// // (k + 1)P_{k+1} = (2k + 1)xP_k - kP_{k-1}
+    /// assert_eq!(Family::Legendre.recurrence(2), (5.0 / 3.0, 0.0, 2.0 / 3.0));
+    /// assert_eq!(Family::Hermite.recurrence(3), (2.0, 0.0, 6.0));
+    /// ```
+    pub fn recurrence(&self, k: usize) -> (f64, f64, f64) {
+
+        self.check();
+        let kf: f64 = k as f64;
+
+        let (a, b, c): (f64, f64, f64) = match *self {
+            Self::Legendre => ((2.0 * kf + 1.0) / (kf + 1.0), 0.0, kf / (kf + 1.0)),
+            Self::ChebyshevT => (if k == 0 { 1.0 } else { 2.0 }, 0.0, 1.0),
+            Self::ChebyshevU => (2.0, 0.0, 1.0),
+            Self::ChebyshevV => (2.0, if k == 0 { -1.0 } else { 0.0 }, 1.0),
+            Self::ChebyshevW => (2.0, if k == 0 { 1.0 } else { 0.0 }, 1.0),
+            Self::Jacobi { alpha, beta } => {
+                if k == 0 {
+                    ((alpha + beta + 2.0) / 2.0, (alpha - beta) / 2.0, 0.0)
+                } else {
+                    let s: f64 = 2.0 * kf + alpha + beta;
+                    let den: f64 = 2.0 * (kf + 1.0) * (kf + alpha + beta + 1.0) * s;
+                    (
+                        (s + 1.0) * (s + 2.0) * s / den,
+                        (s + 1.0) * (alpha * alpha - beta * beta) / den,
+                        2.0 * (kf + alpha) * (kf + beta) * (s + 2.0) / den
+                    )
+                }
+            },
+            Self::Gegenbauer { alpha } => (2.0 * (kf + alpha) / (kf + 1.0), 0.0, (kf + 2.0 * alpha - 1.0) / (kf + 1.0)),
+            Self::Laguerre { alpha } => (-1.0 / (kf + 1.0), (2.0 * kf + 1.0 + alpha) / (kf + 1.0), (kf + alpha) / (kf + 1.0)),
+            Self::Hermite => (2.0, 0.0, 2.0 * kf),
+            Self::HermiteProb => (1.0, 0.0, kf)
+        };
+
+        // The previous polynomial is null for the first step
+        (a, b, if k == 0 { 0.0 } else { c })
+    }
+
+    /// # Evaluation by recurrence
+    /// 
+    /// ## Definition
+    /// Computes $P_n(x)$ by running the three-term recurrence from $P_0 = 1$.
+    /// 
+    /// ## Inputs
+    /// - `x`: the value to evaluate
+    /// - `n`: the degree of the polynomial
+    /// 
+    /// Returns the value of the polynomial of degree $n$ of the family at $x$.
+    /// 
+    /// ## Example
+    /// ```
+    /// # use scilib::math::orthogonal::Family;
+    /// // U_n(cos(t)) = sin((n + 1)t) / sin(t)
+    /// let t: f64 = 1.1;
+    /// let res = Family::ChebyshevU.compute(t.cos(), 50);
+    /// assert!((res - (51.0 * t).sin() / t.sin()).abs() < 1.0e-12);
+    /// 
+    /// // He_3(x) = x^3 - 3x
+    /// assert_eq!(Family::HermiteProb.compute(2.0, 3), 2.0);
+    /// ```
+    pub fn compute(&self, x: f64, n: usize) -> f64 {
+
+        let mut prev: f64 = 0.0;
+        let mut res: f64 = 1.0;
+
+        for k in 0..n {
+            let (a, b, c): (f64, f64, f64) = self.recurrence(k);
+            (prev, res) = (res, (a * x + b) * res - c * prev);
+        }
+
+        res
+    }
+
+    /// # Evaluation of all the degrees by recurrence
+    /// 
+    /// ## Definition
+    /// Computes $P_0(x), P_1(x), ..., P_n(x)$ in a single run of the three-term recurrence.
+    /// 
+    /// ## Inputs
+    /// - `x`: the value to evaluate
+    /// - `n`: the highest degree
+    /// 
+    /// Returns the $n+1$ values, in increasing degrees.
+    /// 
+    /// ## Example
+    /// ```
+    /// # use scilib::math::orthogonal::Family;
+    /// let res = Family::Legendre.compute_array(0.5, 3);
+    /// assert_eq!(res, vec![1.0, 0.5, -0.125, -0.4375]);
+    /// ```
+    pub fn compute_array(&self, x: f64, n: usize) -> Vec<f64> {
+
+        let mut res: Vec<f64> = Vec::with_capacity(n + 1);
+        res.push(1.0);
+
+        for k in 0..n {
+            let (a, b, c): (f64, f64, f64) = self.recurrence(k);
+            let prev: f64 = if k == 0 { 0.0 } else { res[k - 1] };
+            res.push((a * x + b) * res[k] - c * prev);
+        }
+
+        res
+    }
+
+    /// # Expanded polynomial
+    /// 
+    /// ## Definition
+    /// Builds the monomial form of $P_n$ by applying the three-term recurrence to the coefficients.
+    /// 
+    /// ## Inputs
+    /// - `n`: the degree of the polynomial
+    /// 
+    /// Returns the polynomial as a `Poly`.
+    /// 
+    /// ## Example
+    /// ```
+    /// # use scilib::math::orthogonal::Family;
+    /// # use scilib::math::polynomial::Poly;
+    /// // T_4(x) = 8x^4 - 8x^2 + 1
+    /// let t4 = Family::ChebyshevT.poly(4);
+    /// assert_eq!(t4, Poly::from(&[(0, 1.0), (2, -8.0), (4, 8.0)]));
+    /// ```
+    pub fn poly(&self, n: usize) -> Poly {
+
+        let mut prev: Vec<f64> = vec![];
+        let mut res: Vec<f64> = vec![1.0];
+
+        for k in 0..n {
+            let (a, b, c): (f64, f64, f64) = self.recurrence(k);
+
+            // (a x + b) P_k - c P_{k-1}, in increasing powers
+            let mut next: Vec<f64> = vec![0.0; k + 2];
+            for (p, f) in res.iter().enumerate() {
+                next[p + 1] += a * f;
+                next[p] += b * f;
+            }
+            for (p, f) in prev.iter().enumerate() {
+                next[p] -= c * f;
+            }
+
+            (prev, res) = (res, next);
+        }
+
+        Poly::from_dense(&res)
+    }
+
+    /// # Checking the parameters of the family
+    fn check(&self) {
+        match *self {
+            Self::Jacobi { alpha, beta } => {
+                assert!(alpha > -1.0 && beta > -1.0, "The Jacobi parameters must be greater than -1!");
+            },
+            Self::Gegenbauer { alpha } => {
+                assert!(alpha > -0.5 && alpha != 0.0, "The Gegenbauer parameter must be greater than -1/2 and non-zero!");
+            },
+            Self::Laguerre { alpha } => {
+                assert!(alpha > -1.0, "The Laguerre parameter must be greater than -1!");
+            },
+            _ => {}
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Zernike radial polynomials
+/// 
+/// ## Definition
+/// The radial part of the [Zernike polynomials](https://en.wikipedia.org/wiki/Zernike_polynomials), null when
+/// $n - m$ is odd, is evaluated from the Jacobi polynomials:
+/// $$
+/// R_n^m(\rho) = (-1)^{(n-m)/2}\rho^mP_{(n-m)/2}^{(m,0)}(1-2\rho^2)
+/// $$
+/// 
+/// ## Inputs
+/// - `rho`: the radius, usually in $[0, 1]$
+/// - `n`: the radial degree
+/// - `m`: the azimuthal degree, with $m \le n$
+/// 
+/// Returns the value of $R_n^m(\rho)$.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::orthogonal::zernike_radial;
+/// // R_4^2(rho) = 4rho^4 - 3rho^2
+/// let rho: f64 = 0.7;
+/// assert!((zernike_radial(rho, 4, 2) - (4.0 * rho.powi(4) - 3.0 * rho.powi(2))).abs() < 1.0e-15);
+/// assert_eq!(zernike_radial(rho, 5, 2), 0.0);
+/// 
+/// // R_n^m(1) = 1
+/// assert!((zernike_radial(1.0, 40, 6) - 1.0).abs() < 1.0e-12);
+/// ```
+pub fn zernike_radial(rho: f64, n: usize, m: usize) -> f64 {
+
+    assert!(m <= n, "The azimuthal degree must not exceed the radial degree!");
+
+    if !(n - m).is_multiple_of(2) {
+        return 0.0;
+    }
+
+    let k: usize = (n - m) / 2;
+    let sign: f64 = if k.is_multiple_of(2) { 1.0 } else { -1.0 };
+    let jacobi: Family = Family::Jacobi { alpha: m as f64, beta: 0.0 };
+
+    sign * rho.powi(m as i32) * jacobi.compute(1.0 - 2.0 * rho * rho, k)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
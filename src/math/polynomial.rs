@@ -27,7 +27,7 @@
 //! creates the polynomial $1 - x + 2x^2$.
 //! 
 //! Basic operations are implemented, such as additions, subtraction, multiplication, both with numbers
-//! and other polynomials:
+//! and other polynomials, as well as the Euclidean division and remainder by other polynomials:
 //! 
 //! ```
 //! # use scilib::math::polynomial::Poly;
@@ -37,6 +37,50 @@
 //! let p4 = p2 / 2.0 + 1.5;
 //! let mut res = p3 * p4;
 //! res.derive(1);
+//! let d = Poly::from(&[(0, 1.0), (1, 1.0)]);
+//! let (q, r) = res.div_rem(&d);
+//! let same_q = res.clone() / d.clone();
+//! let same_r = res.clone() % d.clone();
+//! assert_eq!(q, same_q);
+//! assert_eq!(r, same_r);
+//! 
+//! // The division is exact up to the rounding errors
+//! let back = d * q + r;
+//! for x in [-1.5, 0.0, 0.5, 2.0] {
+//!     assert!((back.compute(x) - res.compute(x)).abs() < 1.0e-12 * res.compute(x).abs().max(1.0));
+//! }
+//! ```
+//! 
+//! ## Storage and evaluation
+//! 
+//! The coefficients are stored densely by default, and evaluated with the Horner scheme. The sparse storage only keeps
+//! the non-null coefficients, for polynomials of high degree with few terms. Slices of values can be evaluated at once,
+//! and the compensated Horner scheme gives accurate values where the terms cancel out:
+//! 
+//! ```
+//! # use scilib::math::polynomial::{ Poly, Storage };
+//! let mut p = Poly::from_dense(&[-1.0, 3.0, -3.0, 1.0]);
+//! let values = p.compute_vec(&[0.0, 0.5, 1.0]);
+//! assert_eq!(values, vec![-1.0, -0.125, 0.0]);
+//! 
+//! // (x - 1)^3 close to its root, where the plain Horner scheme is off by about 10%
+//! let accurate = p.compute_compensated(1.0 + 1.0e-5);
+//! assert!((accurate / 1.0e-15 - 1.0).abs() < 1.0e-9);
+//! 
+//! p.set_storage(Storage::Sparse);
+//! assert_eq!(p.compute(2.0), 1.0);
+//! ```
+//! 
+//! ## Root finding
+//! 
+//! All the complex roots are given by `roots`, with closed forms up to degree 4 and the Aberth-Ehrlich
+//! method beyond. The distinct real roots in an interval are isolated by a Sturm sequence with `real_roots`:
+//! 
+//! ```
+//! # use scilib::math::polynomial::Poly;
+//! let p = Poly::from(&[(0, -2.0), (2, 1.0)]);
+//! let roots = p.real_roots(0.0, 2.0);
+//! assert!((roots[0] - 2.0_f64.sqrt()).abs() < 1.0e-15);
 //! ```
 //! 
 //! ## Implementation of named polynomials
@@ -49,10 +93,17 @@
 //! - **Bernoulli**: `B(n)` with `n` positive integer
 //! - **Euler**: `E(n)` with `n` positive integer
 //! - **Bessel**: `y(n)` with `n` positive integer
-//! - **Hermite**: `H(n)` with `n` positive integer
+//! - **Hermite**: `H(n)` with `n` positive integer, in the physicists' and probabilists' normalizations
+//! - **Chebyshev**: `T(n)`, `U(n)`, `V(n)` and `W(n)` of the four kinds, with `n` positive integer
+//! - **Jacobi**: `P(n,a,b)` with `n` positive integer and `a`, `b` real numbers greater than -1
+//! - **Gegenbauer**: `C(n,a)` with `n` positive integer and `a` a real number greater than -1/2
+//! - **Zernike radial**: `R(n,m)` with `n` and `m` positive integers such that `m <= n`
 //! - **Rising factorial**: the polynomial associated to the rising factorial function, with `n` positive integer
 //! - **Falling factorial**: the polynomial associated to the falling factorial function, with `n` positive integer
 //! 
+//! The orthogonal polynomials can also be evaluated without expanding them, which is more accurate at high degree,
+//! with the `math::orthogonal` module.
+//! 
 //! For example, to create the generalized Legendre Polynomial of degree 4, with associated factor 1:
 //! 
 //! ```
@@ -70,19 +121,57 @@
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-use std::collections::HashMap;  // Rust Hashmap
+use std::collections::BTreeMap; // Ordered map of the sparse coefficients
 
 use super::basic;               // Basic functions
 use super::series;
+use super::orthogonal::Family;  // Three-term recurrences of the orthogonal polynomials
 
 use num_complex::Complex64;     // Using complex numbers from the num crate
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// Maximum number of iterations of the root finders
+const ROOTS_ITERATIONS: usize = 500;
+
+/// Maximum number of Newton iterations polishing the roots
+const POLISH_ITERATIONS: usize = 3;
+
+/// Magnitude, relative to the terms cancelling into it, under which a coefficient of a Sturm remainder is dropped
+const STURM_TOLERANCE: f64 = 1.0e-12;
+
+/// Fraction of the interval at which the Sturm bisection splits it, away from one half to avoid landing on simple roots
+const STURM_SPLIT: f64 = 0.4637;
+
+/// Magnitude, relative to the terms cancelling into it, under which a coefficient of a remainder is dropped in the
+/// Euclidean algorithm of the greatest common divisor
+const GCD_TOLERANCE: f64 = 1.0e-8;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Storage of the coefficients
+/// 
+/// The dense storage keeps every coefficient up to the degree, indexed by the power, and evaluates with the
+/// Horner scheme. The sparse storage only keeps the non-null coefficients, for polynomials of high degree with few terms.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Storage {
+    /// Every coefficient up to the degree
+    Dense,
+    /// Only the non-null coefficients
+    Sparse
+}
+
+/// # Coefficients of a polynomial, in one of the storages
+#[derive(Clone, Debug)]
+enum Coefs {
+    Dense(Vec<f64>),
+    Sparse(BTreeMap<i32, f64>)
+}
+
 /// # Polynomial implementation
 #[derive(Clone)]
 pub struct Poly {
-    coef: HashMap<i32, f64>,
+    coef: Coefs,
     l: Option<f64>,
     compute_fn: fn(&Self, f64) -> f64,
     compute_fnc: fn(&Self, Complex64) -> Complex64
@@ -91,7 +180,7 @@ pub struct Poly {
 /// # Debug for polynomial
 impl std::fmt::Debug for Poly {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        writeln!(f, "{:?}", self.coef)?;
+        writeln!(f, "{:?}", self.get_coefs())?;
         writeln!(f, "{:?}", self.l)?;
 
         Ok(())
@@ -102,9 +191,9 @@ impl std::fmt::Debug for Poly {
 impl std::fmt::Display for Poly {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         
-        // We write the power and associated factors
+        // We write the power and associated factors, in increasing powers
         writeln!(f, "Power :: Factor")?;
-        for (power, factor) in &self.coef {
+        for (power, factor) in &self.get_coefs() {
             writeln!(f, "{:5} :: {}", power, factor)?;
         }
 
@@ -116,7 +205,7 @@ impl std::fmt::Display for Poly {
 impl Default for Poly {
     fn default() -> Self {
         Self {
-            coef: HashMap::new(),
+            coef: Coefs::Dense(vec![]),
             l: None,
             compute_fn: Self::compute_base,
             compute_fnc: Self::compute_base_complex,
@@ -124,6 +213,103 @@ impl Default for Poly {
     }
 }
 
+impl Coefs {
+
+    /// # Coefficients from powers and factors, without the null ones
+    fn from_map(coef: BTreeMap<i32, f64>, storage: Storage) -> Self {
+
+        let mut res: Self = match storage {
+            Storage::Dense => {
+                let mut dense: Vec<f64> = vec![0.0; coef.keys().last().map_or(0, |p| *p as usize + 1)];
+                for (p, f) in coef {
+                    dense[p as usize] = f;
+                }
+                Self::Dense(dense)
+            },
+            Storage::Sparse => Self::Sparse(coef)
+        };
+
+        res.normalize();
+        res
+    }
+
+    /// # Powers and non-null factors, in increasing powers
+    fn to_map(&self) -> BTreeMap<i32, f64> {
+        match self {
+            Self::Dense(c) => c.iter().enumerate().filter(|(_, f)| **f != 0.0).map(|(p, f)| (p as i32, *f)).collect(),
+            Self::Sparse(c) => c.clone()
+        }
+    }
+
+    /// # Removing the null leading coefficients, or all the null ones when sparse
+    fn normalize(&mut self) {
+        match self {
+            Self::Dense(c) => {
+                while c.last() == Some(&0.0) {
+                    c.pop();
+                }
+            },
+            Self::Sparse(c) => c.retain(|_, f| *f != 0.0)
+        }
+    }
+
+    /// # Adding a value to the coefficient of a power
+    fn add(&mut self, power: i32, value: f64) {
+
+        match self {
+            Self::Dense(c) => {
+                if c.len() <= power as usize {
+                    c.resize(power as usize + 1, 0.0);
+                }
+                c[power as usize] += value;
+            },
+            Self::Sparse(c) => *c.entry(power).or_insert(0.0) += value
+        }
+
+        self.normalize();
+    }
+
+    /// # Applying a function to all the coefficients
+    fn map_values<F: Fn(f64) -> f64>(&mut self, f: F) {
+
+        match self {
+            Self::Dense(c) => c.iter_mut().for_each(|v| *v = f(*v)),
+            Self::Sparse(c) => c.values_mut().for_each(|v| *v = f(*v))
+        }
+
+        self.normalize();
+    }
+
+    /// # Product of two polynomials, in the storage of the first one
+    fn mul(&self, rhs: &Self) -> Self {
+
+        let mut res: Self = match (self, rhs) {
+            (Self::Dense(a), Self::Dense(b)) if a.is_empty() || b.is_empty() => Self::Dense(vec![]),
+            (Self::Dense(a), Self::Dense(b)) => {
+                let mut c: Vec<f64> = vec![0.0; a.len() + b.len() - 1];
+                for (i, fa) in a.iter().enumerate() {
+                    for (j, fb) in b.iter().enumerate() {
+                        c[i + j] += fa * fb;
+                    }
+                }
+                Self::Dense(c)
+            },
+            _ => {
+                let mut c: BTreeMap<i32, f64> = BTreeMap::new();
+                for (p, f) in self.to_map() {
+                    for (rhs_p, rhs_f) in rhs.to_map() {
+                        *c.entry(p + rhs_p).or_insert(0.0) += f * rhs_f;
+                    }
+                }
+                Self::from_map(c, match self { Self::Dense(_) => Storage::Dense, Self::Sparse(_) => Storage::Sparse })
+            }
+        };
+
+        res.normalize();
+        res
+    }
+}
+
 impl Poly {
 
     /// # Creates a new polynomial
@@ -140,6 +326,8 @@ impl Poly {
     /// $$
     /// where the $i$ indices are where non zero coefficients exist.
     /// 
+    /// The coefficients use the dense storage, see `set_storage` for the sparse one.
+    /// 
     /// ## Inputs
     /// - `pow_fac`: the power and associated factor
     /// 
@@ -153,10 +341,71 @@ impl Poly {
     /// ```
     pub fn from(pow_fac: &[(usize, f64)]) -> Self {
 
-        let coef: HashMap<i32, f64> = pow_fac.iter().map(|(p, f)| (*p as i32, *f)).collect();
+        let coef: BTreeMap<i32, f64> = pow_fac.iter().map(|(p, f)| (*p as i32, *f)).collect();
+
+        Self::from_map(coef)
+    }
 
+    /// # Creates a new polynomial from dense coefficients
+    /// 
+    /// ## Definition
+    /// We create the polynomial from all its coefficients, in increasing powers:
+    /// $$
+    /// p = c_0 + c_1x + c_2x^2 + ... + c_nx^n
+    /// $$
+    /// 
+    /// ## Inputs
+    /// - `coefs`: the coefficients $c_0, c_1, ..., c_n$
+    /// 
+    /// ## Example
+    /// ```
+    /// # use scilib::math::polynomial::Poly;
+    /// // 2 + 3x^2
+    /// let p = Poly::from_dense(&[2.0, 0.0, 3.0]);
+    /// assert_eq!(p, Poly::from(&[(0, 2.0), (2, 3.0)]));
+    /// ```
+    pub fn from_dense(coefs: &[f64]) -> Self {
         Self {
-            coef,
+            coef: Coefs::from_map(coefs.iter().enumerate().map(|(p, f)| (p as i32, *f)).collect(), Storage::Dense),
+            ..Self::default()
+        }
+    }
+
+    /// # Setting the storage of the coefficients
+    /// 
+    /// ## Definition
+    /// Converts the coefficients to the dense storage, used by default and evaluated with the Horner scheme,
+    /// or to the sparse storage, which only keeps the non-null coefficients.
+    /// 
+    /// ## Inputs
+    /// - `storage`: the new storage
+    /// 
+    /// ## Example
+    /// ```
+    /// # use scilib::math::polynomial::{ Poly, Storage };
+    /// let mut p = Poly::from(&[(0, 1.0), (1000, 1.0)]);
+    /// p.set_storage(Storage::Sparse);
+    /// assert_eq!(p.get_storage(), Storage::Sparse);
+    /// assert_eq!(p.compute(1.0), 2.0);
+    /// ```
+    pub fn set_storage(&mut self, storage: Storage) {
+        self.coef = Coefs::from_map(self.coef.to_map(), storage);
+    }
+
+    /// # Getting the storage of the coefficients
+    /// 
+    /// Returns the current `Storage` of the coefficients.
+    pub fn get_storage(&self) -> Storage {
+        match self.coef {
+            Coefs::Dense(_) => Storage::Dense,
+            Coefs::Sparse(_) => Storage::Sparse
+        }
+    }
+
+    /// # Polynomial from powers and factors, in the dense storage
+    fn from_map(coef: BTreeMap<i32, f64>) -> Self {
+        Self {
+            coef: Coefs::from_map(coef, Storage::Dense),
             ..Self::default()
         }
     }
@@ -283,7 +532,7 @@ impl Poly {
     pub fn legendre(n: usize) -> Self {
 
         // Initializing the coefficients
-        let mut coef: HashMap<i32, f64> = HashMap::new();
+        let mut coef: BTreeMap<i32, f64> = BTreeMap::new();
         
         // Going through the powers of the order
         for k in 0..=(n / 2) {
@@ -292,10 +541,7 @@ impl Poly {
         }
 
         // Returning associated struct
-        Self {
-            coef,
-            ..Self::default()
-        }
+        Self::from_map(coef)
     }
 
     /// # Laguerre polynomials
@@ -354,7 +600,7 @@ impl Poly {
         let alpha: f64 = l.into();
 
         // Initializing the vectors
-        let mut coef: HashMap<i32, f64> = HashMap::new();
+        let mut coef: BTreeMap<i32, f64> = BTreeMap::new();
 
         // Going through the powers of the order
         for i in (0..=n).rev() {
@@ -364,9 +610,8 @@ impl Poly {
 
         // Returning associated struct
         Self {
-            coef,
             l: Some(alpha),
-            ..Self::default()
+            ..Self::from_map(coef)
         }
     }
 
@@ -416,7 +661,7 @@ impl Poly {
     pub fn bernoulli(n: usize) -> Self {
 
         // Initializing the vectors
-        let mut coef: HashMap<i32, f64> = HashMap::new();
+        let mut coef: BTreeMap<i32, f64> = BTreeMap::new();
 
         for k in 0..=n {
             let c: f64 = basic::binomial(n, k) as f64 * Self::bernoulli_number(n - k);
@@ -424,10 +669,7 @@ impl Poly {
         }
 
         // Returning associated struct
-        Self {
-            coef,
-            ..Self::default()
-        }
+        Self::from_map(coef)
     }
 
     /// # Euler polynomials
@@ -475,7 +717,7 @@ impl Poly {
     pub fn euler(n: usize) -> Self {
 
         // Initializing the vectors
-        let mut coef: HashMap<i32, f64> = (0..=n).map(|x| (x as i32, 0.0)).collect();
+        let mut coef: BTreeMap<i32, f64> = (0..=n).map(|x| (x as i32, 0.0)).collect();
 
         for k in 0..=n {
             let binom: f64 = basic::binomial(n, k) as f64;
@@ -494,10 +736,7 @@ impl Poly {
         }
 
         // Returning associated struct
-        Self {
-            coef,
-            ..Self::default()
-        }
+        Self::from_map(coef)
     }
 
     /// # Rising factorial polynomials
@@ -524,7 +763,7 @@ impl Poly {
     /// ```
     pub fn factorial_rising(n: usize) -> Self {
 
-        let mut coef: HashMap<i32, f64> = (0..=n).rev().map(|k| {
+        let mut coef: BTreeMap<i32, f64> = (0..=n).rev().map(|k| {
             (k as i32, Poly::stirling_number(n, k) as f64)
         }).collect();
 
@@ -532,10 +771,7 @@ impl Poly {
             coef.remove(&0);
         }
 
-        Self {
-            coef,
-            ..Self::default()
-        }
+        Self::from_map(coef)
     }
     
     /// # Falling factorial polynomials
@@ -563,7 +799,7 @@ impl Poly {
     /// ```
     pub fn factorial_falling(n: usize) -> Self {
 
-        let mut coef: HashMap<i32, f64> = (0..=n).rev().map(|k| {
+        let mut coef: BTreeMap<i32, f64> = (0..=n).rev().map(|k| {
             (k as i32, Poly::stirling_number_signed(n, k) as f64)
         }).collect();
 
@@ -571,10 +807,7 @@ impl Poly {
             coef.remove(&0);
         }
 
-        Self {
-            coef,
-            ..Self::default()
-        }
+        Self::from_map(coef)
     }
 
     /// # Bessel Polynomials
@@ -614,7 +847,7 @@ impl Poly {
     /// ```
     pub fn bessel(n: usize) -> Self {
 
-        let mut coef: HashMap<i32, f64> = HashMap::new();
+        let mut coef: BTreeMap<i32, f64> = BTreeMap::new();
 
         // Setting up variables once
         let mut kf: usize = 1;                      // k!
@@ -636,10 +869,7 @@ impl Poly {
             coef.insert(k as i32, c);
         }
 
-        Self {
-            coef,
-            ..Self::default()
-        }
+        Self::from_map(coef)
     }
 
     /// # Hermite polynomials
@@ -670,7 +900,7 @@ impl Poly {
     /// ```
     pub fn hermite(n: usize) -> Self {
 
-        let mut coef: HashMap<i32, f64> = HashMap::new();
+        let mut coef: BTreeMap<i32, f64> = BTreeMap::new();
 
         let nf: usize = basic::factorial(n);
         let mut n2m: usize;
@@ -691,10 +921,225 @@ impl Poly {
             coef.insert((n - 2 * m) as i32, c);
         }
 
-        Self {
-            coef,
-            ..Self::default()
+        Self::from_map(coef)
+    }
+
+    /// # Probabilists' Hermite polynomials
+    /// 
+    /// ## Definitions
+    /// The probabilists' [Hermite polynomials](https://en.wikipedia.org/wiki/Hermite_polynomials) are orthogonal
+    /// for the weight $e^{-x^2/2}$, and relate to the physicists' ones given by `hermite`:
+    /// $$
+    /// He_n(x) = 2^{-n/2}H_n\left(\frac{x}{\sqrt{2}}\right),~ He_{n+1}(x) = xHe_n(x) - nHe_{n-1}(x)
+    /// $$
+    /// 
+    /// ## Inputs
+    /// - `n` the order of the polynomial
+    /// 
+    /// ## Example
+    /// ```
+    /// # use scilib::math::polynomial::Poly;
+    /// // He_4(x) = x^4 - 6x^2 + 3
+    /// let h = Poly::hermite_prob(4);
+    /// assert_eq!(h, Poly::from(&[(0, 3.0), (2, -6.0), (4, 1.0)]));
+    /// ```
+    pub fn hermite_prob(n: usize) -> Self {
+        Family::HermiteProb.poly(n)
+    }
+
+    /// # Chebyshev polynomials of the first kind
+    /// 
+    /// ## Definition
+    /// The [Chebyshev polynomials](https://en.wikipedia.org/wiki/Chebyshev_polynomials) of the first kind
+    /// are defined by:
+    /// $$
+    /// T_n(\cos\theta) = \cos(n\theta),~ T_{n+1}(x) = 2xT_n(x) - T_{n-1}(x)
+    /// $$
+    /// 
+    /// ## Inputs
+    /// - `n` the order of the polynomial
+    /// 
+    /// ## Example
+    /// ```
+    /// # use scilib::math::polynomial::Poly;
+    /// let t = Poly::chebyshev_t(5);
+    /// assert_eq!(t, Poly::from(&[(1, 5.0), (3, -20.0), (5, 16.0)]));
+    /// assert!((t.compute(0.4_f64.cos()) - 2.0_f64.cos()).abs() < 1.0e-14);
+    /// ```
+    pub fn chebyshev_t(n: usize) -> Self {
+        Family::ChebyshevT.poly(n)
+    }
+
+    /// # Chebyshev polynomials of the second kind
+    /// 
+    /// ## Definition
+    /// The [Chebyshev polynomials](https://en.wikipedia.org/wiki/Chebyshev_polynomials) of the second kind
+    /// are defined by:
+    /// $$
+    /// U_n(\cos\theta) = \frac{\sin((n+1)\theta)}{\sin\theta},~ U_{n+1}(x) = 2xU_n(x) - U_{n-1}(x)
+    /// $$
+    /// 
+    /// ## Inputs
+    /// - `n` the order of the polynomial
+    /// 
+    /// ## Example
+    /// ```
+    /// # use scilib::math::polynomial::Poly;
+    /// let u = Poly::chebyshev_u(4);
+    /// assert_eq!(u, Poly::from(&[(0, 1.0), (2, -12.0), (4, 16.0)]));
+    /// ```
+    pub fn chebyshev_u(n: usize) -> Self {
+        Family::ChebyshevU.poly(n)
+    }
+
+    /// # Chebyshev polynomials of the third kind
+    /// 
+    /// ## Definition
+    /// The Chebyshev polynomials of the third kind are defined by:
+    /// $$
+    /// V_n(\cos\theta) = \frac{\cos((n+1/2)\theta)}{\cos(\theta/2)},~ V_0 = 1,~ V_1(x) = 2x - 1
+    /// $$
+    /// and follow the same recurrence as the first two kinds.
+    /// 
+    /// ## Inputs
+    /// - `n` the order of the polynomial
+    /// 
+    /// ## Example
+    /// ```
+    /// # use scilib::math::polynomial::Poly;
+    /// let v = Poly::chebyshev_v(3);
+    /// let theta: f64 = 0.7;
+    /// let expected: f64 = (3.5 * theta).cos() / (0.5 * theta).cos();
+    /// assert!((v.compute(theta.cos()) - expected).abs() < 1.0e-14);
+    /// ```
+    pub fn chebyshev_v(n: usize) -> Self {
+        Family::ChebyshevV.poly(n)
+    }
+
+    /// # Chebyshev polynomials of the fourth kind
+    /// 
+    /// ## Definition
+    /// The Chebyshev polynomials of the fourth kind are defined by:
+    /// $$
+    /// W_n(\cos\theta) = \frac{\sin((n+1/2)\theta)}{\sin(\theta/2)},~ W_0 = 1,~ W_1(x) = 2x + 1
+    /// $$
+    /// and follow the same recurrence as the first two kinds.
+    /// 
+    /// ## Inputs
+    /// - `n` the order of the polynomial
+    /// 
+    /// ## Example
+    /// ```
+    /// # use scilib::math::polynomial::Poly;
+    /// let w = Poly::chebyshev_w(3);
+    /// let theta: f64 = 0.7;
+    /// let expected: f64 = (3.5 * theta).sin() / (0.5 * theta).sin();
+    /// assert!((w.compute(theta.cos()) - expected).abs() < 1.0e-14);
+    /// ```
+    pub fn chebyshev_w(n: usize) -> Self {
+        Family::ChebyshevW.poly(n)
+    }
+
+    /// # Jacobi polynomials
+    /// 
+    /// ## Definition
+    /// The [Jacobi polynomials](https://en.wikipedia.org/wiki/Jacobi_polynomials) are orthogonal on $[-1, 1]$
+    /// for the weight $(1-x)^\alpha(1+x)^\beta$, and are given by:
+    /// $$
+    /// P_n^{(\alpha,\beta)}(x) = \sum_{s=0}^{n}\binom{n+\alpha}{n-s}\binom{n+\beta}{s}\left(\frac{x-1}{2}\right)^s\left(\frac{x+1}{2}\right)^{n-s}
+    /// $$
+    /// The Legendre, Chebyshev and Gegenbauer polynomials are special cases, up to a normalization.
+    /// 
+    /// ## Inputs
+    /// - `n` the order of the polynomial
+    /// - `alpha`, `beta` the parameters, greater than -1
+    /// 
+    /// ## Example
+    /// ```
+    /// # use scilib::math::polynomial::Poly;
+    /// // Legendre polynomials for alpha = beta = 0
+    /// let p = Poly::jacobi(6, 0.0, 0.0);
+    /// let l = Poly::legendre(6);
+    /// assert!((p.compute(0.35) - l.compute(0.35)).abs() < 1.0e-14);
+    /// 
+    /// // P_1(x) = (alpha + 1) + (alpha + beta + 2)(x - 1) / 2
+    /// assert_eq!(Poly::jacobi(1, 1.0, 2.0), Poly::from(&[(0, -0.5), (1, 2.5)]));
+    /// ```
+    pub fn jacobi<U, V>(n: usize, alpha: U, beta: V) -> Self
+    where U: Into<f64>, V: Into<f64> {
+        Family::Jacobi { alpha: alpha.into(), beta: beta.into() }.poly(n)
+    }
+
+    /// # Gegenbauer polynomials
+    /// 
+    /// ## Definition
+    /// The [Gegenbauer polynomials](https://en.wikipedia.org/wiki/Gegenbauer_polynomials), or ultraspherical
+    /// polynomials, are orthogonal on $[-1, 1]$ for the weight $(1-x^2)^{\alpha-1/2}$:
+    /// $$
+    /// C_0^{(\alpha)} = 1,~ C_1^{(\alpha)}(x) = 2\alpha x,~
+    /// (n+1)C_{n+1}^{(\alpha)}(x) = 2(n+\alpha)xC_n^{(\alpha)}(x) - (n+2\alpha-1)C_{n-1}^{(\alpha)}(x)
+    /// $$
+    /// 
+    /// ## Inputs
+    /// - `n` the order of the polynomial
+    /// - `alpha` the parameter, greater than -1/2 and non-zero
+    /// 
+    /// ## Example
+    /// ```
+    /// # use scilib::math::polynomial::Poly;
+    /// // Chebyshev polynomials of the second kind for alpha = 1
+    /// assert_eq!(Poly::gegenbauer(5, 1.0), Poly::chebyshev_u(5));
+    /// 
+    /// // Legendre polynomials for alpha = 1/2
+    /// let c = Poly::gegenbauer(4, 0.5);
+    /// assert!((c.compute(0.8) - Poly::legendre(4).compute(0.8)).abs() < 1.0e-14);
+    /// ```
+    pub fn gegenbauer<U>(n: usize, alpha: U) -> Self
+    where U: Into<f64> {
+        Family::Gegenbauer { alpha: alpha.into() }.poly(n)
+    }
+
+    /// # Zernike radial polynomials
+    /// 
+    /// ## Definition
+    /// The radial part of the [Zernike polynomials](https://en.wikipedia.org/wiki/Zernike_polynomials),
+    /// orthogonal on the unit disk, is given for an even $n - m$ by:
+    /// $$
+    /// R_n^m(\rho) = \sum_{k=0}^{\frac{n-m}{2}}\frac{(-1)^k(n-k)!}{k!\left(\frac{n+m}{2}-k\right)!\left(\frac{n-m}{2}-k\right)!}\rho^{n-2k}
+    /// $$
+    /// and is null for an odd $n - m$.
+    /// 
+    /// ## Inputs
+    /// - `n` the radial order
+    /// - `m` the azimuthal order, with $m \le n$
+    /// 
+    /// ## Example
+    /// ```
+    /// # use scilib::math::polynomial::Poly;
+    /// // R_4^0(rho) = 6rho^4 - 6rho^2 + 1
+    /// assert_eq!(Poly::zernike_radial(4, 0), Poly::from(&[(0, 1.0), (2, -6.0), (4, 6.0)]));
+    /// assert_eq!(Poly::zernike_radial(5, 2), Poly::default());
+    /// ```
+    pub fn zernike_radial(n: usize, m: usize) -> Self {
+
+        assert!(m <= n, "The azimuthal order must not exceed the radial order!");
+
+        let mut coef: BTreeMap<i32, f64> = BTreeMap::new();
+        if (n - m).is_multiple_of(2) {
+
+            let (s, d): (usize, usize) = ((n + m) / 2, (n - m) / 2);
+
+            // First coefficient n! / (s! d!), then the ratio between successive terms
+            let mut c: f64 = (1..=d).fold(1.0, |res, i| res * (s + i) as f64 / i as f64);
+            for k in 0..=d {
+                coef.insert((n - 2 * k) as i32, c);
+                if k < d {
+                    c *= -(((s - k) * (d - k)) as f64) / ((k + 1) * (n - k)) as f64;
+                }
+            }
         }
+
+        Self::from_map(coef)
     }
 
     //////////////////////////////////////////////////
@@ -721,15 +1166,15 @@ impl Poly {
         // Looping the derivation, m times
         for _ in 1..=m {
 
-            let mut temp_c: HashMap<i32, f64> = HashMap::new();
+            let mut temp_c: BTreeMap<i32, f64> = BTreeMap::new();
 
-            for (p, f) in &self.coef {
+            for (p, f) in self.coef.to_map() {
                 match p {
                     0 => { },
-                    _ => { temp_c.insert(p - 1, f * *p as f64); }
+                    _ => { temp_c.insert(p - 1, f * p as f64); }
                 }
             }
-            self.coef = temp_c;
+            self.coef = Coefs::from_map(temp_c, self.get_storage());
         }
     }
 
@@ -768,15 +1213,15 @@ impl Poly {
         // We loop to integrate
         for n_f in n_coef {
 
-            let mut temp_c: HashMap<i32, f64> = HashMap::new();
+            let mut temp_c: BTreeMap<i32, f64> = BTreeMap::new();
 
-            for (p, f) in &self.coef {
+            for (p, f) in self.coef.to_map() {
                 temp_c.insert(p + 1, f / (p + 1) as f64);
             }
 
             temp_c.insert(0, n_f);
             
-            self.coef = temp_c;
+            self.coef = Coefs::from_map(temp_c, self.get_storage());
         }
     }
 
@@ -815,6 +1260,7 @@ impl Poly {
     pub fn pow(&self, n: usize) -> Poly {
 
         let mut res = Poly::from(&[(0, 1.0)]);  // Init at +1
+        res.set_storage(self.get_storage());
         
         for _ in 1..=n {                        // For n >= 1
             res *= self.clone();
@@ -823,20 +1269,194 @@ impl Poly {
         res
     }
 
-    /// # Getting the coefficients of a polynomial
+    /// # Euclidean division
     /// 
-    /// Simply returns the HashMap of the power and associated coefficients.
+    /// ## Definition
+    /// Divides the polynomial $A$ by the polynomial $B$, giving the quotient $Q$ and the remainder $R$ such that:
+    /// $$
+    /// A = BQ + R,~ \deg(R) < \deg(B)
+    /// $$
+    /// 
+    /// ## Inputs
+    /// - `rhs`: the divisor, which must not be null
+    /// 
+    /// Returns the quotient and the remainder, as new `Poly` instances.
     /// 
+    /// ## Example
     /// ```
-    /// # use std::collections::HashMap;
     /// # use scilib::math::polynomial::Poly;
-    /// let setup = vec![(0, 1.0), (2, 2.2), (3, -1.2)];
+    /// // (x^3 - 2x^2 - 4) = (x - 3)(x^2 + x + 3) + 5
+    /// let a = Poly::from(&[(0, -4.0), (2, -2.0), (3, 1.0)]);
+    /// let b = Poly::from(&[(0, -3.0), (1, 1.0)]);
+    /// let (q, r) = a.div_rem(&b);
+    /// assert_eq!(q, Poly::from(&[(0, 3.0), (1, 1.0), (2, 1.0)]));
+    /// assert_eq!(r, Poly::from(&[(0, 5.0)]));
+    /// ```
+    pub fn div_rem(&self, rhs: &Poly) -> (Poly, Poly) {
+
+        let den: Vec<f64> = rhs.dense_coefs();
+        assert!(den.iter().any(|c| *c != 0.0), "Cannot divide by a null polynomial!");
+
+        let (quot, rem): (Vec<f64>, Vec<f64>) = Self::dense_div_rem(&self.dense_coefs(), &den, 0.0);
+
+        (Self::from_dense(&quot), Self::from_dense(&rem))
+    }
+
+    /// # Synthetic division by a known root
+    /// 
+    /// ## Definition
+    /// Divides the polynomial by $(x - r)$ with the Ruffini-Horner scheme:
+    /// $$
+    /// P(x) = (x - r)Q(x) + P(r)
+    /// $$
+    /// When $r$ is a root of $P$, this deflates the polynomial by one degree.
+    /// 
+    /// ## Inputs
+    /// - `root`: the value $r$
+    /// 
+    /// Returns the quotient $Q$ and the remainder $P(r)$.
+    /// 
+    /// ## Example
+    /// ```
+    /// # use scilib::math::polynomial::Poly;
+    /// // x^3 - 6x^2 + 11x - 6 = (x - 1)(x - 2)(x - 3)
+    /// let p = Poly::from(&[(0, -6.0), (1, 11.0), (2, -6.0), (3, 1.0)]);
+    /// let (q, r) = p.synthetic_division(3.0);
+    /// assert_eq!(q, Poly::from(&[(0, 2.0), (1, -3.0), (2, 1.0)]));
+    /// assert_eq!(r, 0.0);
+    /// ```
+    pub fn synthetic_division(&self, root: f64) -> (Poly, f64) {
+
+        let coefs: Vec<f64> = self.dense_coefs();
+        let mut quot: Vec<f64> = vec![0.0; coefs.len() - 1];
+
+        // Horner scheme, the intermediate values being the quotient coefficients
+        let mut acc: f64 = 0.0;
+        for (k, c) in coefs.iter().enumerate().rev() {
+            acc = acc * root + c;
+            if k > 0 {
+                quot[k - 1] = acc;
+            }
+        }
+
+        (Self::from_dense(&quot), acc)
+    }
+
+    /// # Greatest common divisor
+    /// 
+    /// ## Definition
+    /// Computes the monic greatest common divisor $G$ of two polynomials with the Euclidean algorithm:
+    /// $$
+    /// \gcd(A, B) = \gcd(B, A \bmod B),~ \gcd(A, 0) = A
+    /// $$
+    /// To cope with rounding errors, the operands are kept monic, and the coefficients of a remainder are dropped when
+    /// they are negligible compared to the terms cancelling into them, such that close roots at small scales stay
+    /// apart.
+    /// 
+    /// ## Inputs
+    /// - `other`: the second polynomial
+    /// 
+    /// Returns the monic greatest common divisor, the null polynomial if both polynomials are null.
+    /// 
+    /// ## Example
+    /// ```
+    /// # use scilib::math::polynomial::Poly;
+    /// // (x - 1)(x + 2) and (x - 1)(x - 3)
+    /// let a = Poly::from(&[(0, -2.0), (1, 1.0), (2, 1.0)]);
+    /// let b = Poly::from(&[(0, 3.0), (1, -4.0), (2, 1.0)]);
+    /// let g = a.gcd(&b);
+    /// assert_eq!(g.get_order(), 1);
+    /// assert!(g.compute(1.0).abs() < 1.0e-12);
+    /// 
+    /// // Close roots at a small scale have no common factor with the derivative
+    /// let p = Poly::from(&[(0, 2.0e-10), (1, -3.0e-5), (2, 1.0)]);    // (x - 1e-5)(x - 2e-5)
+    /// let mut dp = p.clone();
+    /// dp.derive(1);
+    /// assert_eq!(p.gcd(&dp).get_order(), 0);
+    /// ```
+    pub fn gcd(&self, other: &Poly) -> Poly {
+        Self::from_dense(&Self::dense_gcd(&self.dense_coefs(), &other.dense_coefs()))
+    }
+
+    /// # Square-free decomposition
+    /// 
+    /// ## Definition
+    /// Factors the polynomial into monic, square-free and pairwise coprime polynomials $F_i$ with
+    /// [Yun's algorithm](https://en.wikipedia.org/wiki/Square-free_polynomial):
+    /// $$
+    /// P = a_n\prod_{i} F_i^i
+    /// $$
+    /// Each $F_i$ holds, once, the roots of multiplicity $i$ of $P$.
+    /// 
+    /// Returns the non-constant factors $F_i$ with their multiplicities $i$, in increasing multiplicities.
+    /// 
+    /// ## Example
+    /// ```
+    /// # use scilib::math::polynomial::Poly;
+    /// // 2(x - 1)^2 (x + 2)^3
+    /// let a = Poly::from(&[(0, -1.0), (1, 1.0)]);
+    /// let b = Poly::from(&[(0, 2.0), (1, 1.0)]);
+    /// let p = a.pow(2) * b.pow(3) * 2.0;
+    /// 
+    /// let factors = p.square_free();
+    /// assert_eq!(factors.len(), 2);
+    /// assert_eq!(factors[0].1, 2);
+    /// assert!(factors[0].0.compute(1.0).abs() < 1.0e-12);
+    /// assert_eq!(factors[1].1, 3);
+    /// assert!(factors[1].0.compute(-2.0).abs() < 1.0e-12);
+    /// 
+    /// // Close roots at a small scale are kept apart
+    /// let close = Poly::from(&[(0, 2.0e-10), (1, -3.0e-5), (2, 1.0)]);  // (x - 1e-5)(x - 2e-5)
+    /// let factors = close.square_free();
+    /// assert_eq!(factors.len(), 1);
+    /// assert_eq!((factors[0].0.get_order(), factors[0].1), (2, 1));
+    /// ```
+    pub fn square_free(&self) -> Vec<(Poly, usize)> {
+
+        let coefs: Vec<f64> = Self::dense_monic(&self.dense_coefs());
+        if coefs.len() < 2 {
+            return vec![];
+        }
+
+        let deriv: Vec<f64> = Self::dense_derivative(&coefs);
+        let common: Vec<f64> = Self::dense_gcd(&coefs, &deriv);
+
+        // Yun's algorithm: b holds the roots of multiplicity at least i, d helps extract those of multiplicity i
+        let mut b: Vec<f64> = Self::dense_div_rem(&coefs, &common, 0.0).0;
+        let c: Vec<f64> = Self::dense_div_rem(&deriv, &common, 0.0).0;
+        let mut d: Vec<f64> = Self::dense_sub(&c, &Self::dense_derivative(&b), GCD_TOLERANCE);
+
+        let mut res: Vec<(Poly, usize)> = vec![];
+        let mut i: usize = 1;
+        while b.len() > 1 && i < coefs.len() {
+            let a: Vec<f64> = Self::dense_gcd(&b, &d);
+            if a.len() > 1 {
+                res.push((Self::from_dense(&a), i));
+            }
+
+            let c: Vec<f64> = Self::dense_div_rem(&d, &a, 0.0).0;
+            b = Self::dense_monic(&Self::dense_div_rem(&b, &a, 0.0).0);
+            d = Self::dense_sub(&c, &Self::dense_derivative(&b), GCD_TOLERANCE);
+            i += 1;
+        }
+
+        res
+    }
+
+    /// # Getting the coefficients of a polynomial
+    /// 
+    /// Returns the ordered map of the powers and associated non-null coefficients, in increasing powers.
+    /// 
+    /// ```
+    /// # use std::collections::BTreeMap;
+    /// # use scilib::math::polynomial::Poly;
+    /// let setup = vec![(0, 1.0), (2, 2.2), (3, -1.2)];
     /// let p = Poly::from(&setup);
-    /// let expected: HashMap<i32, f64> = setup.iter().map(|(p, f)| (*p as i32, *f)).collect();
+    /// let expected: BTreeMap<i32, f64> = setup.iter().map(|(p, f)| (*p as i32, *f)).collect();
     /// assert_eq!(p.get_coefs(), expected);
     /// ```
-    pub fn get_coefs(&self) -> HashMap<i32, f64> {
-        self.coef.clone()
+    pub fn get_coefs(&self) -> BTreeMap<i32, f64> {
+        self.coef.to_map()
     }
 
     /// # Computing for a real value
@@ -857,17 +1477,81 @@ impl Poly {
 
     /// # Compute the polynomial for a real number
     fn compute_base(&self, x: f64) -> f64 {
-
-        // Iterates through the values of the factors and powers
-        self.coef.iter().fold(0.0, |res, (p, f)| res + f * x.powi(*p))
+        match &self.coef {
+            // Horner scheme, from the highest power
+            Coefs::Dense(c) => c.iter().rev().fold(0.0, |res, f| res * x + f),
+            // Iterates through the values of the factors and powers
+            Coefs::Sparse(c) => c.iter().fold(0.0, |res, (p, f)| res + f * x.powi(*p))
+        }
     }
 
     /// # Computation for Legendre
     fn compute_legendre(&self, x: f64) -> f64 {
 
-        // Iterates through the values of the factors and powers
         let pre: f64 = (1.0 - x.powi(2)).powf(self.l.unwrap() / 2.0);
-        self.coef.iter().fold(0.0, |res, (p, f)| res + f * x.powi(*p)) * pre
+        self.compute_base(x) * pre
+    }
+
+    /// # Computing for a slice of real values
+    /// 
+    /// ## Inputs
+    /// - `x`: the values to evaluate
+    /// 
+    /// Returns the values of the polynomial for each $x$.
+    /// 
+    /// ## Example
+    /// ```
+    /// # use scilib::math::polynomial::Poly;
+    /// let p = Poly::from(&[(0, 1.0), (2, 2.0)]);
+    /// assert_eq!(p.compute_vec(&[0.0, 1.0, 2.0]), vec![1.0, 3.0, 9.0]);
+    /// ```
+    pub fn compute_vec(&self, x: &[f64]) -> Vec<f64> {
+        x.iter().map(|v| (self.compute_fn)(self, *v)).collect()
+    }
+
+    /// # Computing with the compensated Horner scheme
+    /// 
+    /// ## Definition
+    /// The [compensated Horner scheme](https://doi.org/10.1007/s10543-006-0066-6) follows the rounding errors of each
+    /// product and sum of the Horner scheme with error-free transformations, and adds them back at the end. The result
+    /// is as accurate as with the Horner scheme in twice the working precision, which matters close to multiple roots.
+    /// 
+    /// For generalized Legendre polynomials with an odd $l$, only the polynomial part is computed.
+    /// 
+    /// ## Inputs
+    /// - `x`: the value to evaluate
+    /// 
+    /// Returns the value of the polynomial for the given $x$.
+    /// 
+    /// ## Example
+    /// ```
+    /// # use scilib::math::polynomial::Poly;
+    /// // (x - 1)^5, with a lot of cancellation near 1
+    /// let p = Poly::from(&[(0, -1.0), (1, 1.0)]).pow(5);
+    /// let x: f64 = 1.0 + 1.0e-3;
+    /// let exact: f64 = (x - 1.0).powi(5);
+    /// assert!((p.compute_compensated(x) - exact).abs() < 1.0e-14 * exact);
+    /// assert!((p.compute(x) - exact).abs() > 1.0e-12 * exact);
+    /// ```
+    pub fn compute_compensated(&self, x: f64) -> f64 {
+
+        let coefs: Vec<f64> = self.dense_coefs();
+        let mut res: f64 = 0.0;
+        let mut err: f64 = 0.0;
+
+        for f in coefs.iter().rev() {
+            // Exact product, then exact sum
+            let prod: f64 = res * x;
+            let prod_err: f64 = res.mul_add(x, -prod);
+            let sum: f64 = prod + f;
+            let z: f64 = sum - prod;
+            let sum_err: f64 = (prod - (sum - z)) + (f - z);
+
+            res = sum;
+            err = err * x + (prod_err + sum_err);
+        }
+
+        res + err
     }
     /// # Computing for a complex value
     /// 
@@ -887,23 +1571,44 @@ impl Poly {
 
     /// # Compute the polynomial for a complex number
     fn compute_base_complex(&self, z: Complex64) -> Complex64 {
-
-        // Iterates through the values of the factors and powers
-        self.coef.iter().fold(Complex64::default(), |res, (p, f)| res + f * z.powi(*p))
+        match &self.coef {
+            // Horner scheme, from the highest power
+            Coefs::Dense(c) => c.iter().rev().fold(Complex64::default(), |res, f| res * z + f),
+            // Iterates through the values of the factors and powers
+            Coefs::Sparse(c) => c.iter().fold(Complex64::default(), |res, (p, f)| res + f * z.powi(*p))
+        }
     }
 
     /// # Computation for Legendre
     fn compute_legendre_complex(&self, z: Complex64) -> Complex64 {
 
-        // Iterates through the values of the factors and powers
         let pre: Complex64 = (1.0 - z.powi(2)).powf(self.l.unwrap() / 2.0);
-        self.coef.iter().fold(Complex64::default(), |res, (p, f)| res + f * z.powi(*p)) * pre
+        self.compute_base_complex(z) * pre
+    }
+
+    /// # Computing for a slice of complex values
+    /// 
+    /// ## Inputs
+    /// - `z`: the values to evaluate
+    /// 
+    /// Returns the values of the polynomial for each $z$.
+    /// 
+    /// ## Example
+    /// ```
+    /// # use num_complex::Complex64;
+    /// # use scilib::math::polynomial::Poly;
+    /// let p = Poly::from(&[(0, 1.0), (2, 1.0)]);
+    /// let res = p.compute_complex_vec(&[Complex64::i(), Complex64::new(1.0, 0.0)]);
+    /// assert_eq!(res, vec![Complex64::new(0.0, 0.0), Complex64::new(2.0, 0.0)]);
+    /// ```
+    pub fn compute_complex_vec(&self, z: &[Complex64]) -> Vec<Complex64> {
+        z.iter().map(|v| (self.compute_fnc)(self, *v)).collect()
     }
 
     /// # Polynomial orders
     /// 
     /// ## Definition
-    /// Extracts the maximum order of the polynomial. Used built-in series function.
+    /// Extracts the maximum order of the polynomial, zero for the null polynomial. Used built-in series function.
     /// 
     /// ## Example
     /// ```
@@ -913,12 +1618,493 @@ impl Poly {
     /// ```
     pub fn get_order(&self) -> i32 {
         // Collecting the powers
-        let p: Vec<i32> = self.coef.keys().map(|x| *x).collect();
+        let p: Vec<i32> = self.coef.to_map().keys().copied().chain([0]).collect();
 
         // Computing the max in the slice
         series::max_slice(&p)
     }
 
+    //////////////////////////////////////////////////
+    // Root finding
+
+    /// # Roots of the polynomial
+    /// 
+    /// ## Definition
+    /// Finds the $n$ complex roots $z_i$ of the polynomial of degree $n$, repeated according to their multiplicity:
+    /// $$
+    /// P_n(x) = a_n\prod_{i=1}^{n}(x - z_i)
+    /// $$
+    /// 
+    /// The roots at zero are removed first. Polynomials up to degree 4 are solved in closed form,
+    /// with the formulas of Cardano for cubics and of Ferrari for quartics. Higher degrees use the
+    /// [Aberth-Ehrlich method](https://en.wikipedia.org/wiki/Aberth_method), which refines all the roots
+    /// simultaneously. The roots are then polished with a few Newton iterations.
+    /// 
+    /// Real roots found by the iterative method can carry an imaginary part of the order of the rounding
+    /// errors; `real_roots` gives exactly real roots. For generalized Legendre polynomials with an odd $l$,
+    /// only the polynomial part is solved.
+    /// 
+    /// Returns the roots, in no particular order, and an empty vector for constant polynomials.
+    /// 
+    /// ## Example
+    /// ```
+    /// # use num_complex::Complex64;
+    /// # use scilib::math::polynomial::Poly;
+    /// // x^3 - x = x(x - 1)(x + 1)
+    /// let p = Poly::from(&[(1, -1.0), (3, 1.0)]);
+    /// let mut roots: Vec<f64> = p.roots().iter().map(|z| z.re).collect();
+    /// roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    /// assert_eq!(roots, vec![-1.0, 0.0, 1.0]);
+    /// 
+    /// // The roots of a Hermite polynomial of degree 7 cancel it
+    /// let h = Poly::hermite(7);
+    /// let roots: Vec<Complex64> = h.roots();
+    /// assert_eq!(roots.len(), 7);
+    /// assert!(roots.iter().all(|z| h.compute_complex(*z).norm() < 1.0e-9 && z.im.abs() < 1.0e-12));
+    /// ```
+    pub fn roots(&self) -> Vec<Complex64> {
+
+        let coefs: Vec<f64> = self.dense_coefs();
+
+        // Roots at zero, then the remaining polynomial
+        let zeros: usize = coefs.iter().position(|c| *c != 0.0).unwrap_or(0);
+        let c: &[f64] = &coefs[zeros..];
+        let mut roots: Vec<Complex64> = vec![Complex64::default(); zeros];
+
+        let found: Vec<Complex64> = match c.len().saturating_sub(1) {
+            0 => vec![],
+            1 => vec![Complex64::new(-c[0] / c[1], 0.0)],
+            2 => Self::quadratic_roots(c[2], c[1], c[0]).to_vec(),
+            3 => Self::cubic_roots(c),
+            4 => Self::quartic_roots(c),
+            _ => Self::aberth_roots(c)
+        };
+
+        roots.extend(found.iter().map(|z| Self::polish_root(c, *z)));
+        roots
+    }
+
+    /// # Real roots in an interval
+    /// 
+    /// ## Definition
+    /// Isolates the distinct real roots in the interval $(a, b]$ with the
+    /// [Sturm sequence](https://en.wikipedia.org/wiki/Sturm%27s_theorem) of the square-free part
+    /// $S = P / \gcd(P, P')$, which has the same distinct roots:
+    /// $$
+    /// p_0 = S,~ p_1 = S',~ p_{k+1} = -\mathrm{rem}(p_{k-1}, p_k)
+    /// $$
+    /// Each polynomial of the sequence is scaled before the next division, and the remainders are only trimmed of
+    /// the coefficients lost in the rounding errors, such that high degrees are handled.
+    /// The number of distinct roots in $(a, b]$ is the difference between the numbers of sign changes of the
+    /// sequence at $a$ and at $b$. The interval is split, slightly off its middle to avoid landing on simple roots,
+    /// until each part holds a single root, which is then refined by Newton iterations, safeguarded by bisection,
+    /// on $S$: $P$ is flattest at its multiple roots, and $S$ is $P$ itself when it has none. Infinite bounds are
+    /// replaced by a bound on the magnitude of the roots.
+    /// 
+    /// ## Inputs
+    /// - `a`: the lower bound, excluded
+    /// - `b`: the upper bound, included
+    /// 
+    /// Returns the distinct real roots in increasing order, each multiple root being given once.
+    /// 
+    /// ## Example
+    /// ```
+    /// # use scilib::math::polynomial::Poly;
+    /// # use scilib::math::orthogonal::Family;
+    /// # use scilib::math::quadrature::gauss_hermite;
+    /// // (x - 1)^2 (x + 2) (x - 3) = x^4 - 3x^3 - 3x^2 + 11x - 6
+    /// let p = Poly::from(&[(0, -6.0), (1, 11.0), (2, -3.0), (3, -3.0), (4, 1.0)]);
+    /// 
+    /// let all = p.real_roots(f64::NEG_INFINITY, f64::INFINITY);
+    /// assert_eq!(all.len(), 3);
+    /// for (r, e) in all.iter().zip(&[-2.0, 1.0, 3.0]) {
+    ///     assert!((r - e).abs() < 1.0e-12);
+    /// }
+    /// 
+    /// // Only the roots in (0, 3]
+    /// assert_eq!(p.real_roots(0.0, 3.0).len(), 2);
+    /// 
+    /// // Odd multiple roots are refined on the square-free part, where P is too flat
+    /// let cube = Poly::from(&[(0, -1.0), (1, 3.0), (2, -3.0), (3, 1.0)]);
+    /// let single = cube.real_roots(-5.0, 5.0);
+    /// assert!(single.len() == 1 && (single[0] - 1.0).abs() < 1.0e-12);
+    /// 
+    /// // Distinct roots at small scales are not merged
+    /// let close = Poly::from(&[(0, 2.0e-10), (1, -3.0e-5), (2, 1.0)]);  // (x - 1e-5)(x - 2e-5)
+    /// let r = close.real_roots(-1.0, 1.0);
+    /// assert!(r.len() == 2 && (r[0] - 1.0e-5).abs() < 1.0e-17 && (r[1] - 2.0e-5).abs() < 1.0e-17);
+    /// 
+    /// let r = Poly::from(&[(0, -2.0e-20), (2, 1.0)]).real_roots(f64::NEG_INFINITY, f64::INFINITY);
+    /// assert!(r.len() == 2 && (r[0] + 2.0e-20_f64.sqrt()).abs() < 1.0e-22 && (r[1] - 2.0e-20_f64.sqrt()).abs() < 1.0e-22);
+    /// 
+    /// let r = Poly::from(&[(0, -1.0e-30), (3, 1.0)]).real_roots(-1.0, 1.0);
+    /// assert!(r.len() == 1 && (r[0] - 1.0e-10).abs() < 1.0e-22);
+    /// 
+    /// // The 30 roots of the Hermite polynomial are the nodes of the Gauss-Hermite rule
+    /// let h = Family::Hermite.poly(30).real_roots(f64::NEG_INFINITY, f64::INFINITY);
+    /// let (nodes, _) = gauss_hermite(30);
+    /// assert_eq!(h.len(), 30);
+    /// for (r, x) in h.iter().zip(&nodes) {
+    ///     assert!((r - x).abs() < 1.0e-10);
+    /// }
+    /// ```
+    pub fn real_roots(&self, a: f64, b: f64) -> Vec<f64> {
+
+        assert!(a < b, "The lower bound must be less than the upper bound!");
+
+        let coefs: Vec<f64> = self.dense_coefs();
+        if coefs.len() < 2 {
+            return vec![];
+        }
+
+        // Cauchy bound on the magnitude of the roots
+        let lead: f64 = coefs[coefs.len() - 1];
+        let bound: f64 = 1.0 + coefs.iter().map(|c| (c / lead).abs()).fold(0.0, f64::max);
+        let (lo, hi): (f64, f64) = (a.max(-bound), b.min(bound));
+        if lo >= hi {
+            return vec![];
+        }
+
+        // Square-free part, as all the polynomials of a Sturm sequence would vanish at a multiple root
+        let common: Vec<f64> = Self::dense_gcd(&coefs, &Self::dense_derivative(&coefs));
+        let square_free: Vec<f64> = match common.len() {
+            1 => coefs.clone(),
+            _ => Self::dense_div_rem(&coefs, &common, 0.0).0
+        };
+        let sturm: Vec<Vec<f64>> = Self::sturm_sequence(&square_free);
+
+        let changes = |x: f64| -> usize {
+            let signs: Vec<f64> = sturm.iter().map(|p| Self::dense_eval(p, x)).filter(|v| *v != 0.0).collect();
+            signs.windows(2).filter(|w| w[0].signum() != w[1].signum()).count()
+        };
+
+        // Splitting until each interval holds a single root
+        let mut roots: Vec<f64> = vec![];
+        let mut stack: Vec<(f64, f64, usize, usize)> = vec![(lo, hi, changes(lo), changes(hi))];
+
+        while let Some((left, right, v_left, v_right)) = stack.pop() {
+            let count: usize = v_left.saturating_sub(v_right);
+            if count == 0 {
+                continue;
+            }
+
+            let mid: f64 = left + STURM_SPLIT * (right - left);
+            if count == 1 {
+                roots.push(Self::refine_root(&square_free, left, right));
+            } else if mid <= left || mid >= right {
+                // Roots closer than the resolution of the floating point numbers
+                roots.push(mid);
+            } else {
+                let v_mid: usize = changes(mid);
+                stack.push((left, mid, v_left, v_mid));
+                stack.push((mid, right, v_mid, v_right));
+            }
+        }
+
+        roots.sort_by(|x, y| x.partial_cmp(y).unwrap());
+        roots
+    }
+
+    /// # Coefficients in increasing powers, up to the degree
+    fn dense_coefs(&self) -> Vec<f64> {
+        match &self.coef {
+            Coefs::Dense(c) if c.is_empty() => vec![0.0],
+            Coefs::Dense(c) => c.clone(),
+            Coefs::Sparse(c) => {
+                let degree: usize = c.keys().last().map_or(0, |p| *p as usize);
+                let mut res: Vec<f64> = vec![0.0; degree + 1];
+                for (p, f) in c {
+                    res[*p as usize] = *f;
+                }
+                res
+            }
+        }
+    }
+
+    /// # Coefficients scaled by a positive factor, such that the largest has a magnitude of one
+    fn dense_normalize(coefs: &[f64]) -> Vec<f64> {
+        let size: f64 = coefs.iter().fold(0.0, |m, c| m.max(c.abs()));
+        coefs.iter().map(|c| c / size).collect()
+    }
+
+    /// # Horner evaluation of coefficients in increasing powers
+    fn dense_eval(coefs: &[f64], x: f64) -> f64 {
+        coefs.iter().rev().fold(0.0, |acc, c| acc * x + c)
+    }
+
+    /// # Derivative of coefficients in increasing powers
+    fn dense_derivative(coefs: &[f64]) -> Vec<f64> {
+        coefs.iter().enumerate().skip(1).map(|(k, c)| k as f64 * c).collect()
+    }
+
+    /// # Euclidean division of coefficients in increasing powers
+    /// 
+    /// The remainder is trimmed of its leading coefficients smaller than `tol` relative to the magnitude of the terms
+    /// cancelling into them, which is independent of the scale of the roots.
+    fn dense_div_rem(num: &[f64], den: &[f64], tol: f64) -> (Vec<f64>, Vec<f64>) {
+
+        let d: usize = den.len() - 1;
+        if num.len() <= d {
+            return (vec![], num.to_vec());
+        }
+
+        let mut rem: Vec<f64> = num.to_vec();
+        let mut size: Vec<f64> = num.iter().map(|c| c.abs()).collect();
+        let mut quot: Vec<f64> = vec![0.0; num.len() - d];
+        for k in (0..quot.len()).rev() {
+            let q: f64 = rem[k + d] / den[d];
+            quot[k] = q;
+            for (j, c) in den.iter().enumerate() {
+                rem[k + j] -= q * c;
+                size[k + j] += (q * c).abs();
+            }
+        }
+        rem.truncate(d);
+
+        while rem.last().is_some_and(|c| c.abs() <= tol * size[rem.len() - 1]) {
+            rem.pop();
+        }
+
+        (quot, rem)
+    }
+
+    /// # Monic form of coefficients in increasing powers, without the null leading ones
+    fn dense_monic(coefs: &[f64]) -> Vec<f64> {
+
+        let degree: usize = match coefs.iter().rposition(|c| *c != 0.0) {
+            Some(d) => d,
+            None => return vec![]
+        };
+
+        coefs[..=degree].iter().map(|c| c / coefs[degree]).collect()
+    }
+
+    /// # Monic greatest common divisor of coefficients in increasing powers
+    fn dense_gcd(a: &[f64], b: &[f64]) -> Vec<f64> {
+
+        let mut a: Vec<f64> = Self::dense_monic(a);
+        let mut b: Vec<f64> = Self::dense_monic(b);
+
+        while !b.is_empty() {
+            let (_, rem): (Vec<f64>, Vec<f64>) = Self::dense_div_rem(&a, &b, GCD_TOLERANCE);
+            a = b;
+            b = Self::dense_monic(&rem);
+        }
+
+        a
+    }
+
+    /// # Difference of coefficients in increasing powers
+    /// 
+    /// The result is trimmed of its leading coefficients smaller than `tol` relative to the terms they come from.
+    fn dense_sub(a: &[f64], b: &[f64], tol: f64) -> Vec<f64> {
+
+        let get = |c: &[f64], k: usize| -> f64 { c.get(k).copied().unwrap_or(0.0) };
+        let mut res: Vec<f64> = (0..a.len().max(b.len())).map(|k| get(a, k) - get(b, k)).collect();
+
+        while res.last().is_some_and(|c| c.abs() <= tol * (get(a, res.len() - 1).abs() + get(b, res.len() - 1).abs())) {
+            res.pop();
+        }
+
+        res
+    }
+
+    /// # Sturm sequence of coefficients in increasing powers
+    /// 
+    /// The polynomial is kept as is, such that its signs match its own evaluation, and the next ones are scaled to a
+    /// largest coefficient of magnitude one. The leading coefficients of the remainders lost in the rounding errors
+    /// are trimmed.
+    fn sturm_sequence(coefs: &[f64]) -> Vec<Vec<f64>> {
+
+        let mut sturm: Vec<Vec<f64>> = vec![coefs.to_vec(), Self::dense_normalize(&Self::dense_derivative(coefs))];
+        loop {
+            let num: &Vec<f64> = &sturm[sturm.len() - 2];
+            let den: &Vec<f64> = &sturm[sturm.len() - 1];
+            if den.len() < 2 {
+                break;
+            }
+
+            let (_, rem): (Vec<f64>, Vec<f64>) = Self::dense_div_rem(num, den, STURM_TOLERANCE);
+            if rem.is_empty() {
+                break;
+            }
+            sturm.push(Self::dense_normalize(&rem.iter().map(|v| -v).collect::<Vec<f64>>()));
+        }
+
+        sturm
+    }
+
+    /// # Single root in (a, b] of a polynomial changing sign, by Newton iterations safeguarded by bisection
+    fn refine_root(coefs: &[f64], a: f64, b: f64) -> f64 {
+
+        let deriv: Vec<f64> = Self::dense_derivative(coefs);
+        let (mut lo, mut hi): (f64, f64) = (a, b);
+        let f_lo: f64 = Self::dense_eval(coefs, lo);
+
+        if Self::dense_eval(coefs, hi) == 0.0 {
+            return hi;
+        }
+
+        let mut x: f64 = 0.5 * (lo + hi);
+        for _ in 0..ROOTS_ITERATIONS {
+            let f: f64 = Self::dense_eval(coefs, x);
+            if f == 0.0 {
+                return x;
+            }
+
+            // Keeping the sign change inside the bracket
+            if f.signum() == f_lo.signum() {
+                lo = x;
+            } else {
+                hi = x;
+            }
+
+            let newton: f64 = x - f / Self::dense_eval(&deriv, x);
+            let next: f64 = if newton > lo && newton < hi { newton } else { 0.5 * (lo + hi) };
+            if (next - x).abs() <= f64::EPSILON * x.abs() || hi - lo <= f64::EPSILON * x.abs() {
+                return next;
+            }
+            x = next;
+        }
+
+        x
+    }
+
+    /// # Roots of a quadratic with real coefficients, avoiding cancellations
+    fn quadratic_roots(a: f64, b: f64, c: f64) -> [Complex64; 2] {
+
+        let disc: f64 = b * b - 4.0 * a * c;
+
+        if disc >= 0.0 {
+            let q: f64 = -0.5 * (b + if b >= 0.0 { disc.sqrt() } else { -disc.sqrt() });
+            if q == 0.0 {
+                return [Complex64::default(); 2];
+            }
+            [Complex64::new(q / a, 0.0), Complex64::new(c / q, 0.0)]
+        } else {
+            let re: f64 = -b / (2.0 * a);
+            let im: f64 = (-disc).sqrt() / (2.0 * a.abs());
+            [Complex64::new(re, im), Complex64::new(re, -im)]
+        }
+    }
+
+    /// # Roots of a cubic, given in increasing powers, by the formulas of Cardano and Viète
+    fn cubic_roots(c: &[f64]) -> Vec<Complex64> {
+
+        // Monic form x^3 + bx^2 + cx + d, and depressed form t^3 + pt + q with x = t - b/3
+        let (b, cc, d): (f64, f64, f64) = (c[2] / c[3], c[1] / c[3], c[0] / c[3]);
+        let p: f64 = cc - b * b / 3.0;
+        let q: f64 = 2.0 * b.powi(3) / 27.0 - b * cc / 3.0 + d;
+        let disc: f64 = (q / 2.0).powi(2) + (p / 3.0).powi(3);
+
+        if disc > 0.0 {
+            // One real root, the other two from the deflated quadratic
+            let u: f64 = -q.signum() * (q.abs() / 2.0 + disc.sqrt()).cbrt();
+            let v: f64 = if u != 0.0 { -p / (3.0 * u) } else { 0.0 };
+            let x: f64 = u + v - b / 3.0;
+
+            let e: f64 = b + x;
+            let f: f64 = if x != 0.0 { -d / x } else { cc + x * e };
+            let [z1, z2] = Self::quadratic_roots(1.0, e, f);
+            vec![Complex64::new(x, 0.0), z1, z2]
+        } else if p == 0.0 {
+            vec![Complex64::new(-b / 3.0, 0.0); 3]
+        } else {
+            // Three real roots, by the trigonometric method
+            let r: f64 = (-p / 3.0).sqrt();
+            let phi: f64 = ((3.0 * q / (2.0 * p)) * (-3.0 / p).sqrt()).clamp(-1.0, 1.0).acos() / 3.0;
+            (0..3).map(|k| {
+                Complex64::new(2.0 * r * (phi - 2.0 * std::f64::consts::PI * k as f64 / 3.0).cos() - b / 3.0, 0.0)
+            }).collect()
+        }
+    }
+
+    /// # Roots of a quartic, given in increasing powers, by the method of Ferrari
+    fn quartic_roots(c: &[f64]) -> Vec<Complex64> {
+
+        // Monic form, and depressed form y^4 + py^2 + qy + r with x = y - b/4
+        let (b, cc, d, e): (f64, f64, f64, f64) = (c[3] / c[4], c[2] / c[4], c[1] / c[4], c[0] / c[4]);
+        let p: f64 = cc - 3.0 * b * b / 8.0;
+        let q: f64 = d - b * cc / 2.0 + b.powi(3) / 8.0;
+        let r: f64 = e - b * d / 4.0 + b * b * cc / 16.0 - 3.0 * b.powi(4) / 256.0;
+
+        let ys: Vec<Complex64> = if q == 0.0 {
+            // Biquadratic, solved for y^2
+            Self::quadratic_roots(1.0, p, r).iter().flat_map(|z| [z.sqrt(), -z.sqrt()]).collect()
+        } else {
+            // Largest root of the resolvent cubic, positive, splitting the quartic in two quadratics
+            let resolvent: Vec<f64> = vec![-q * q, 2.0 * p * p - 8.0 * r, 8.0 * p, 8.0];
+            let m: f64 = Self::cubic_roots(&resolvent).iter().filter(|z| z.im == 0.0).map(|z| z.re).fold(f64::MIN, f64::max);
+            let s: f64 = (2.0 * m).sqrt();
+
+            let mut res: Vec<Complex64> = Self::quadratic_roots(1.0, -s, p / 2.0 + m + q / (2.0 * s)).to_vec();
+            res.extend(Self::quadratic_roots(1.0, s, p / 2.0 + m - q / (2.0 * s)));
+            res
+        };
+
+        ys.iter().map(|y| y - b / 4.0).collect()
+    }
+
+    /// # Roots of a polynomial, given in increasing powers, by the Aberth-Ehrlich method
+    fn aberth_roots(c: &[f64]) -> Vec<Complex64> {
+
+        let degree: usize = c.len() - 1;
+        let deriv: Vec<f64> = Self::dense_derivative(c);
+        let eval = |coefs: &[f64], z: Complex64| coefs.iter().rev().fold(Complex64::default(), |acc, v| acc * z + v);
+
+        // Starting points on a circle enclosing the roots
+        let radius: f64 = c[..degree].iter().rev().enumerate().fold(0.0_f64, |m, (k, v)| {
+            m.max((v / c[degree]).abs().powf(1.0 / (k + 1) as f64))
+        });
+        let mut z: Vec<Complex64> = (0..degree).map(|k| {
+            Complex64::from_polar(radius.max(1.0e-3), 2.0 * std::f64::consts::PI * k as f64 / degree as f64 + 0.4)
+        }).collect();
+
+        for _ in 0..ROOTS_ITERATIONS {
+            let mut max_step: f64 = 0.0;
+            for i in 0..degree {
+                let value: Complex64 = eval(c, z[i]);
+                if value.norm() == 0.0 {
+                    continue;
+                }
+                let ratio: Complex64 = value / eval(&deriv, z[i]);
+                let repulsion: Complex64 = (0..degree).filter(|j| *j != i).map(|j| 1.0 / (z[i] - z[j])).sum();
+                let step: Complex64 = ratio / (1.0 - ratio * repulsion);
+                z[i] -= step;
+                max_step = max_step.max(step.norm() / z[i].norm().max(1.0));
+            }
+            if max_step < 1.0e-15 {
+                break;
+            }
+        }
+
+        z
+    }
+
+    /// # Newton polishing of a root, keeping the iterations that decrease the residual
+    fn polish_root(c: &[f64], root: Complex64) -> Complex64 {
+
+        let deriv: Vec<f64> = Self::dense_derivative(c);
+        let eval = |coefs: &[f64], z: Complex64| coefs.iter().rev().fold(Complex64::default(), |acc, v| acc * z + v);
+
+        let mut z: Complex64 = root;
+        let mut value: Complex64 = eval(c, z);
+        for _ in 0..POLISH_ITERATIONS {
+            let slope: Complex64 = eval(&deriv, z);
+            if value.norm() == 0.0 || slope.norm() == 0.0 {
+                break;
+            }
+            let next: Complex64 = z - value / slope;
+            let next_value: Complex64 = eval(c, next);
+            if next_value.norm() >= value.norm() {
+                break;
+            }
+            (z, value) = (next, next_value);
+        }
+
+        z
+    }
+
     //////////////////////////////////////////////////
     // Specific methods to generate coefficients
 
@@ -1109,7 +2295,7 @@ impl Poly {
 impl std::cmp::PartialEq for Poly {
     fn eq(&self, other: &Self) -> bool {
 
-        let c: bool = self.coef == other.coef;
+        let c: bool = self.coef.to_map() == other.coef.to_map();
         let l: bool = self.l == other.l;
 
         c & l
@@ -1129,11 +2315,8 @@ impl<T: Into<f64>> std::ops::Add<T> for Poly {
     type Output = Self;
     fn add(self, rhs: T) -> Self::Output {
 
-        let mut coef = self.coef.clone();
-        match coef.get_mut(&0) {
-            Some(v) => *v += rhs.into(),
-            None => { coef.insert(0, rhs.into()); }
-        }
+        let mut coef: Coefs = self.coef.clone();
+        coef.add(0, rhs.into());
 
         Self {
             coef,
@@ -1156,12 +2339,9 @@ impl std::ops::Add<Self> for Poly {
     type Output = Self;
     fn add(self, rhs: Poly) -> Self::Output {
 
-        let mut coef = self.coef.clone();
-        for (c, f) in &rhs.coef {
-            match coef.get_mut(c) {
-                Some(v) => *v += f,
-                None => { coef.insert(*c, *f); }
-            }
+        let mut coef: Coefs = self.coef.clone();
+        for (c, f) in rhs.coef.to_map() {
+            coef.add(c, f);
         }
         
         Poly {
@@ -1182,10 +2362,7 @@ impl std::ops::Add<Self> for Poly {
 /// ```
 impl<T: Into<f64>> std::ops::AddAssign<T> for Poly {
     fn add_assign(&mut self, rhs: T) {
-        match self.coef.get_mut(&0) {
-            Some(v) => *v += rhs.into(),
-            None => { self.coef.insert(0, rhs.into()); }
-        }
+        self.coef.add(0, rhs.into());
     }
 }
 
@@ -1201,11 +2378,8 @@ impl<T: Into<f64>> std::ops::AddAssign<T> for Poly {
 /// ```
 impl std::ops::AddAssign<Self> for Poly {
     fn add_assign(&mut self, rhs: Self) {
-        for (c, f) in &rhs.coef {
-            match self.coef.get_mut(c) {
-                Some(v) => *v += f,
-                None => { self.coef.insert(*c, *f); }
-            }
+        for (c, f) in rhs.coef.to_map() {
+            self.coef.add(c, f);
         }
     }
 }
@@ -1223,11 +2397,8 @@ impl<T: Into<f64>> std::ops::Sub<T> for Poly {
     type Output = Self;
     fn sub(self, rhs: T) -> Self::Output {
 
-        let mut coef = self.coef.clone();
-        match coef.get_mut(&0) {
-            Some(v) => *v -= rhs.into(),
-            None => { coef.insert(0, -rhs.into()); }
-        }
+        let mut coef: Coefs = self.coef.clone();
+        coef.add(0, -rhs.into());
 
         Self {
             coef,
@@ -1250,12 +2421,9 @@ impl std::ops::Sub<Self> for Poly {
     type Output = Self;
     fn sub(self, rhs: Poly) -> Self::Output {
 
-        let mut coef = self.coef.clone();
-        for (c, f) in &rhs.coef {
-            match coef.get_mut(c) {
-                Some(v) => *v -= f,
-                None => { coef.insert(*c, -*f); }
-            }
+        let mut coef: Coefs = self.coef.clone();
+        for (c, f) in rhs.coef.to_map() {
+            coef.add(c, -f);
         }
         
         Poly {
@@ -1276,10 +2444,7 @@ impl std::ops::Sub<Self> for Poly {
 /// ```
 impl<T: Into<f64>> std::ops::SubAssign<T> for Poly {
     fn sub_assign(&mut self, rhs: T) {
-        match self.coef.get_mut(&0) {
-            Some(v) => *v -= rhs.into(),
-            None => { self.coef.insert(0, -rhs.into()); }
-        }
+        self.coef.add(0, -rhs.into());
     }
 }
 
@@ -1295,11 +2460,8 @@ impl<T: Into<f64>> std::ops::SubAssign<T> for Poly {
 /// ```
 impl std::ops::SubAssign<Self> for Poly {
     fn sub_assign(&mut self, rhs: Self) {
-        for (c, f) in &rhs.coef {
-            match self.coef.get_mut(c) {
-                Some(v) => *v -= f,
-                None => { self.coef.insert(*c, -*f); }
-            }
+        for (c, f) in rhs.coef.to_map() {
+            self.coef.add(c, -f);
         }
     }
 }
@@ -1318,11 +2480,8 @@ impl<T: Into<f64>> std::ops::Mul<T> for Poly {
     fn mul(self, rhs: T) -> Self::Output {
 
         let rhs_conv: f64 = rhs.into();
-        let mut coef = self.coef.clone();
-
-        for f in coef.values_mut() {
-            *f *= rhs_conv;
-        }
+        let mut coef: Coefs = self.coef.clone();
+        coef.map_values(|f| f * rhs_conv);
         
         Self {
             coef,
@@ -1344,27 +2503,8 @@ impl<T: Into<f64>> std::ops::Mul<T> for Poly {
 impl std::ops::Mul<Self> for Poly {
     type Output = Self;
     fn mul(self, rhs: Poly) -> Self::Output {
-
-        let mut n_p: i32;
-        let mut n_f: f64;
-
-        let mut coef: HashMap<i32, f64> = HashMap::new();
-
-        for (p, f) in self.coef.iter() {
-
-            for (rhs_p, rhs_f) in &rhs.coef {
-                n_p = p + rhs_p;
-                n_f = f * rhs_f;
-
-                match coef.get_mut(&n_p) {
-                    Some(v) => *v += n_f,
-                    None => { coef.insert(n_p, n_f); }
-                }
-            }
-        };
-        
         Poly {
-            coef,
+            coef: self.coef.mul(&rhs.coef),
             ..self
         }
     }    
@@ -1382,9 +2522,7 @@ impl std::ops::Mul<Self> for Poly {
 impl<T: Into<f64>> std::ops::MulAssign<T> for Poly {
     fn mul_assign(&mut self, rhs: T) {
         let rhs_conv: f64 = rhs.into();
-        for f in self.coef.values_mut() {
-            *f *= rhs_conv;
-        }
+        self.coef.map_values(|f| f * rhs_conv);
     }
 }
 
@@ -1400,25 +2538,7 @@ impl<T: Into<f64>> std::ops::MulAssign<T> for Poly {
 /// ```
 impl std::ops::MulAssign<Self> for Poly {
     fn mul_assign(&mut self, rhs: Self) {
-        let mut n_p: i32;
-        let mut n_f: f64;
-
-        let mut coef: HashMap<i32, f64> = HashMap::new();
-
-        for (p, f) in self.coef.iter() {
-
-            for (rhs_p, rhs_f) in &rhs.coef {
-                n_p = p + rhs_p;
-                n_f = f * rhs_f;
-
-                match coef.get_mut(&n_p) {
-                    Some(v) => *v += n_f,
-                    None => { coef.insert(n_p, n_f); }
-                }
-            }
-        };
-
-        self.coef = coef;
+        self.coef = self.coef.mul(&rhs.coef);
     }
 }
 
@@ -1436,11 +2556,8 @@ impl<T: Into<f64>> std::ops::Div<T> for Poly {
     fn div(self, rhs: T) -> Self::Output {
 
         let rhs_conv: f64 = rhs.into();
-        let mut coef = self.coef.clone();
-
-        for f in coef.values_mut() {
-            *f /= rhs_conv;
-        }
+        let mut coef: Coefs = self.coef.clone();
+        coef.map_values(|f| f / rhs_conv);
         
         Self {
             coef,
@@ -1449,6 +2566,36 @@ impl<T: Into<f64>> std::ops::Div<T> for Poly {
     }
 }
 
+/// # Division by Poly
+/// 
+/// Gives the quotient of the Euclidean division, see `div_rem`.
+/// 
+/// ```
+/// # use scilib::math::polynomial::{ Poly, Storage };
+/// let p1 = Poly::from(&[(0, -1.0), (2, 1.0)]);
+/// let p2 = Poly::from(&[(0, 1.0), (1, 1.0)]);
+/// let res = p1 / p2;
+/// let expected = Poly::from(&[(0, -1.0), (1, 1.0)]);
+/// assert_eq!(res, expected);
+/// 
+/// // The storage of the dividend is kept
+/// let mut p3 = Poly::from(&[(0, -1.0), (2, 1.0)]);
+/// p3.set_storage(Storage::Sparse);
+/// assert_eq!((p3 / Poly::from(&[(0, 1.0), (1, 1.0)])).get_storage(), Storage::Sparse);
+/// ```
+impl std::ops::Div<Self> for Poly {
+    type Output = Self;
+    fn div(self, rhs: Poly) -> Self::Output {
+
+        let res: Poly = self.div_rem(&rhs).0;
+
+        Self {
+            coef: Coefs::from_map(res.get_coefs(), self.get_storage()),
+            ..self
+        }
+    }
+}
+
 /// # Division assigned
 /// 
 /// ```
@@ -1461,12 +2608,72 @@ impl<T: Into<f64>> std::ops::Div<T> for Poly {
 impl<T: Into<f64>> std::ops::DivAssign<T> for Poly {
     fn div_assign(&mut self, rhs: T) {
         let rhs_conv: f64 = rhs.into();
-        for f in self.coef.values_mut() {
-            *f /= rhs_conv;
+        self.coef.map_values(|f| f / rhs_conv);
+    }
+}
+
+/// # Division assigned of Poly
+/// 
+/// ```
+/// # use scilib::math::polynomial::Poly;
+/// let mut p1 = Poly::from(&[(0, -1.0), (2, 1.0)]);
+/// let p2 = Poly::from(&[(0, -1.0), (1, 1.0)]);
+/// p1 /= p2;
+/// let expected = Poly::from(&[(0, 1.0), (1, 1.0)]);
+/// assert_eq!(p1, expected);
+/// ```
+impl std::ops::DivAssign<Self> for Poly {
+    fn div_assign(&mut self, rhs: Self) {
+        self.coef = Coefs::from_map(self.div_rem(&rhs).0.get_coefs(), self.get_storage());
+    }
+}
+
+/// # Remainder
+/// 
+/// Gives the remainder of the Euclidean division, see `div_rem`.
+/// 
+/// ```
+/// # use scilib::math::polynomial::{ Poly, Storage };
+/// let p1 = Poly::from(&[(0, 2.0), (2, 1.0)]);
+/// let p2 = Poly::from(&[(0, 1.0), (1, 1.0)]);
+/// let res = p1 % p2;
+/// let expected = Poly::from(&[(0, 3.0)]);
+/// assert_eq!(res, expected);
+/// 
+/// // The storage of the dividend is kept
+/// let mut p3 = Poly::from(&[(0, 2.0), (2, 1.0)]);
+/// p3.set_storage(Storage::Sparse);
+/// assert_eq!((p3 % Poly::from(&[(0, 1.0), (1, 1.0)])).get_storage(), Storage::Sparse);
+/// ```
+impl std::ops::Rem<Self> for Poly {
+    type Output = Self;
+    fn rem(self, rhs: Poly) -> Self::Output {
+
+        let res: Poly = self.div_rem(&rhs).1;
+
+        Self {
+            coef: Coefs::from_map(res.get_coefs(), self.get_storage()),
+            ..self
         }
     }
 }
 
+/// # Remainder assigned
+/// 
+/// ```
+/// # use scilib::math::polynomial::Poly;
+/// let mut p1 = Poly::from(&[(0, 2.0), (2, 1.0)]);
+/// let p2 = Poly::from(&[(0, 1.0), (1, 1.0)]);
+/// p1 %= p2;
+/// let expected = Poly::from(&[(0, 3.0)]);
+/// assert_eq!(p1, expected);
+/// ```
+impl std::ops::RemAssign<Self> for Poly {
+    fn rem_assign(&mut self, rhs: Self) {
+        self.coef = Coefs::from_map(self.div_rem(&rhs).1.get_coefs(), self.get_storage());
+    }
+}
+
 /// # Negation
 /// 
 /// ```
@@ -0,0 +1,459 @@
+//!
+//! # Gaussian quadrature
+//! 
+//! [Gaussian quadrature](https://en.wikipedia.org/wiki/Gaussian_quadrature) rules approximate a weighted integral by
+//! a weighted sum over $n$ nodes:
+//! $$
+//! \int_a^b w(x)f(x)dx \approx \sum_{i=1}^{n}w_if(x_i)
+//! $$
+//! which is exact when $f$ is a polynomial of degree up to $2n-1$. The nodes are the roots of the polynomial of
+//! degree $n$ of the family orthogonal for the weight $w$.
+//! 
+//! The rules are computed with the [Golub-Welsch algorithm](https://doi.org/10.1090/S0025-5718-69-99647-1): the nodes
+//! are the eigenvalues of the symmetric tridiagonal Jacobi matrix built from the three-term recurrence of the family,
+//! and the weights come from the first components of its eigenvectors. Any of the families of `math::orthogonal`
+//! can be used, with dedicated functions for the usual rules, along with the Gauss-Lobatto and Gauss-Radau rules
+//! including the ends of the interval.
+//! 
+//! ```
+//! # use scilib::math::quadrature::{ gauss_hermite, integrate };
+//! // Integral of x^2 exp(-x^2) over the real line
+//! let (nodes, weights) = gauss_hermite(10);
+//! let res: f64 = nodes.iter().zip(&weights).map(|(x, w)| w * x * x).sum();
+//! assert!((res - std::f64::consts::PI.sqrt() / 2.0).abs() < 1.0e-14);
+//! 
+//! // Integral of a function on an interval
+//! let res: f64 = integrate(|x| x.sin(), 0.0, std::f64::consts::PI, 20);
+//! assert!((res - 2.0).abs() < 1.0e-14);
+//! ```
+//! 
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use std::f64::consts::PI;       // Pi
+
+use super::{                    // Using the parent module
+    basic,                      // Logarithm of the gamma function
+    orthogonal::Family          // Three-term recurrences
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Maximum number of QL iterations per eigenvalue of the Jacobi matrix
+const QL_ITERATIONS: usize = 60;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Gaussian quadrature of a family
+/// 
+/// ## Definition
+/// Computes the rule of $n$ nodes for the weight of an orthogonal family. The monic recurrence
+/// $p_{k+1} = (x - \alpha_k)p_k - \beta_kp_{k-1}$ of the family gives the Jacobi matrix:
+/// $$
+/// J = \begin{pmatrix}
+/// \alpha_0 & \sqrt{\beta_1} & & \\\\
+/// \sqrt{\beta_1} & \alpha_1 & \ddots & \\\\
+/// & \ddots & \ddots & \sqrt{\beta_{n-1}} \\\\
+/// & & \sqrt{\beta_{n-1}} & \alpha_{n-1}
+/// \end{pmatrix}
+/// $$
+/// whose eigenvalues are the nodes $x_i$. With $v_i$ the normalized eigenvectors, the weights are
+/// $w_i = \mu_0 v_{i,0}^2$, where $\mu_0$ is the integral of the weight function.
+/// 
+/// ## Inputs
+/// - `family`: the orthogonal family, defining the weight
+/// - `n`: the number of nodes
+/// 
+/// Returns the nodes in increasing order, and their weights.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::orthogonal::Family;
+/// # use scilib::math::quadrature::gauss;
+/// // Integral of x^2 sqrt(1 - x^2) on [-1, 1], with the weight of the Chebyshev polynomials of the second kind
+/// let (nodes, weights) = gauss(Family::ChebyshevU, 4);
+/// let res: f64 = nodes.iter().zip(&weights).map(|(x, w)| w * x * x).sum();
+/// assert!((res - std::f64::consts::PI / 8.0).abs() < 1.0e-15);
+/// ```
+pub fn gauss(family: Family, n: usize) -> (Vec<f64>, Vec<f64>) {
+
+    if n == 0 {
+        return (vec![], vec![]);
+    }
+
+    // Monic recurrence from P_{k+1} = (a_k x + b_k) P_k - c_k P_{k-1}
+    let coefs: Vec<(f64, f64, f64)> = (0..n).map(|k| family.recurrence(k)).collect();
+    let diag: Vec<f64> = coefs.iter().map(|(a, b, _)| -b / a).collect();
+    let mut off: Vec<f64> = (1..n).map(|k| (coefs[k].2 / (coefs[k - 1].0 * coefs[k].0)).sqrt()).collect();
+    off.push(0.0);
+
+    let (nodes, first): (Vec<f64>, Vec<f64>) = tridiagonal_eigen(diag, off);
+    let moment: f64 = weight_integral(family);
+
+    let mut rule: Vec<(f64, f64)> = nodes.iter().zip(&first).map(|(x, v)| (*x, moment * v * v)).collect();
+    rule.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+    rule.into_iter().unzip()
+}
+
+/// # Gauss-Legendre quadrature
+/// 
+/// ## Definition
+/// Rule of $n$ nodes on $[-1, 1]$ for the weight $w(x) = 1$, exact for polynomials of degree up to $2n-1$.
+/// 
+/// ## Inputs
+/// - `n`: the number of nodes
+/// 
+/// Returns the nodes in increasing order, and their weights.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::quadrature::gauss_legendre;
+/// let (nodes, weights) = gauss_legendre(3);
+/// assert!((nodes[2] - 0.6_f64.sqrt()).abs() < 1.0e-15);
+/// assert!((weights[1] - 8.0 / 9.0).abs() < 1.0e-15);
+/// 
+/// // Exact for x^4
+/// let res: f64 = nodes.iter().zip(&weights).map(|(x, w)| w * x.powi(4)).sum();
+/// assert!((res - 0.4).abs() < 1.0e-15);
+/// ```
+pub fn gauss_legendre(n: usize) -> (Vec<f64>, Vec<f64>) {
+    gauss(Family::Legendre, n)
+}
+
+/// # Gauss-Laguerre quadrature
+/// 
+/// ## Definition
+/// Rule of $n$ nodes on $[0, \infty)$ for the weight $w(x) = x^\alpha e^{-x}$, with $\alpha > -1$.
+/// 
+/// ## Inputs
+/// - `n`: the number of nodes
+/// - `alpha`: the power of the weight
+/// 
+/// Returns the nodes in increasing order, and their weights.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::quadrature::gauss_laguerre;
+/// // Integral of x^3 exp(-x) = 3!
+/// let (nodes, weights) = gauss_laguerre(4, 0.0);
+/// let res: f64 = nodes.iter().zip(&weights).map(|(x, w)| w * x.powi(3)).sum();
+/// assert!((res - 6.0).abs() < 1.0e-13);
+/// ```
+pub fn gauss_laguerre<T>(n: usize, alpha: T) -> (Vec<f64>, Vec<f64>)
+where T: Into<f64> {
+    gauss(Family::Laguerre { alpha: alpha.into() }, n)
+}
+
+/// # Gauss-Hermite quadrature
+/// 
+/// ## Definition
+/// Rule of $n$ nodes on the real line for the weight $w(x) = e^{-x^2}$ of the physicists' Hermite polynomials.
+/// The rule for the weight $e^{-x^2/2}$ is given by `gauss(Family::HermiteProb, n)`.
+/// 
+/// ## Inputs
+/// - `n`: the number of nodes
+/// 
+/// Returns the nodes in increasing order, and their weights.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::quadrature::gauss_hermite;
+/// // Integral of cos(x) exp(-x^2) = sqrt(pi) exp(-1/4)
+/// let (nodes, weights) = gauss_hermite(20);
+/// let res: f64 = nodes.iter().zip(&weights).map(|(x, w)| w * x.cos()).sum();
+/// assert!((res - std::f64::consts::PI.sqrt() * (-0.25_f64).exp()).abs() < 1.0e-14);
+/// ```
+pub fn gauss_hermite(n: usize) -> (Vec<f64>, Vec<f64>) {
+    gauss(Family::Hermite, n)
+}
+
+/// # Gauss-Jacobi quadrature
+/// 
+/// ## Definition
+/// Rule of $n$ nodes on $[-1, 1]$ for the weight $w(x) = (1-x)^\alpha(1+x)^\beta$, with $\alpha, \beta > -1$,
+/// suited to integrands with algebraic singularities at the ends of the interval.
+/// 
+/// ## Inputs
+/// - `n`: the number of nodes
+/// - `alpha`, `beta`: the powers of the weight
+/// 
+/// Returns the nodes in increasing order, and their weights.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::quadrature::gauss_jacobi;
+/// // Integral of sqrt(1 - x) on [-1, 1] = 4 sqrt(2) / 3
+/// let (nodes, weights) = gauss_jacobi(2, 0.5, 0.0);
+/// let res: f64 = weights.iter().sum();
+/// assert!((res - 4.0 * 2.0_f64.sqrt() / 3.0).abs() < 1.0e-15);
+/// ```
+pub fn gauss_jacobi<T, U>(n: usize, alpha: T, beta: U) -> (Vec<f64>, Vec<f64>)
+where T: Into<f64>, U: Into<f64> {
+    gauss(Family::Jacobi { alpha: alpha.into(), beta: beta.into() }, n)
+}
+
+/// # Gauss-Chebyshev quadrature
+/// 
+/// ## Definition
+/// Rule of $n$ nodes on $[-1, 1]$ for the weight $w(x) = (1-x^2)^{-1/2}$, known in closed form:
+/// $$
+/// x_i = \cos\left(\frac{(2i-1)\pi}{2n}\right),~ w_i = \frac{\pi}{n}
+/// $$
+/// The rules for the other kinds are given by `gauss` with the other Chebyshev families.
+/// 
+/// ## Inputs
+/// - `n`: the number of nodes
+/// 
+/// Returns the nodes in increasing order, and their weights.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::quadrature::gauss_chebyshev;
+/// // Integral of x^2 / sqrt(1 - x^2) on [-1, 1] = pi / 2
+/// let (nodes, weights) = gauss_chebyshev(5);
+/// let res: f64 = nodes.iter().zip(&weights).map(|(x, w)| w * x * x).sum();
+/// assert!((res - std::f64::consts::FRAC_PI_2).abs() < 1.0e-15);
+/// ```
+pub fn gauss_chebyshev(n: usize) -> (Vec<f64>, Vec<f64>) {
+
+    let nodes: Vec<f64> = (1..=n).rev().map(|i| ((2 * i - 1) as f64 * PI / (2 * n) as f64).cos()).collect();
+
+    (nodes, vec![PI / n as f64; n])
+}
+
+/// # Gauss-Lobatto quadrature
+/// 
+/// ## Definition
+/// Rule of $n \ge 2$ nodes on $[-1, 1]$ for the weight $w(x) = 1$, including both ends of the interval, and exact for
+/// polynomials of degree up to $2n-3$. The interior nodes are the roots of $P'_{n-1}$, and are given by the
+/// Gauss-Jacobi rule with $\alpha = \beta = 1$:
+/// $$
+/// w_{\pm1} = \frac{2}{n(n-1)},~ w_i = \frac{w_i^{(1,1)}}{1-x_i^2}
+/// $$
+/// 
+/// ## Inputs
+/// - `n`: the number of nodes, at least 2
+/// 
+/// Returns the nodes in increasing order, and their weights.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::quadrature::gauss_lobatto;
+/// // Simpson's rule for 3 nodes
+/// let (nodes, weights) = gauss_lobatto(3);
+/// assert_eq!(nodes, vec![-1.0, 0.0, 1.0]);
+/// assert!((weights[1] - 4.0 / 3.0).abs() < 1.0e-14);
+/// 
+/// // Exact for x^6 with 5 nodes
+/// let (nodes, weights) = gauss_lobatto(5);
+/// let res: f64 = nodes.iter().zip(&weights).map(|(x, w)| w * x.powi(6)).sum();
+/// assert!((res - 2.0 / 7.0).abs() < 1.0e-15);
+/// ```
+pub fn gauss_lobatto(n: usize) -> (Vec<f64>, Vec<f64>) {
+
+    assert!(n >= 2, "The Gauss-Lobatto rule needs at least 2 nodes!");
+
+    let end: f64 = 2.0 / (n * (n - 1)) as f64;
+    let (inner, inner_weights): (Vec<f64>, Vec<f64>) = gauss_jacobi(n - 2, 1.0, 1.0);
+
+    let mut nodes: Vec<f64> = vec![-1.0];
+    let mut weights: Vec<f64> = vec![end];
+    for (x, w) in inner.iter().zip(&inner_weights) {
+        nodes.push(*x);
+        weights.push(w / (1.0 - x * x));
+    }
+    nodes.push(1.0);
+    weights.push(end);
+
+    (nodes, weights)
+}
+
+/// # Gauss-Radau quadrature
+/// 
+/// ## Definition
+/// Rule of $n \ge 1$ nodes on $[-1, 1]$ for the weight $w(x) = 1$, including the lower end of the interval, and exact
+/// for polynomials of degree up to $2n-2$. The other nodes are given by the Gauss-Jacobi rule with
+/// $\alpha = 0$ and $\beta = 1$:
+/// $$
+/// w_{-1} = \frac{2}{n^2},~ w_i = \frac{w_i^{(0,1)}}{1+x_i}
+/// $$
+/// The rule including the upper end instead is obtained by negating the nodes.
+/// 
+/// ## Inputs
+/// - `n`: the number of nodes, at least 1
+/// 
+/// Returns the nodes in increasing order, and their weights.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::quadrature::gauss_radau;
+/// let (nodes, weights) = gauss_radau(2);
+/// assert_eq!(nodes[0], -1.0);
+/// assert!((nodes[1] - 1.0 / 3.0).abs() < 1.0e-15);
+/// assert!((weights[1] - 1.5).abs() < 1.0e-15);
+/// 
+/// // Exact for x^6 with 4 nodes
+/// let (nodes, weights) = gauss_radau(4);
+/// let res: f64 = nodes.iter().zip(&weights).map(|(x, w)| w * x.powi(6)).sum();
+/// assert!((res - 2.0 / 7.0).abs() < 1.0e-15);
+/// ```
+pub fn gauss_radau(n: usize) -> (Vec<f64>, Vec<f64>) {
+
+    assert!(n >= 1, "The Gauss-Radau rule needs at least 1 node!");
+
+    let (inner, inner_weights): (Vec<f64>, Vec<f64>) = gauss_jacobi(n - 1, 0.0, 1.0);
+
+    let mut nodes: Vec<f64> = vec![-1.0];
+    let mut weights: Vec<f64> = vec![2.0 / (n * n) as f64];
+    for (x, w) in inner.iter().zip(&inner_weights) {
+        nodes.push(*x);
+        weights.push(w / (1.0 + x));
+    }
+
+    (nodes, weights)
+}
+
+/// # Integration of a function
+/// 
+/// ## Definition
+/// Computes the integral of $f$ on $[a, b]$ with the Gauss-Legendre rule of $n$ nodes, mapped to the interval.
+/// Infinite bounds are mapped to a finite interval with the changes of variables:
+/// $$
+/// x = a + \frac{1+t}{1-t},~ x = b - \frac{1-t}{1+t},~ x = \frac{t}{1-t^2}
+/// $$
+/// for $[a, \infty)$, $(-\infty, b]$ and the real line, the integrand needing to decrease fast enough.
+/// 
+/// ## Inputs
+/// - `f`: the function to integrate
+/// - `a`: the lower bound, possibly infinite
+/// - `b`: the upper bound, possibly infinite
+/// - `n`: the number of nodes
+/// 
+/// Returns the approximated integral.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::math::quadrature::integrate;
+/// let res: f64 = integrate(|x| x.exp(), 0.0, 1.0, 12);
+/// assert!((res - (1.0_f64.exp() - 1.0)).abs() < 1.0e-14);
+/// 
+/// // Integral of 1 / (1 + x^2) on the real line
+/// let res: f64 = integrate(|x| 1.0 / (1.0 + x * x), f64::NEG_INFINITY, f64::INFINITY, 40);
+/// assert!((res - std::f64::consts::PI).abs() < 1.0e-14);
+/// ```
+pub fn integrate<F>(f: F, a: f64, b: f64, n: usize) -> f64
+where F: Fn(f64) -> f64 {
+
+    if a == b {
+        return 0.0;
+    } else if a > b {
+        return -integrate(f, b, a, n);
+    }
+
+    let (nodes, weights): (Vec<f64>, Vec<f64>) = gauss_legendre(n);
+
+    // Change of variable from t in [-1, 1], and its derivative
+    let map = |t: f64| -> (f64, f64) {
+        match (a.is_finite(), b.is_finite()) {
+            (true, true) => ((b - a) / 2.0 * t + (a + b) / 2.0, (b - a) / 2.0),
+            (true, false) => (a + (1.0 + t) / (1.0 - t), 2.0 / (1.0 - t).powi(2)),
+            (false, true) => (b - (1.0 - t) / (1.0 + t), 2.0 / (1.0 + t).powi(2)),
+            (false, false) => (t / (1.0 - t * t), (1.0 + t * t) / (1.0 - t * t).powi(2))
+        }
+    };
+
+    nodes.iter().zip(&weights).map(|(t, w)| {
+        let (x, dx): (f64, f64) = map(*t);
+        w * f(x) * dx
+    }).sum()
+}
+
+/// # Integral of the weight function of a family
+fn weight_integral(family: Family) -> f64 {
+    match family {
+        Family::Legendre => 2.0,
+        Family::ChebyshevT | Family::ChebyshevV | Family::ChebyshevW => PI,
+        Family::ChebyshevU => PI / 2.0,
+        Family::Jacobi { alpha, beta } => {
+            ((alpha + beta + 1.0) * 2.0_f64.ln() + basic::ln_gamma(alpha + 1.0) + basic::ln_gamma(beta + 1.0)
+                - basic::ln_gamma(alpha + beta + 2.0)).exp()
+        },
+        Family::Gegenbauer { alpha } => PI.sqrt() * (basic::ln_gamma(alpha + 0.5) - basic::ln_gamma(alpha + 1.0)).exp(),
+        Family::Laguerre { alpha } => basic::ln_gamma(alpha + 1.0).exp(),
+        Family::Hermite => PI.sqrt(),
+        Family::HermiteProb => (2.0 * PI).sqrt()
+    }
+}
+
+/// # Eigenvalues of a symmetric tridiagonal matrix, with the first components of the eigenvectors
+/// 
+/// Implicit QL algorithm with Wilkinson shifts, where `off[i]` couples `diag[i]` and `diag[i + 1]`.
+fn tridiagonal_eigen(mut diag: Vec<f64>, mut off: Vec<f64>) -> (Vec<f64>, Vec<f64>) {
+
+    let n: usize = diag.len();
+    let mut first: Vec<f64> = vec![0.0; n];
+    first[0] = 1.0;
+
+    for l in 0..n {
+        let mut iterations: usize = 0;
+        loop {
+            // Looking for a negligible off-diagonal element to split the matrix
+            let mut m: usize = l;
+            while m < n - 1 && off[m].abs() > f64::EPSILON * (diag[m].abs() + diag[m + 1].abs()) {
+                m += 1;
+            }
+            if m == l {
+                break;
+            }
+
+            iterations += 1;
+            assert!(iterations <= QL_ITERATIONS, "The eigenvalues of the Jacobi matrix did not converge!");
+
+            // Shift from the eigenvalue of the leading 2x2 block closest to diag[l]
+            let mut g: f64 = (diag[l + 1] - diag[l]) / (2.0 * off[l]);
+            let mut r: f64 = g.hypot(1.0);
+            g = diag[m] - diag[l] + off[l] / (g + r.copysign(g));
+
+            let (mut s, mut c, mut p): (f64, f64, f64) = (1.0, 1.0, 0.0);
+            let mut deflated: bool = false;
+
+            for i in (l..m).rev() {
+                let f: f64 = s * off[i];
+                let b: f64 = c * off[i];
+                r = f.hypot(g);
+                off[i + 1] = r;
+
+                if r == 0.0 {
+                    diag[i + 1] -= p;
+                    off[m] = 0.0;
+                    deflated = true;
+                    break;
+                }
+
+                s = f / r;
+                c = g / r;
+                g = diag[i + 1] - p;
+                r = (diag[i] - g) * s + 2.0 * c * b;
+                p = s * r;
+                diag[i + 1] = g + p;
+                g = c * r - b;
+
+                // Rotating the first components of the eigenvectors
+                let v: f64 = first[i + 1];
+                first[i + 1] = s * first[i] + c * v;
+                first[i] = c * first[i] - s * v;
+            }
+
+            if !deflated {
+                diag[l] -= p;
+                off[l] = g;
+                off[m] = 0.0;
+            }
+        }
+    }
+
+    (diag, first)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,570 @@
+//!
+//! # Discrete cosine and sine transforms
+//! 
+//! The four types of [discrete cosine transform](https://en.wikipedia.org/wiki/Discrete_cosine_transform) (DCT)
+//! and [discrete sine transform](https://en.wikipedia.org/wiki/Discrete_sine_transform) (DST) of real sequences,
+//! their inverses, and their versions for arrays of any dimension. They are computed in $O(N\log N)$ with the
+//! fast Fourier transform.
+//! 
+//! The transforms are unnormalized by default, the inverses carrying the $1/2N$ factor (`Norm::Backward`).
+//! With `Norm::Ortho`, the transforms are orthonormal: they preserve the energy of the signal, types I and IV
+//! are their own inverse, and types II and III are the inverse of each other.
+//! 
+//! ```
+//! # use scilib::signal::dct::{ dct, idct, Kind, Norm };
+//! let x: Vec<f64> = vec![1.0, 2.0, -1.0, 0.5, 3.0];
+//! let y = dct(&x, Kind::II, Norm::Ortho);
+//! 
+//! // Energy is preserved, and the signal is recovered
+//! let energy = |v: &[f64]| v.iter().map(|a| a * a).sum::<f64>();
+//! assert!((energy(&x) - energy(&y)).abs() < 1.0e-12);
+//! for (a, b) in idct(&y, Kind::II, Norm::Ortho).iter().zip(&x) {
+//!     assert!((a - b).abs() < 1.0e-12);
+//! }
+//! ```
+//! 
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use std::f64::consts::{     // Using std lib constants
+    PI,                     // Pi
+    SQRT_2                  // Square root of two
+};
+
+use super::{                // Using the parent module
+    fft,                    // Forward transform
+    ifft                    // Inverse transform
+};
+
+use num_complex::Complex64; // Using complex numbers from the num crate
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Type of the transform
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kind {
+    /// Type I, even or odd around the edge samples
+    I,
+    /// Type II, the most common DCT
+    II,
+    /// Type III, the inverse of type II
+    III,
+    /// Type IV, even or odd around half-samples at both ends
+    IV
+}
+
+/// # Normalization of the transforms
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Norm {
+    /// No scaling of the forward transform, the inverse is scaled
+    Backward,
+    /// Orthonormal forward and inverse transforms
+    Ortho
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Discrete cosine transform
+/// 
+/// ## Definition
+/// The unnormalized DCT of a sequence of length $N$ is, for each type:
+/// $$
+/// \begin{aligned}
+/// \mathrm{I}&: y_k = x_0 + (-1)^kx_{N-1} + 2\sum_{n=1}^{N-2}x_n\cos\left(\frac{\pi kn}{N-1}\right) \\\\
+/// \mathrm{II}&: y_k = 2\sum_{n=0}^{N-1}x_n\cos\left(\frac{\pi k(2n+1)}{2N}\right) \\\\
+/// \mathrm{III}&: y_k = x_0 + 2\sum_{n=1}^{N-1}x_n\cos\left(\frac{\pi n(2k+1)}{2N}\right) \\\\
+/// \mathrm{IV}&: y_k = 2\sum_{n=0}^{N-1}x_n\cos\left(\frac{\pi(2n+1)(2k+1)}{4N}\right)
+/// \end{aligned}
+/// $$
+/// With the orthonormal scaling, the terms are weighted such that the transform matrix is orthogonal.
+/// Type I needs at least two points.
+/// 
+/// ## Inputs
+/// - `x`: the sequence
+/// - `kind`: the type of the transform
+/// - `norm`: the normalization
+/// 
+/// Returns the transform.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::dct::{ dct, Kind, Norm };
+/// let x: Vec<f64> = vec![1.0, 2.0, 3.0, 4.0];
+/// let direct = |k: usize| -> f64 {
+///     2.0 * x.iter().enumerate().map(|(n, v)| v * (std::f64::consts::PI * k as f64 * (2 * n + 1) as f64 / 8.0).cos()).sum::<f64>()
+/// };
+/// 
+/// let y = dct(&x, Kind::II, Norm::Backward);
+/// for (k, v) in y.iter().enumerate() {
+///     assert!((v - direct(k)).abs() < 1.0e-12);
+/// }
+/// assert_eq!(y[0], 20.0);
+/// 
+/// let y1 = dct(&x, Kind::I, Norm::Backward);
+/// let expected: Vec<f64> = vec![15.0, -4.0, 0.0, -1.0];
+/// for (v, e) in y1.iter().zip(&expected) {
+///     assert!((v - e).abs() < 1.0e-12);
+/// }
+/// ```
+pub fn dct(x: &[f64], kind: Kind, norm: Norm) -> Vec<f64> {
+
+    let n: usize = x.len();
+    if n == 0 {
+        return vec![];
+    }
+
+    match (kind, norm) {
+        (Kind::I, Norm::Backward) => dct1(x),
+        (Kind::II, Norm::Backward) => dct2(x),
+        (Kind::III, Norm::Backward) => dct3(x),
+        (Kind::IV, Norm::Backward) => dct4(x),
+        (Kind::I, Norm::Ortho) => {
+            let mut input: Vec<f64> = x.to_vec();
+            input[0] *= SQRT_2;
+            input[n - 1] *= SQRT_2;
+            let mut y: Vec<f64> = scale(dct1(&input), 1.0 / (2.0 * (n - 1) as f64).sqrt());
+            y[0] /= SQRT_2;
+            y[n - 1] /= SQRT_2;
+            y
+        },
+        (Kind::II, Norm::Ortho) => {
+            let mut y: Vec<f64> = scale(dct2(x), 1.0 / (2.0 * n as f64).sqrt());
+            y[0] /= SQRT_2;
+            y
+        },
+        (Kind::III, Norm::Ortho) => {
+            let mut input: Vec<f64> = x.to_vec();
+            input[0] *= SQRT_2;
+            scale(dct3(&input), 1.0 / (2.0 * n as f64).sqrt())
+        },
+        (Kind::IV, Norm::Ortho) => scale(dct4(x), 1.0 / (2.0 * n as f64).sqrt())
+    }
+}
+
+/// # Inverse discrete cosine transform
+/// 
+/// ## Definition
+/// Inverts `dct` with the same type and normalization: types I and IV are their own inverse up to a scaling,
+/// and types II and III are the inverse of each other.
+/// 
+/// ## Inputs
+/// - `y`: the transform
+/// - `kind`: the type of the forward transform
+/// - `norm`: the normalization of the forward transform
+/// 
+/// Returns the sequence.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::dct::{ dct, idct, Kind, Norm };
+/// let x: Vec<f64> = (0..13).map(|k| (0.4 * k as f64).sin() + 0.1 * k as f64).collect();
+/// 
+/// for kind in [Kind::I, Kind::II, Kind::III, Kind::IV] {
+///     for norm in [Norm::Backward, Norm::Ortho] {
+///         let back = idct(&dct(&x, kind, norm), kind, norm);
+///         for (a, b) in back.iter().zip(&x) {
+///             assert!((a - b).abs() < 1.0e-12);
+///         }
+///     }
+/// }
+/// ```
+pub fn idct(y: &[f64], kind: Kind, norm: Norm) -> Vec<f64> {
+
+    let n: usize = y.len();
+    let inverse_kind: Kind = match kind {
+        Kind::II => Kind::III,
+        Kind::III => Kind::II,
+        other => other
+    };
+
+    match norm {
+        Norm::Ortho => dct(y, inverse_kind, Norm::Ortho),
+        Norm::Backward => {
+            let factor: f64 = match kind {
+                Kind::I => 2.0 * (n as f64 - 1.0),
+                _ => 2.0 * n as f64
+            };
+            scale(dct(y, inverse_kind, Norm::Backward), 1.0 / factor)
+        }
+    }
+}
+
+/// # Discrete sine transform
+/// 
+/// ## Definition
+/// The unnormalized DST of a sequence of length $N$ is, for each type:
+/// $$
+/// \begin{aligned}
+/// \mathrm{I}&: y_k = 2\sum_{n=0}^{N-1}x_n\sin\left(\frac{\pi(k+1)(n+1)}{N+1}\right) \\\\
+/// \mathrm{II}&: y_k = 2\sum_{n=0}^{N-1}x_n\sin\left(\frac{\pi(k+1)(2n+1)}{2N}\right) \\\\
+/// \mathrm{III}&: y_k = (-1)^kx_{N-1} + 2\sum_{n=0}^{N-2}x_n\sin\left(\frac{\pi(n+1)(2k+1)}{2N}\right) \\\\
+/// \mathrm{IV}&: y_k = 2\sum_{n=0}^{N-1}x_n\sin\left(\frac{\pi(2n+1)(2k+1)}{4N}\right)
+/// \end{aligned}
+/// $$
+/// With the orthonormal scaling, the terms are weighted such that the transform matrix is orthogonal.
+/// 
+/// ## Inputs
+/// - `x`: the sequence
+/// - `kind`: the type of the transform
+/// - `norm`: the normalization
+/// 
+/// Returns the transform.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::dct::{ dst, Kind, Norm };
+/// let x: Vec<f64> = vec![1.0, -2.0, 0.5, 4.0, 1.5];
+/// let n: f64 = x.len() as f64;
+/// let direct = |k: usize| -> f64 {
+///     2.0 * x.iter().enumerate().map(|(m, v)| {
+///         v * (std::f64::consts::PI * (2 * m + 1) as f64 * (2 * k + 1) as f64 / (4.0 * n)).sin()
+///     }).sum::<f64>()
+/// };
+/// 
+/// let y = dst(&x, Kind::IV, Norm::Backward);
+/// for (k, v) in y.iter().enumerate() {
+///     assert!((v - direct(k)).abs() < 1.0e-12);
+/// }
+/// ```
+pub fn dst(x: &[f64], kind: Kind, norm: Norm) -> Vec<f64> {
+
+    let n: usize = x.len();
+    if n == 0 {
+        return vec![];
+    }
+
+    match (kind, norm) {
+        (Kind::I, Norm::Backward) => dst1(x),
+        (Kind::II, Norm::Backward) => dst2(x),
+        (Kind::III, Norm::Backward) => dst3(x),
+        (Kind::IV, Norm::Backward) => dst4(x),
+        (Kind::I, Norm::Ortho) => scale(dst1(x), 1.0 / (2.0 * (n + 1) as f64).sqrt()),
+        (Kind::II, Norm::Ortho) => {
+            let mut y: Vec<f64> = scale(dst2(x), 1.0 / (2.0 * n as f64).sqrt());
+            y[n - 1] /= SQRT_2;
+            y
+        },
+        (Kind::III, Norm::Ortho) => {
+            let mut input: Vec<f64> = x.to_vec();
+            input[n - 1] *= SQRT_2;
+            scale(dst3(&input), 1.0 / (2.0 * n as f64).sqrt())
+        },
+        (Kind::IV, Norm::Ortho) => scale(dst4(x), 1.0 / (2.0 * n as f64).sqrt())
+    }
+}
+
+/// # Inverse discrete sine transform
+/// 
+/// ## Definition
+/// Inverts `dst` with the same type and normalization: types I and IV are their own inverse up to a scaling,
+/// and types II and III are the inverse of each other.
+/// 
+/// ## Inputs
+/// - `y`: the transform
+/// - `kind`: the type of the forward transform
+/// - `norm`: the normalization of the forward transform
+/// 
+/// Returns the sequence.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::dct::{ dst, idst, Kind, Norm };
+/// let x: Vec<f64> = (0..10).map(|k| (k * k % 7) as f64).collect();
+/// 
+/// for kind in [Kind::I, Kind::II, Kind::III, Kind::IV] {
+///     for norm in [Norm::Backward, Norm::Ortho] {
+///         let back = idst(&dst(&x, kind, norm), kind, norm);
+///         for (a, b) in back.iter().zip(&x) {
+///             assert!((a - b).abs() < 1.0e-12);
+///         }
+///     }
+/// }
+/// ```
+pub fn idst(y: &[f64], kind: Kind, norm: Norm) -> Vec<f64> {
+
+    let n: usize = y.len();
+    let inverse_kind: Kind = match kind {
+        Kind::II => Kind::III,
+        Kind::III => Kind::II,
+        other => other
+    };
+
+    match norm {
+        Norm::Ortho => dst(y, inverse_kind, Norm::Ortho),
+        Norm::Backward => {
+            let factor: f64 = match kind {
+                Kind::I => 2.0 * (n as f64 + 1.0),
+                _ => 2.0 * n as f64
+            };
+            scale(dst(y, inverse_kind, Norm::Backward), 1.0 / factor)
+        }
+    }
+}
+
+/// # N-dimensional discrete cosine transform
+/// 
+/// Computes the DCT along every axis of an array stored in row-major order.
+/// 
+/// ## Inputs
+/// - `x`: the values, in row-major order
+/// - `shape`: the length along each axis, whose product must be the length of `x`
+/// - `kind`: the type of the transform
+/// - `norm`: the normalization
+/// 
+/// Returns the transform, in row-major order.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::dct::{ dct, dctn, idctn, Kind, Norm };
+/// let image: Vec<f64> = (0..12).map(|k| (k as f64 * 0.9).cos()).collect();
+/// let y = dctn(&image, &[3, 4], Kind::II, Norm::Ortho);
+/// 
+/// // Separable: rows then columns
+/// let rows: Vec<f64> = image.chunks(4).flat_map(|r| dct(r, Kind::II, Norm::Ortho)).collect();
+/// let col0: Vec<f64> = dct(&[rows[0], rows[4], rows[8]], Kind::II, Norm::Ortho);
+/// assert!((y[0] - col0[0]).abs() < 1.0e-12 && (y[8] - col0[2]).abs() < 1.0e-12);
+/// 
+/// let back = idctn(&y, &[3, 4], Kind::II, Norm::Ortho);
+/// for (a, b) in back.iter().zip(&image) {
+///     assert!((a - b).abs() < 1.0e-12);
+/// }
+/// 
+/// // An empty axis gives an empty transform
+/// assert!(dctn(&[], &[0, 3], Kind::II, Norm::Ortho).is_empty());
+/// ```
+pub fn dctn(x: &[f64], shape: &[usize], kind: Kind, norm: Norm) -> Vec<f64> {
+    apply_axes(x, shape, |line| dct(line, kind, norm))
+}
+
+/// # N-dimensional inverse discrete cosine transform
+/// 
+/// Inverts `dctn` with the same type and normalization.
+/// 
+/// ## Inputs
+/// - `y`: the transform, in row-major order
+/// - `shape`: the length along each axis, whose product must be the length of `y`
+/// - `kind`: the type of the forward transform
+/// - `norm`: the normalization of the forward transform
+/// 
+/// Returns the values, in row-major order.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::dct::{ dctn, idctn, Kind, Norm };
+/// let cube: Vec<f64> = (0..60).map(|k| (k % 7) as f64 - 0.5 * (k % 3) as f64).collect();
+/// let back = idctn(&dctn(&cube, &[3, 4, 5], Kind::I, Norm::Backward), &[3, 4, 5], Kind::I, Norm::Backward);
+/// for (a, b) in back.iter().zip(&cube) {
+///     assert!((a - b).abs() < 1.0e-12);
+/// }
+/// ```
+pub fn idctn(y: &[f64], shape: &[usize], kind: Kind, norm: Norm) -> Vec<f64> {
+    apply_axes(y, shape, |line| idct(line, kind, norm))
+}
+
+/// # N-dimensional discrete sine transform
+/// 
+/// Computes the DST along every axis of an array stored in row-major order.
+/// 
+/// ## Inputs
+/// - `x`: the values, in row-major order
+/// - `shape`: the length along each axis, whose product must be the length of `x`
+/// - `kind`: the type of the transform
+/// - `norm`: the normalization
+/// 
+/// Returns the transform, in row-major order.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::dct::{ dst, dstn, Kind, Norm };
+/// // Along a single axis, the N-dimensional transform is the 1-D transform
+/// let x: Vec<f64> = vec![0.5, 1.0, -1.5, 2.0];
+/// assert_eq!(dstn(&x, &[4], Kind::III, Norm::Ortho), dst(&x, Kind::III, Norm::Ortho));
+/// 
+/// // Eigenfunction of the 2-D Laplacian with zero boundaries: a single coefficient
+/// let (nx, ny): (usize, usize) = (6, 5);
+/// let mode: Vec<f64> = (0..nx * ny).map(|k| {
+///     let (i, j) = (k / ny, k % ny);
+///     (std::f64::consts::PI * 2.0 * (i + 1) as f64 / (nx + 1) as f64).sin()
+///         * (std::f64::consts::PI * (j + 1) as f64 / (ny + 1) as f64).sin()
+/// }).collect();
+/// let y = dstn(&mode, &[nx, ny], Kind::I, Norm::Ortho);
+/// for (k, v) in y.iter().enumerate() {
+///     if k != ny {
+///         assert!(v.abs() < 1.0e-12);
+///     }
+/// }
+/// ```
+pub fn dstn(x: &[f64], shape: &[usize], kind: Kind, norm: Norm) -> Vec<f64> {
+    apply_axes(x, shape, |line| dst(line, kind, norm))
+}
+
+/// # N-dimensional inverse discrete sine transform
+/// 
+/// Inverts `dstn` with the same type and normalization.
+/// 
+/// ## Inputs
+/// - `y`: the transform, in row-major order
+/// - `shape`: the length along each axis, whose product must be the length of `y`
+/// - `kind`: the type of the forward transform
+/// - `norm`: the normalization of the forward transform
+/// 
+/// Returns the values, in row-major order.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::dct::{ dstn, idstn, Kind, Norm };
+/// let grid: Vec<f64> = (0..24).map(|k| (0.3 * k as f64).exp().ln_1p()).collect();
+/// let back = idstn(&dstn(&grid, &[2, 3, 4], Kind::IV, Norm::Backward), &[2, 3, 4], Kind::IV, Norm::Backward);
+/// for (a, b) in back.iter().zip(&grid) {
+///     assert!((a - b).abs() < 1.0e-12);
+/// }
+/// ```
+pub fn idstn(y: &[f64], shape: &[usize], kind: Kind, norm: Norm) -> Vec<f64> {
+    apply_axes(y, shape, |line| idst(line, kind, norm))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Unnormalized DCT-I, from the FFT of the even extension
+fn dct1(x: &[f64]) -> Vec<f64> {
+
+    let n: usize = x.len();
+    assert!(n >= 2, "The type I DCT needs at least two points!");
+
+    let mut ext: Vec<f64> = x.to_vec();
+    ext.extend(x[1..n - 1].iter().rev());
+
+    fft(&ext).iter().take(n).map(|v| v.re).collect()
+}
+
+/// # Unnormalized DCT-II, with the N-point algorithm of Makhoul
+fn dct2(x: &[f64]) -> Vec<f64> {
+
+    let n: usize = x.len();
+
+    // Even samples in increasing order, then odd samples in decreasing order
+    let mut v: Vec<f64> = vec![0.0; n];
+    for (k, val) in x.iter().enumerate() {
+        let idx: usize = if k.is_multiple_of(2) { k / 2 } else { n - 1 - k / 2 };
+        v[idx] = *val;
+    }
+
+    fft(&v).iter().enumerate().map(|(k, val)| {
+        2.0 * (Complex64::from_polar(1.0, -PI * k as f64 / (2 * n) as f64) * val).re
+    }).collect()
+}
+
+/// # Unnormalized DCT-III, by inverting the algorithm of Makhoul
+fn dct3(x: &[f64]) -> Vec<f64> {
+
+    let n: usize = x.len();
+
+    let spectrum: Vec<Complex64> = (0..n).map(|k| {
+        let mirror: f64 = if k == 0 { 0.0 } else { x[n - k] };
+        Complex64::from_polar(n as f64, PI * k as f64 / (2 * n) as f64) * Complex64::new(x[k], -mirror)
+    }).collect();
+    let v: Vec<Complex64> = ifft(&spectrum);
+
+    // Undoing the reordering of the samples
+    (0..n).map(|k| {
+        let idx: usize = if k.is_multiple_of(2) { k / 2 } else { n - 1 - k / 2 };
+        v[idx].re
+    }).collect()
+}
+
+/// # Unnormalized DCT-IV, from a zero-padded FFT of length 2N
+fn dct4(x: &[f64]) -> Vec<f64> {
+    quarter_shift(x).iter().map(|v| 2.0 * v.re).collect()
+}
+
+/// # Unnormalized DST-I, from the FFT of the odd extension
+fn dst1(x: &[f64]) -> Vec<f64> {
+
+    let n: usize = x.len();
+
+    let mut ext: Vec<f64> = vec![0.0];
+    ext.extend_from_slice(x);
+    ext.push(0.0);
+    ext.extend(x.iter().rev().map(|v| -v));
+
+    fft(&ext).iter().skip(1).take(n).map(|v| -v.im).collect()
+}
+
+/// # Unnormalized DST-II, from the DCT-II of the sequence with alternating signs
+fn dst2(x: &[f64]) -> Vec<f64> {
+
+    let alternating: Vec<f64> = x.iter().enumerate().map(|(k, v)| if k.is_multiple_of(2) { *v } else { -v }).collect();
+
+    dct2(&alternating).into_iter().rev().collect()
+}
+
+/// # Unnormalized DST-III, from the DCT-III of the reversed sequence
+fn dst3(x: &[f64]) -> Vec<f64> {
+
+    let reversed: Vec<f64> = x.iter().rev().copied().collect();
+
+    dct3(&reversed).iter().enumerate().map(|(k, v)| if k.is_multiple_of(2) { *v } else { -v }).collect()
+}
+
+/// # Unnormalized DST-IV, from a zero-padded FFT of length 2N
+fn dst4(x: &[f64]) -> Vec<f64> {
+    quarter_shift(x).iter().map(|v| -2.0 * v.im).collect()
+}
+
+/// # Sums $\sum_n x_n e^{-i\pi(2n+1)(2k+1)/4N}$, shared by the type IV transforms
+fn quarter_shift(x: &[f64]) -> Vec<Complex64> {
+
+    let n: usize = x.len();
+
+    let mut z: Vec<Complex64> = x.iter().enumerate().map(|(k, v)| {
+        Complex64::from_polar(*v, -PI * k as f64 / (2 * n) as f64)
+    }).collect();
+    z.resize(2 * n, Complex64::default());
+
+    fft(&z).iter().take(n).enumerate().map(|(k, v)| {
+        Complex64::from_polar(1.0, -PI * (2 * k + 1) as f64 / (4 * n) as f64) * v
+    }).collect()
+}
+
+/// # Scaling of a sequence
+fn scale(mut x: Vec<f64>, factor: f64) -> Vec<f64> {
+    x.iter_mut().for_each(|v| *v *= factor);
+    x
+}
+
+/// # Transform along every axis of a row-major array
+fn apply_axes<F>(data: &[f64], shape: &[usize], transform: F) -> Vec<f64>
+where F: Fn(&[f64]) -> Vec<f64> {
+
+    assert_eq!(shape.iter().product::<usize>(), data.len(), "The shape does not match the number of values!");
+
+    // An empty axis leaves no line to transform
+    if data.is_empty() {
+        return data.to_vec();
+    }
+
+    let mut res: Vec<f64> = data.to_vec();
+    let mut line: Vec<f64> = Vec::new();
+
+    for (axis, &length) in shape.iter().enumerate() {
+
+        let stride: usize = shape[axis + 1..].iter().product();
+        line.resize(length, 0.0);
+
+        // Going through every line along the current axis
+        for block in res.chunks_exact_mut(length * stride) {
+            for offset in 0..stride {
+                for (k, val) in line.iter_mut().enumerate() {
+                    *val = block[offset + k * stride];
+                }
+                for (k, val) in transform(&line).iter().enumerate() {
+                    block[offset + k * stride] = *val;
+                }
+            }
+        }
+    }
+
+    res
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
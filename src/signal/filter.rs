@@ -0,0 +1,1717 @@
+//!
+//! # Digital filters
+//! 
+//! Design and application of linear time-invariant digital filters.
+//! 
+//! ## Design
+//! - **FIR**: windowed-sinc design with `firwin`, and equiripple design with the Parks-McClellan algorithm in `remez`
+//! - **IIR**: Butterworth, Chebyshev I and II, elliptic and Bessel filters with `iir_design`, in low-pass,
+//!   high-pass, band-pass and band-stop forms. They are built from an analog prototype, transformed to the
+//!   requested band and brought to the digital domain with the bilinear transform.
+//! 
+//! IIR filters are returned as zeros, poles and gain (`Zpk`), which can be converted to transfer function
+//! coefficients (`b` and `a`) or to second-order sections. High order filters should be applied as second-order
+//! sections, as the transfer function coefficients quickly lose precision.
+//! 
+//! ## Application
+//! - `lfilter`: applies a transfer function, with initial conditions
+//! - `sosfilt`: applies cascaded second-order sections, with initial conditions
+//! - `filtfilt` and `sosfiltfilt`: zero-phase filtering, forward and backward
+//! - `savgol_filter`: Savitzky-Golay smoothing and differentiation, with the coefficients of `savgol_coeffs`
+//! 
+//! ## Analysis
+//! - `freqz` and `sosfreqz`: frequency response
+//! - `group_delay`: group delay of a transfer function
+//! - `Zpk::from_ba`, `Zpk::from_sos`, `Zpk::to_ba` and `Zpk::to_sos`: conversions between representations
+//! - `is_stable` and `Zpk::is_stable`: stability checks
+//! 
+//! ```
+//! # use scilib::signal::filter::{ iir_design, sosfilt, BandType, Prototype };
+//! // Fourth order Butterworth low-pass at 10 Hz, for a signal sampled at 100 Hz
+//! let filter = iir_design(4, &[10.0], BandType::LowPass, Prototype::Butterworth, 100.0);
+//! let sos = filter.to_sos();
+//! 
+//! // A 40 Hz sinusoid is strongly attenuated once the transient is over
+//! let x: Vec<f64> = (0..500).map(|k| (std::f64::consts::TAU * 40.0 * k as f64 / 100.0).sin()).collect();
+//! let (y, _) = sosfilt(&sos, &x, None);
+//! assert!(y[100..].iter().all(|v| v.abs() < 1.0e-2));
+//! ```
+//! 
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use std::f64::consts::{     // Using std lib constants
+    PI,                     // Pi
+    FRAC_PI_2               // Pi / 2
+};
+
+use crate::math::{          // Using math functions
+    basic,                  // Sinc and factorial functions
+    polynomial::Poly        // Polynomials for the root finding
+};
+
+use super::{                // Using the parent module
+    convolve_auto,          // Convolution with automatic method
+    Mode                    // Size of the convolution
+};
+
+use num_complex::Complex64; // Using complex numbers from the num crate
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Density of the frequency grid of the Parks-McClellan algorithm, per extremal frequency
+const REMEZ_DENSITY: usize = 16;
+
+/// Maximum number of exchanges of the Parks-McClellan algorithm
+const REMEZ_ITERATIONS: usize = 100;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Frequency band of a filter
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BandType {
+    /// Passes the frequencies below the cutoff
+    LowPass,
+    /// Passes the frequencies above the cutoff
+    HighPass,
+    /// Passes the frequencies between the two cutoffs
+    BandPass,
+    /// Rejects the frequencies between the two cutoffs
+    BandStop
+}
+
+/// # Analog prototype of an IIR filter
+/// 
+/// The ripples and attenuations are given in decibels.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Prototype {
+    /// Maximally flat pass-band
+    Butterworth,
+    /// Equiripple pass-band, with the given maximum ripple
+    ChebyshevI {
+        /// Maximum ripple in the pass-band
+        ripple: f64
+    },
+    /// Equiripple stop-band, with the given minimum attenuation
+    ChebyshevII {
+        /// Minimum attenuation in the stop-band
+        attenuation: f64
+    },
+    /// Equiripple pass-band and stop-band, with the sharpest transition for a given order
+    Elliptic {
+        /// Maximum ripple in the pass-band
+        ripple: f64,
+        /// Minimum attenuation in the stop-band
+        attenuation: f64
+    },
+    /// Maximally flat group delay, with the phase midpoint at the cutoff
+    Bessel
+}
+
+/// # Edge handling of the Savitzky-Golay filter
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SavgolMode {
+    /// Polynomial fitted to the first and last windows, evaluated on the edge samples
+    Interp,
+    /// Even reflection around the edge samples
+    Mirror,
+    /// Repetition of the edge samples
+    Nearest,
+    /// Extension with zeros
+    Constant,
+    /// Periodic repetition of the signal
+    Wrap
+}
+
+/// # Zeros, poles and gain representation
+/// 
+/// ## Definition
+/// Represents the transfer function:
+/// $$
+/// H(z) = k\frac{\prod_i (z - z_i)}{\prod_i (z - p_i)}
+/// $$
+/// for digital filters, and the same function of $s$ for analog filters.
+/// Complex zeros and poles come in conjugate pairs for real filters.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Zpk {
+    /// Zeros of the transfer function
+    pub zeros: Vec<Complex64>,
+    /// Poles of the transfer function
+    pub poles: Vec<Complex64>,
+    /// Gain of the transfer function
+    pub gain: f64
+}
+
+impl Zpk {
+
+    /// # Transfer function coefficients
+    /// 
+    /// ## Definition
+    /// Expands the zeros and poles into the polynomials of the transfer function:
+    /// $$
+    /// H(z) = \frac{b_0 + b_1z^{-1} + ... + b_Mz^{-M}}{a_0 + a_1z^{-1} + ... + a_Nz^{-N}}
+    /// $$
+    /// with $a_0 = 1$.
+    /// 
+    /// Returns the `(b, a)` coefficients.
+    /// 
+    /// ## Example
+    /// ```
+    /// # use num_complex::Complex64;
+    /// # use scilib::signal::filter::Zpk;
+    /// let zpk = Zpk {
+    ///     zeros: vec![Complex64::new(-1.0, 0.0)],
+    ///     poles: vec![Complex64::new(0.5, 0.5), Complex64::new(0.5, -0.5)],
+    ///     gain: 2.0
+    /// };
+    /// let (b, a) = zpk.to_ba();
+    /// assert_eq!(b, vec![2.0, 2.0]);
+    /// assert_eq!(a, vec![1.0, -1.0, 0.5]);
+    /// ```
+    pub fn to_ba(&self) -> (Vec<f64>, Vec<f64>) {
+
+        let b: Vec<f64> = poly(&self.zeros).iter().map(|c| self.gain * c.re).collect();
+        let a: Vec<f64> = poly(&self.poles).iter().map(|c| c.re).collect();
+
+        (b, a)
+    }
+
+    /// # Second-order sections
+    /// 
+    /// ## Definition
+    /// Splits the filter in cascaded sections of order two, each stored as `[b0, b1, b2, a0, a1, a2]`:
+    /// $$
+    /// H(z) = \prod_s \frac{b_{0,s} + b_{1,s}z^{-1} + b_{2,s}z^{-2}}{a_{0,s} + a_{1,s}z^{-1} + a_{2,s}z^{-2}}
+    /// $$
+    /// Conjugate poles are grouped in the same section, with the nearest zeros. The sections are
+    /// ordered with the poles closest to the unit circle last, and the gain is put in the first section.
+    /// 
+    /// Returns the sections.
+    /// 
+    /// ## Example
+    /// ```
+    /// # use scilib::signal::filter::{ iir_design, BandType, Prototype };
+    /// let filter = iir_design(5, &[0.2], BandType::HighPass, Prototype::ChebyshevI { ripple: 1.0 }, 1.0);
+    /// let sos = filter.to_sos();
+    /// assert_eq!(sos.len(), 3);
+    /// 
+    /// // The product of the sections is the transfer function
+    /// let (b, a) = filter.to_ba();
+    /// let z = num_complex::Complex64::from_polar(1.0, 0.7);
+    /// let eval = |c: &[f64]| c.iter().rev().fold(num_complex::Complex64::default(), |acc, v| acc / z + v);
+    /// let h_ba = eval(&b) / eval(&a);
+    /// let h_sos = sos.iter().fold(num_complex::Complex64::new(1.0, 0.0), |acc, s| acc * eval(&s[..3]) / eval(&s[3..]));
+    /// assert!((h_ba - h_sos).norm() < 1.0e-10);
+    /// ```
+    pub fn to_sos(&self) -> Vec<[f64; 6]> {
+
+        let n_sections: usize = self.zeros.len().max(self.poles.len()).div_ceil(2).max(1);
+
+        // Grouping the poles by conjugate pairs, then the real poles two by two
+        let mut pole_groups: Vec<Vec<Complex64>> = Vec::new();
+        let (pairs_p, mut reals_p): (Vec<Complex64>, Vec<f64>) = split_conjugates(&self.poles);
+        for p in pairs_p {
+            pole_groups.push(vec![p, p.conj()]);
+        }
+        reals_p.sort_by(|x, y| x.partial_cmp(y).unwrap());
+        for chunk in reals_p.chunks(2) {
+            pole_groups.push(chunk.iter().map(|r| Complex64::new(*r, 0.0)).collect());
+        }
+        pole_groups.resize(n_sections, vec![]);
+
+        // Poles furthest from the unit circle first
+        let distance = |g: &Vec<Complex64>| g.iter().map(|p| (1.0 - p.norm()).abs()).fold(f64::MAX, f64::min);
+        pole_groups.sort_by(|g1, g2| distance(g2).partial_cmp(&distance(g1)).unwrap());
+
+        // Zeros are given to the sections starting with the poles closest to the unit circle
+        let (mut pairs_z, mut reals_z): (Vec<Complex64>, Vec<f64>) = split_conjugates(&self.zeros);
+        let mut zero_groups: Vec<Vec<Complex64>> = vec![vec![]; n_sections];
+        for (s, group) in pole_groups.iter().enumerate().rev() {
+
+            let target: Complex64 = group.first().copied().unwrap_or_default();
+            let nearest = |z: Complex64| (z - target).norm();
+
+            if !pairs_z.is_empty() && (reals_z.len() < 2 || group.len() == 2) {
+                let idx: usize = (0..pairs_z.len()).fold(0, |m, k| if nearest(pairs_z[k]) < nearest(pairs_z[m]) { k } else { m });
+                let z: Complex64 = pairs_z.remove(idx);
+                zero_groups[s] = vec![z, z.conj()];
+            } else {
+                for _ in 0..2.min(reals_z.len()) {
+                    let idx: usize = (0..reals_z.len()).fold(0, |m, k| {
+                        if nearest(reals_z[k].into()) < nearest(reals_z[m].into()) { k } else { m }
+                    });
+                    zero_groups[s].push(reals_z.remove(idx).into());
+                }
+            }
+        }
+
+        pole_groups.iter().zip(&zero_groups).enumerate().map(|(s, (p, z))| {
+            let gain: f64 = if s == 0 { self.gain } else { 1.0 };
+            let mut b: Vec<f64> = poly(z).iter().map(|c| gain * c.re).collect();
+            let mut a: Vec<f64> = poly(p).iter().map(|c| c.re).collect();
+            b.resize(3, 0.0);
+            a.resize(3, 0.0);
+            [b[0], b[1], b[2], a[0], a[1], a[2]]
+        }).collect()
+    }
+
+    /// # From transfer function coefficients
+    /// 
+    /// ## Definition
+    /// Computes the zeros and poles as the roots of the numerator and denominator of:
+    /// $$
+    /// H(z) = \frac{b_0 + b_1z^{-1} + ... + b_Mz^{-M}}{a_0 + a_1z^{-1} + ... + a_Nz^{-N}}
+    /// $$
+    /// Both polynomials are completed to the same length, such that delays appear as zeros or poles at the origin.
+    /// 
+    /// ## Inputs
+    /// - `b`: the numerator coefficients
+    /// - `a`: the denominator coefficients, with $a_0\neq0$
+    /// 
+    /// Returns the zeros, poles and gain.
+    /// 
+    /// ## Example
+    /// ```
+    /// # use scilib::signal::filter::Zpk;
+    /// let zpk = Zpk::from_ba(&[2.0, 2.0], &[1.0, -1.0, 0.5]);
+    /// assert_eq!(zpk.gain, 2.0);
+    /// assert_eq!(zpk.zeros.len(), 2);
+    /// assert!(zpk.zeros.iter().any(|z| (z.re + 1.0).abs() < 1.0e-12 && z.im == 0.0));
+    /// assert!(zpk.poles.iter().all(|p| (p.re - 0.5).abs() < 1.0e-12 && (p.im.abs() - 0.5).abs() < 1.0e-12));
+    /// 
+    /// let (b, a) = zpk.to_ba();
+    /// assert!((b[1] - 2.0).abs() < 1.0e-12 && b[2].abs() < 1.0e-12);
+    /// assert!((a[1] + 1.0).abs() < 1.0e-12 && (a[2] - 0.5).abs() < 1.0e-12);
+    /// ```
+    pub fn from_ba(b: &[f64], a: &[f64]) -> Self {
+
+        assert!(!a.is_empty() && a[0] != 0.0, "The first denominator coefficient cannot be zero!");
+
+        let n: usize = a.len().max(b.len());
+        let mut b_n: Vec<f64> = b.to_vec();
+        let mut a_n: Vec<f64> = a.to_vec();
+        b_n.resize(n, 0.0);
+        a_n.resize(n, 0.0);
+
+        Self {
+            zeros: clean_conjugates(&poly_roots(&b_n)),
+            poles: clean_conjugates(&poly_roots(&a_n)),
+            gain: b.iter().find(|v| **v != 0.0).copied().unwrap_or(0.0) / a[0]
+        }
+    }
+
+    /// # From second-order sections
+    /// 
+    /// ## Definition
+    /// Gathers the zeros and poles of each section, and the product of their gains.
+    /// 
+    /// ## Inputs
+    /// - `sos`: the sections, as `[b0, b1, b2, a0, a1, a2]`
+    /// 
+    /// Returns the zeros, poles and gain.
+    /// 
+    /// ## Example
+    /// ```
+    /// # use scilib::signal::filter::{ iir_design, BandType, Prototype, Zpk };
+    /// let filter = iir_design(3, &[0.1, 0.2], BandType::BandPass, Prototype::ChebyshevI { ripple: 0.5 }, 1.0);
+    /// let back = Zpk::from_sos(&filter.to_sos());
+    /// 
+    /// assert_eq!(back.poles.len(), filter.poles.len());
+    /// assert!((back.gain - filter.gain).abs() < 1.0e-12 * filter.gain);
+    /// for p in &filter.poles {
+    ///     assert!(back.poles.iter().any(|q| (p - q).norm() < 1.0e-9));
+    /// }
+    /// ```
+    pub fn from_sos(sos: &[[f64; 6]]) -> Self {
+
+        let mut res: Self = Self {
+            gain: 1.0,
+            ..Self::default()
+        };
+
+        for section in sos {
+            let zpk: Self = Self::from_ba(&section[..3], &section[3..]);
+
+            // Dropping the padding of first order sections
+            let (zeros, poles): (Vec<Complex64>, Vec<Complex64>) = if section[2] == 0.0 && section[5] == 0.0 {
+                (zpk.zeros.into_iter().skip(1).collect(), zpk.poles.into_iter().skip(1).collect())
+            } else {
+                (zpk.zeros, zpk.poles)
+            };
+            res.zeros.extend(zeros);
+            res.poles.extend(poles);
+            res.gain *= zpk.gain;
+        }
+
+        res
+    }
+
+    /// # Stability
+    /// 
+    /// ## Definition
+    /// A causal digital filter is stable when all its poles are strictly inside the unit circle.
+    /// 
+    /// Returns `true` if the filter is stable.
+    /// 
+    /// ## Example
+    /// ```
+    /// # use num_complex::Complex64;
+    /// # use scilib::signal::filter::Zpk;
+    /// let stable = Zpk { poles: vec![Complex64::new(0.9, 0.1)], ..Zpk::default() };
+    /// let unstable = Zpk { poles: vec![Complex64::new(-1.0, 0.0)], ..Zpk::default() };
+    /// assert!(stable.is_stable());
+    /// assert!(!unstable.is_stable());
+    /// ```
+    pub fn is_stable(&self) -> bool {
+        self.poles.iter().all(|p| p.norm() < 1.0)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Windowed-sinc FIR design
+/// 
+/// ## Definition
+/// Computes the coefficients of a linear phase FIR filter by the
+/// [window method](https://en.wikipedia.org/wiki/Finite_impulse_response#Window_design_method):
+/// the ideal impulse response, made of `math::basic::sinc` functions, is truncated to the number of taps
+/// and multiplied by the window. For a low-pass filter with cutoff $f_c$ and sampling rate $f_s$:
+/// $$
+/// h_n = w_n\frac{2f_c}{f_s}\mathrm{sinc}\left(2\pi\frac{f_c}{f_s}\left(n - \frac{N-1}{2}\right)\right)
+/// $$
+/// The coefficients are scaled to a unit gain at the center of the first pass-band (zero frequency for
+/// low-pass and band-stop filters, Nyquist frequency for high-pass filters).
+/// 
+/// ## Inputs
+/// - `numtaps`: the number of coefficients ($N$), which must be odd for high-pass and band-stop filters
+/// - `cutoff`: the cutoff frequency, or the two band edges for band-pass and band-stop filters
+/// - `band`: the type of filter
+/// - `window`: the window, of length `numtaps`, from the `signal::window` module
+/// - `fs`: the sampling rate ($f_s$), in the units of the cutoff
+/// 
+/// Returns the filter coefficients.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::filter::{ firwin, BandType };
+/// # use scilib::signal::window::{ hamming, Symmetry };
+/// let h = firwin(31, &[0.1], BandType::LowPass, &hamming(31, Symmetry::Symmetric), 1.0);
+/// 
+/// // Linear phase and unit gain at zero frequency
+/// assert!((h.iter().sum::<f64>() - 1.0).abs() < 1.0e-12);
+/// for k in 0..15 {
+///     assert!((h[k] - h[30 - k]).abs() < 1.0e-15);
+/// }
+/// 
+/// let bp = firwin(41, &[100.0, 200.0], BandType::BandPass, &hamming(41, Symmetry::Symmetric), 1000.0);
+/// assert_eq!(bp.len(), 41);
+/// ```
+pub fn firwin(numtaps: usize, cutoff: &[f64], band: BandType, window: &[f64], fs: f64) -> Vec<f64> {
+
+    assert_eq!(window.len(), numtaps, "The window must have numtaps points!");
+    if matches!(band, BandType::HighPass | BandType::BandStop) {
+        assert!(!numtaps.is_multiple_of(2), "High-pass and band-stop filters need an odd number of taps!");
+    }
+
+    // Pass-bands, in cycles per sample
+    let edges: Vec<f64> = cutoff.iter().map(|c| c / fs).collect();
+    let bands: Vec<(f64, f64)> = match band {
+        BandType::LowPass => vec![(0.0, edges[0])],
+        BandType::HighPass => vec![(edges[0], 0.5)],
+        BandType::BandPass => vec![(edges[0], edges[1])],
+        BandType::BandStop => vec![(0.0, edges[0]), (edges[1], 0.5)]
+    };
+
+    // Ideal response as differences of low-pass filters
+    let center: f64 = (numtaps as f64 - 1.0) / 2.0;
+    let mut h: Vec<f64> = (0..numtaps).map(|n| {
+        let m: f64 = n as f64 - center;
+        let ideal: f64 = bands.iter().fold(0.0, |acc, (lo, hi)| {
+            acc + 2.0 * hi * basic::sinc(2.0 * PI * hi * m) - 2.0 * lo * basic::sinc(2.0 * PI * lo * m)
+        });
+        ideal * window[n]
+    }).collect();
+
+    // Unit gain at the center of the first pass-band
+    let f_ref: f64 = match band {
+        BandType::LowPass | BandType::BandStop => 0.0,
+        BandType::HighPass => 0.5,
+        BandType::BandPass => 0.5 * (edges[0] + edges[1])
+    };
+    let gain: f64 = h.iter().enumerate().map(|(n, v)| v * (2.0 * PI * f_ref * (n as f64 - center)).cos()).sum();
+    h.iter_mut().for_each(|v| *v /= gain);
+
+    h
+}
+
+/// # Parks-McClellan FIR design
+/// 
+/// ## Definition
+/// Computes the coefficients of the linear phase FIR filter minimizing the maximum weighted error
+/// with a piecewise constant desired response, using the Remez exchange algorithm of the
+/// [Parks-McClellan method](https://en.wikipedia.org/wiki/Parks%E2%80%93McClellan_filter_design_algorithm).
+/// The resulting filter is equiripple in each band, the ripples being inversely proportional to the weights.
+/// 
+/// The response is symmetric (types I and II); an even number of taps forces a zero at the Nyquist frequency.
+/// 
+/// ## Inputs
+/// - `numtaps`: the number of coefficients
+/// - `bands`: the edges of the bands, in increasing order, two per band, between 0 and $f_s/2$
+/// - `desired`: the desired gain in each band
+/// - `weights`: the weight of the error in each band
+/// - `fs`: the sampling rate ($f_s$), in the units of the band edges
+/// 
+/// Returns the filter coefficients.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::filter::remez;
+/// // Low-pass with a pass-band up to 0.1 and a stop-band from 0.15, with normalized frequencies
+/// let h = remez(51, &[0.0, 0.1, 0.15, 0.5], &[1.0, 0.0], &[1.0, 10.0], 1.0);
+/// 
+/// // Evaluating the response
+/// let response = |f: f64| h.iter().enumerate().map(|(n, v)| v * (2.0 * std::f64::consts::PI * f * (n as f64 - 25.0)).cos()).sum::<f64>();
+/// assert!((response(0.05) - 1.0).abs() < 0.03);
+/// assert!(response(0.3).abs() < 3.0e-3);
+/// ```
+pub fn remez(numtaps: usize, bands: &[f64], desired: &[f64], weights: &[f64], fs: f64) -> Vec<f64> {
+
+    assert!(numtaps >= 3, "At least three taps are needed!");
+    assert!(bands.len().is_multiple_of(2) && bands.len() / 2 == desired.len(), "Each band needs two edges and a desired value!");
+    assert_eq!(desired.len(), weights.len(), "Each band needs a weight!");
+
+    let odd: bool = !numtaps.is_multiple_of(2);
+    let r: usize = if odd { numtaps / 2 + 1 } else { numtaps / 2 };
+
+    // Dense grid on the bands, the odd lengths response being A(f) = cos(pi f) P(f)
+    let delta: f64 = 0.5 / (REMEZ_DENSITY * r) as f64;
+    let mut grid: Vec<f64> = Vec::new();
+    let mut des: Vec<f64> = Vec::new();
+    let mut wt: Vec<f64> = Vec::new();
+    let mut band_start: Vec<bool> = Vec::new();
+    for (b, edge) in bands.chunks_exact(2).enumerate() {
+        let lo: f64 = edge[0] / fs;
+        let mut hi: f64 = edge[1] / fs;
+        if !odd && hi > 0.5 - delta {
+            hi = 0.5 - delta;
+        }
+        let n_pts: usize = (((hi - lo) / delta).round() as usize).max(1);
+        for k in 0..=n_pts {
+            let f: f64 = lo + (hi - lo) * k as f64 / n_pts as f64;
+            let c: f64 = if odd { 1.0 } else { (PI * f).cos() };
+            grid.push(f);
+            des.push(desired[b] / c);
+            wt.push(weights[b] * c);
+            band_start.push(k == 0);
+        }
+    }
+    let band_end: Vec<bool> = (0..grid.len()).map(|k| k + 1 == grid.len() || band_start[k + 1]).collect();
+    let x_grid: Vec<f64> = grid.iter().map(|f| (2.0 * PI * f).cos()).collect();
+
+    assert!(grid.len() > r, "The bands are too narrow for this number of taps!");
+
+    // Initial extremal frequencies, evenly spread on the grid
+    let mut ext: Vec<usize> = (0..=r).map(|k| k * (grid.len() - 1) / r).collect();
+    let mut interp: (Vec<f64>, Vec<f64>, Vec<f64>) = (vec![], vec![], vec![]);
+
+    for _ in 0..REMEZ_ITERATIONS {
+
+        // Levelled error on the extremal set
+        let x: Vec<f64> = ext.iter().map(|&i| x_grid[i]).collect();
+        let gamma: Vec<f64> = barycentric_weights(&x);
+        let (num, den): (f64, f64) = ext.iter().enumerate().fold((0.0, 0.0), |(num, den), (k, &i)| {
+            let sign: f64 = if k % 2 == 0 { 1.0 } else { -1.0 };
+            (num + gamma[k] * des[i], den + sign * gamma[k] / wt[i])
+        });
+        let dev: f64 = num / den;
+
+        // Interpolating polynomial through the first r points
+        let x_int: Vec<f64> = x[..r].to_vec();
+        let c_int: Vec<f64> = ext[..r].iter().enumerate().map(|(k, &i)| {
+            let sign: f64 = if k % 2 == 0 { 1.0 } else { -1.0 };
+            des[i] - sign * dev / wt[i]
+        }).collect();
+        let g_int: Vec<f64> = barycentric_weights(&x_int);
+
+        let err: Vec<f64> = (0..grid.len()).map(|i| wt[i] * (des[i] - barycentric_eval(&x_int, &g_int, &c_int, x_grid[i]))).collect();
+        interp = (x_int, g_int, c_int);
+
+        // New extremal set: local extrema of the error, with alternating signs
+        let mut candidates: Vec<usize> = Vec::new();
+        for i in 0..grid.len() {
+            let left: f64 = if band_start[i] { f64::NAN } else { err[i - 1] };
+            let right: f64 = if band_end[i] { f64::NAN } else { err[i + 1] };
+            let is_max: bool = err[i] > 0.0 && left.partial_cmp(&err[i]) != Some(std::cmp::Ordering::Greater) && right.partial_cmp(&err[i]) != Some(std::cmp::Ordering::Greater);
+            let is_min: bool = err[i] < 0.0 && left.partial_cmp(&err[i]) != Some(std::cmp::Ordering::Less) && right.partial_cmp(&err[i]) != Some(std::cmp::Ordering::Less);
+            if is_max || is_min {
+                candidates.push(i);
+            }
+        }
+
+        let mut alternating: Vec<usize> = Vec::new();
+        for i in candidates {
+            match alternating.last() {
+                Some(&last) if err[last].signum() == err[i].signum() => {
+                    if err[i].abs() > err[last].abs() {
+                        *alternating.last_mut().unwrap() = i;
+                    }
+                },
+                _ => alternating.push(i)
+            }
+        }
+        while alternating.len() > r + 1 {
+            if err[alternating[0]].abs() < err[*alternating.last().unwrap()].abs() {
+                alternating.remove(0);
+            } else {
+                alternating.pop();
+            }
+        }
+
+        if alternating.len() < r + 1 {
+            break;
+        }
+
+        // Converged when the error is levelled
+        let max_err: f64 = alternating.iter().fold(0.0_f64, |m, &i| m.max(err[i].abs()));
+        let converged: bool = alternating == ext || max_err - dev.abs() <= 1.0e-10 * dev.abs();
+        ext = alternating;
+        if converged {
+            break;
+        }
+    }
+
+    // Sampling the response on numtaps frequencies and transforming back
+    let (x_int, g_int, c_int) = interp;
+    let center: f64 = (numtaps as f64 - 1.0) / 2.0;
+    let response: Vec<f64> = (0..numtaps).map(|j| {
+        let f: f64 = j as f64 / numtaps as f64;
+        let c: f64 = if odd { 1.0 } else { (PI * f).cos() };
+        c * barycentric_eval(&x_int, &g_int, &c_int, (2.0 * PI * f).cos())
+    }).collect();
+
+    (0..numtaps).map(|n| {
+        response.iter().enumerate().map(|(j, a)| {
+            a * (2.0 * PI * j as f64 * (n as f64 - center) / numtaps as f64).cos()
+        }).sum::<f64>() / numtaps as f64
+    }).collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Analog prototype
+/// 
+/// ## Definition
+/// Computes the zeros, poles and gain of the analog low-pass prototype of order $n$, with a cutoff at 1 rad/s:
+/// - **Butterworth**: poles evenly spread on the left half of the unit circle
+/// - **Chebyshev I**: poles on an ellipse, the gain at the cutoff being the pass-band ripple
+/// - **Chebyshev II**: poles and zeros such that the attenuation is reached at the cutoff
+/// - **Elliptic**: from the Jacobi elliptic functions, the gain at the cutoff being the pass-band ripple
+/// - **Bessel**: roots of the reverse Bessel polynomial, scaled for a phase midpoint at the cutoff
+/// 
+/// ## Inputs
+/// - `order`: the order of the filter ($n$)
+/// - `prototype`: the prototype family
+/// 
+/// Returns the analog prototype.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::filter::{ analog_prototype, Prototype };
+/// let butter = analog_prototype(3, Prototype::Butterworth);
+/// assert_eq!(butter.poles.len(), 3);
+/// assert!(butter.poles.iter().all(|p| (p.norm() - 1.0).abs() < 1.0e-15 && p.re < 0.0));
+/// 
+/// let bessel = analog_prototype(4, Prototype::Bessel);
+/// assert!(bessel.poles.iter().all(|p| p.re < 0.0));
+/// ```
+pub fn analog_prototype(order: usize, prototype: Prototype) -> Zpk {
+
+    assert!(order > 0, "The order must be positive!");
+
+    let n: f64 = order as f64;
+    // Angles of the Butterworth poles
+    let theta: Vec<f64> = (0..order).map(|k| PI * (2.0 * k as f64 - n + 1.0) / (2.0 * n)).collect();
+
+    match prototype {
+        Prototype::Butterworth => {
+            Zpk {
+                zeros: vec![],
+                poles: theta.iter().map(|t| -Complex64::from_polar(1.0, *t)).collect(),
+                gain: 1.0
+            }
+        },
+        Prototype::ChebyshevI { ripple } => {
+            let eps: f64 = (10.0_f64.powf(0.1 * ripple) - 1.0).sqrt();
+            let mu: f64 = (1.0 / eps).asinh() / n;
+            let poles: Vec<Complex64> = theta.iter().map(|t| -Complex64::new(mu, *t).sinh()).collect();
+            let mut gain: f64 = prod(&poles.iter().map(|p| -p).collect::<Vec<Complex64>>()).re;
+            if order.is_multiple_of(2) {
+                gain /= (1.0 + eps.powi(2)).sqrt();
+            }
+            Zpk {
+                zeros: vec![],
+                poles,
+                gain
+            }
+        },
+        Prototype::ChebyshevII { attenuation } => {
+            let de: f64 = 1.0 / (10.0_f64.powf(0.1 * attenuation) - 1.0).sqrt();
+            let mu: f64 = (1.0 / de).asinh() / n;
+
+            // Zeros on the imaginary axis, skipping the infinite one for odd orders
+            let zeros: Vec<Complex64> = theta.iter().filter(|t| t.abs() > 1.0e-12).map(|t| {
+                Complex64::new(0.0, 1.0 / t.sin())
+            }).collect();
+            let poles: Vec<Complex64> = theta.iter().map(|t| {
+                let p: Complex64 = -Complex64::from_polar(1.0, *t);
+                1.0 / Complex64::new(mu.sinh() * p.re, mu.cosh() * p.im)
+            }).collect();
+            let gain: f64 = (prod(&poles.iter().map(|p| -p).collect::<Vec<Complex64>>())
+                / prod(&zeros.iter().map(|z| -z).collect::<Vec<Complex64>>())).re;
+
+            Zpk {
+                zeros,
+                poles,
+                gain
+            }
+        },
+        Prototype::Elliptic { ripple, attenuation } => elliptic_prototype(order, ripple, attenuation),
+        Prototype::Bessel => {
+
+            // Reverse Bessel polynomial, in decreasing powers
+            let mut coefs: Vec<f64> = vec![1.0; order + 1];
+            for k in (1..=order).rev() {
+                coefs[order - k + 1] = coefs[order - k] * ((2 * order - k + 1) * k) as f64 / (2 * (order - k + 1)) as f64;
+            }
+
+            // Normalized such that the product of the poles is one
+            let scale: f64 = coefs[order].powf(-1.0 / n);
+            let poles: Vec<Complex64> = clean_conjugates(&poly_roots(&coefs)).iter().map(|p| p * scale).collect();
+
+            Zpk {
+                zeros: vec![],
+                poles,
+                gain: 1.0
+            }
+        }
+    }
+}
+
+/// # IIR filter design
+/// 
+/// ## Definition
+/// Designs a digital IIR filter from an analog prototype. The cutoff frequencies are pre-warped, the prototype
+/// is transformed to the requested band type, and the
+/// [bilinear transform](https://en.wikipedia.org/wiki/Bilinear_transform) $s = 2f_s\frac{z-1}{z+1}$
+/// brings it to the digital domain. Band-pass and band-stop filters have twice the order of the prototype.
+/// 
+/// ## Inputs
+/// - `order`: the order of the prototype
+/// - `cutoff`: the cutoff frequency, or the two band edges for band-pass and band-stop filters
+/// - `band`: the type of filter
+/// - `prototype`: the analog prototype family
+/// - `fs`: the sampling rate ($f_s$), in the units of the cutoff
+/// 
+/// Returns the zeros, poles and gain of the digital filter.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::filter::{ iir_design, BandType, Prototype };
+/// // Butterworth low-pass at a quarter of the sampling rate
+/// let (b, a) = iir_design(2, &[0.25], BandType::LowPass, Prototype::Butterworth, 1.0).to_ba();
+/// let expected_b: Vec<f64> = vec![0.29289321881345254, 0.5857864376269051, 0.29289321881345254];
+/// for (v, e) in b.iter().zip(&expected_b) {
+///     assert!((v - e).abs() < 1.0e-12);
+/// }
+/// assert!(a[1].abs() < 1.0e-12 && (a[2] - 0.17157287525381).abs() < 1.0e-12);
+/// 
+/// // Elliptic band-stop, with stable poles
+/// let proto = Prototype::Elliptic { ripple: 0.5, attenuation: 60.0 };
+/// let bs = iir_design(4, &[50.0, 60.0], BandType::BandStop, proto, 500.0);
+/// assert_eq!(bs.poles.len(), 8);
+/// assert!(bs.poles.iter().all(|p| p.norm() < 1.0));
+/// ```
+pub fn iir_design(order: usize, cutoff: &[f64], band: BandType, prototype: Prototype, fs: f64) -> Zpk {
+
+    let proto: Zpk = analog_prototype(order, prototype);
+
+    // Pre-warping the cutoffs, such that they are exact after the bilinear transform
+    let warped: Vec<f64> = cutoff.iter().map(|f| {
+        assert!(*f > 0.0 && *f < fs / 2.0, "The cutoffs must be between 0 and the Nyquist frequency!");
+        2.0 * fs * (PI * f / fs).tan()
+    }).collect();
+
+    let analog: Zpk = match band {
+        BandType::LowPass => lp2lp(&proto, warped[0]),
+        BandType::HighPass => lp2hp(&proto, warped[0]),
+        BandType::BandPass => lp2bp(&proto, (warped[0] * warped[1]).sqrt(), warped[1] - warped[0]),
+        BandType::BandStop => lp2bs(&proto, (warped[0] * warped[1]).sqrt(), warped[1] - warped[0])
+    };
+
+    bilinear(&analog, fs)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Filtering with a transfer function
+/// 
+/// ## Definition
+/// Applies the filter with coefficients `b` and `a` to the signal, in direct form II transposed:
+/// $$
+/// a_0y_n = \sum_k b_kx_{n-k} - \sum_{k\ge1} a_ky_{n-k}
+/// $$
+/// 
+/// ## Inputs
+/// - `b`: the numerator coefficients
+/// - `a`: the denominator coefficients, with $a_0\neq0$
+/// - `x`: the signal
+/// - `zi`: the initial state of the filter, of length `max(len(a), len(b)) - 1`, zero if `None`
+/// 
+/// Returns the filtered signal and the final state of the filter, which can be passed as initial
+/// state for the next part of the signal.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::filter::lfilter;
+/// // Moving average and exponential smoothing
+/// let x: Vec<f64> = vec![1.0, 2.0, 3.0, 4.0];
+/// let (y, _) = lfilter(&[0.5, 0.5], &[1.0], &x, None);
+/// assert_eq!(y, vec![0.5, 1.5, 2.5, 3.5]);
+/// 
+/// let (y2, zf) = lfilter(&[0.5], &[1.0, -0.5], &x[..2], None);
+/// let (y3, _) = lfilter(&[0.5], &[1.0, -0.5], &x[2..], Some(&zf));
+/// assert_eq!([y2, y3].concat(), lfilter(&[0.5], &[1.0, -0.5], &x, None).0);
+/// ```
+pub fn lfilter(b: &[f64], a: &[f64], x: &[f64], zi: Option<&[f64]>) -> (Vec<f64>, Vec<f64>) {
+
+    assert!(!a.is_empty() && a[0] != 0.0, "The first denominator coefficient cannot be zero!");
+
+    // Normalized coefficients of the same length
+    let n: usize = a.len().max(b.len());
+    let mut b_n: Vec<f64> = b.iter().map(|v| v / a[0]).collect();
+    let mut a_n: Vec<f64> = a.iter().map(|v| v / a[0]).collect();
+    b_n.resize(n, 0.0);
+    a_n.resize(n, 0.0);
+
+    let mut z: Vec<f64> = match zi {
+        Some(state) => {
+            assert_eq!(state.len(), n - 1, "The initial state must have max(len(a), len(b)) - 1 values!");
+            state.to_vec()
+        },
+        None => vec![0.0; n - 1]
+    };
+
+    let y: Vec<f64> = x.iter().map(|xn| {
+        let yn: f64 = b_n[0] * xn + z.first().copied().unwrap_or(0.0);
+        for i in 0..n.saturating_sub(1) {
+            let next: f64 = if i + 2 < n { z[i + 1] } else { 0.0 };
+            z[i] = b_n[i + 1] * xn + next - a_n[i + 1] * yn;
+        }
+        yn
+    }).collect();
+
+    (y, z)
+}
+
+/// # Steady-state initial conditions of a transfer function
+/// 
+/// ## Definition
+/// Computes the state of the filter once it has settled for a constant unit input, such that filtering
+/// a signal starting at $x_0$ with the initial state $x_0z_i$ avoids the start-up transient.
+/// 
+/// ## Inputs
+/// - `b`: the numerator coefficients
+/// - `a`: the denominator coefficients
+/// 
+/// Returns the initial state.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::filter::{ lfilter, lfilter_zi, iir_design, BandType, Prototype };
+/// let (b, a) = iir_design(3, &[0.1], BandType::LowPass, Prototype::Butterworth, 1.0).to_ba();
+/// let zi: Vec<f64> = lfilter_zi(&b, &a).iter().map(|v| 2.0 * v).collect();
+/// 
+/// // A constant signal goes through unchanged
+/// let (y, _) = lfilter(&b, &a, &[2.0; 20], Some(&zi));
+/// assert!(y.iter().all(|v| (v - 2.0).abs() < 1.0e-12));
+/// ```
+pub fn lfilter_zi(b: &[f64], a: &[f64]) -> Vec<f64> {
+
+    let n: usize = a.len().max(b.len());
+    let mut b_n: Vec<f64> = b.iter().map(|v| v / a[0]).collect();
+    let mut a_n: Vec<f64> = a.iter().map(|v| v / a[0]).collect();
+    b_n.resize(n, 0.0);
+    a_n.resize(n, 0.0);
+
+    if n <= 1 {
+        return vec![];
+    }
+
+    // (I - A^T) zi = B, with A the companion matrix of a
+    let m: usize = n - 1;
+    let mut mat: Vec<Vec<f64>> = vec![vec![0.0; m]; m];
+    for (i, row) in mat.iter_mut().enumerate() {
+        row[i] += 1.0;
+        row[0] += a_n[i + 1];
+        if i + 1 < m {
+            row[i + 1] -= 1.0;
+        }
+    }
+    let rhs: Vec<f64> = (1..n).map(|i| b_n[i] - a_n[i] * b_n[0]).collect();
+
+    solve_linear(mat, rhs)
+}
+
+/// # Filtering with second-order sections
+/// 
+/// ## Definition
+/// Applies the cascaded second-order sections to the signal, each section being applied with `lfilter`.
+/// This is numerically much more robust than the transfer function for high order filters.
+/// 
+/// ## Inputs
+/// - `sos`: the sections, as `[b0, b1, b2, a0, a1, a2]`
+/// - `x`: the signal
+/// - `zi`: the initial state of each section, zero if `None`
+/// 
+/// Returns the filtered signal and the final state of each section.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::filter::{ sosfilt, lfilter, iir_design, BandType, Prototype };
+/// let filter = iir_design(4, &[0.1, 0.2], BandType::BandPass, Prototype::ChebyshevII { attenuation: 40.0 }, 1.0);
+/// let x: Vec<f64> = (0..100).map(|k| (0.4 * k as f64).sin() + (k % 7) as f64).collect();
+/// 
+/// let (y_sos, _) = sosfilt(&filter.to_sos(), &x, None);
+/// let (b, a) = filter.to_ba();
+/// let (y_ba, _) = lfilter(&b, &a, &x, None);
+/// for (s, t) in y_sos.iter().zip(&y_ba) {
+///     assert!((s - t).abs() < 1.0e-9);
+/// }
+/// ```
+pub fn sosfilt(sos: &[[f64; 6]], x: &[f64], zi: Option<&[[f64; 2]]>) -> (Vec<f64>, Vec<[f64; 2]>) {
+
+    if let Some(state) = zi {
+        assert_eq!(state.len(), sos.len(), "There must be one initial state per section!");
+    }
+
+    let mut y: Vec<f64> = x.to_vec();
+    let mut zf: Vec<[f64; 2]> = Vec::with_capacity(sos.len());
+
+    for (s, section) in sos.iter().enumerate() {
+        let state: [f64; 2] = zi.map(|z| z[s]).unwrap_or([0.0; 2]);
+        let (ys, zs) = lfilter(&section[..3], &section[3..], &y, Some(&state));
+        y = ys;
+        zf.push([zs[0], zs[1]]);
+    }
+
+    (y, zf)
+}
+
+/// # Steady-state initial conditions of second-order sections
+/// 
+/// ## Definition
+/// Computes the state of each section once the cascade has settled for a constant unit input,
+/// see `lfilter_zi`.
+/// 
+/// ## Inputs
+/// - `sos`: the sections, as `[b0, b1, b2, a0, a1, a2]`
+/// 
+/// Returns the initial state of each section.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::filter::{ sosfilt, sosfilt_zi, iir_design, BandType, Prototype };
+/// let sos = iir_design(6, &[0.05], BandType::LowPass, Prototype::Butterworth, 1.0).to_sos();
+/// let zi: Vec<[f64; 2]> = sosfilt_zi(&sos).iter().map(|z| [-z[0], -z[1]]).collect();
+/// 
+/// let (y, _) = sosfilt(&sos, &[-1.0; 50], Some(&zi));
+/// assert!(y.iter().all(|v| (v + 1.0).abs() < 1.0e-10));
+/// ```
+pub fn sosfilt_zi(sos: &[[f64; 6]]) -> Vec<[f64; 2]> {
+
+    let mut scale: f64 = 1.0;
+
+    sos.iter().map(|section| {
+        let zi: Vec<f64> = lfilter_zi(&section[..3], &section[3..]);
+        let res: [f64; 2] = [scale * zi[0], scale * zi[1]];
+
+        // The next section sees the DC gain of the previous ones
+        scale *= section[..3].iter().sum::<f64>() / section[3..].iter().sum::<f64>();
+        res
+    }).collect()
+}
+
+/// # Zero-phase filtering with a transfer function
+/// 
+/// ## Definition
+/// Applies the filter forward, then backward, such that the result has no phase shift and the
+/// square of the magnitude response. The signal is extended at both ends by odd reflection over
+/// $3\max(n_a, n_b)$ points, and the steady-state initial conditions of `lfilter_zi` are used,
+/// which limits the edge transients.
+/// 
+/// ## Inputs
+/// - `b`: the numerator coefficients
+/// - `a`: the denominator coefficients
+/// - `x`: the signal, longer than the extension
+/// 
+/// Returns the filtered signal.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::filter::{ filtfilt, iir_design, BandType, Prototype };
+/// let (b, a) = iir_design(4, &[0.05], BandType::LowPass, Prototype::Butterworth, 1.0).to_ba();
+/// 
+/// // A slow sinusoid is kept in phase, the fast oscillation is removed
+/// let slow: Vec<f64> = (0..400).map(|k| (0.02 * k as f64).sin()).collect();
+/// let x: Vec<f64> = slow.iter().enumerate().map(|(k, v)| v + 0.5 * (2.5 * k as f64).sin()).collect();
+/// let y = filtfilt(&b, &a, &x);
+/// for (v, s) in y[50..350].iter().zip(&slow[50..350]) {
+///     assert!((v - s).abs() < 1.0e-2);
+/// }
+/// ```
+pub fn filtfilt(b: &[f64], a: &[f64], x: &[f64]) -> Vec<f64> {
+
+    let pad: usize = 3 * a.len().max(b.len());
+    let ext: Vec<f64> = odd_extension(x, pad);
+    let zi: Vec<f64> = lfilter_zi(b, a);
+
+    // Forward pass
+    let z0: Vec<f64> = zi.iter().map(|v| v * ext[0]).collect();
+    let (mut y, _) = lfilter(b, a, &ext, Some(&z0));
+
+    // Backward pass
+    y.reverse();
+    let z1: Vec<f64> = zi.iter().map(|v| v * y[0]).collect();
+    let (mut y, _) = lfilter(b, a, &y, Some(&z1));
+    y.reverse();
+
+    y[pad..pad + x.len()].to_vec()
+}
+
+/// # Zero-phase filtering with second-order sections
+/// 
+/// ## Definition
+/// Same as `filtfilt`, with second-order sections. The signal is extended over $3(2n_s + 1)$ points
+/// for $n_s$ sections.
+/// 
+/// ## Inputs
+/// - `sos`: the sections, as `[b0, b1, b2, a0, a1, a2]`
+/// - `x`: the signal, longer than the extension
+/// 
+/// Returns the filtered signal.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::filter::{ sosfiltfilt, filtfilt, iir_design, BandType, Prototype };
+/// let filter = iir_design(3, &[0.1, 0.3], BandType::BandPass, Prototype::Butterworth, 1.0);
+/// let x: Vec<f64> = (0..300).map(|k| (0.9 * k as f64).sin() + (0.05 * k as f64).cos()).collect();
+/// 
+/// let (b, a) = filter.to_ba();
+/// let y_ba = filtfilt(&b, &a, &x);
+/// let y_sos = sosfiltfilt(&filter.to_sos(), &x);
+/// for (s, t) in y_sos[50..250].iter().zip(&y_ba[50..250]) {
+///     assert!((s - t).abs() < 1.0e-6);
+/// }
+/// ```
+pub fn sosfiltfilt(sos: &[[f64; 6]], x: &[f64]) -> Vec<f64> {
+
+    let pad: usize = 3 * (2 * sos.len() + 1);
+    let ext: Vec<f64> = odd_extension(x, pad);
+    let zi: Vec<[f64; 2]> = sosfilt_zi(sos);
+
+    // Forward pass
+    let z0: Vec<[f64; 2]> = zi.iter().map(|z| [z[0] * ext[0], z[1] * ext[0]]).collect();
+    let (mut y, _) = sosfilt(sos, &ext, Some(&z0));
+
+    // Backward pass
+    y.reverse();
+    let z1: Vec<[f64; 2]> = zi.iter().map(|z| [z[0] * y[0], z[1] * y[0]]).collect();
+    let (mut y, _) = sosfilt(sos, &y, Some(&z1));
+    y.reverse();
+
+    y[pad..pad + x.len()].to_vec()
+}
+
+/// # Frequency response of a transfer function
+/// 
+/// ## Definition
+/// Evaluates the transfer function on the unit circle, $z = e^{i\omega}$, at $n$ frequencies
+/// evenly spaced from zero (included) to the Nyquist frequency (excluded):
+/// $$
+/// H(e^{i\omega}) = \frac{\sum_k b_ke^{-ik\omega}}{\sum_k a_ke^{-ik\omega}}, \quad \omega = 2\pi\frac{f}{f_s}
+/// $$
+/// 
+/// ## Inputs
+/// - `b`: the numerator coefficients
+/// - `a`: the denominator coefficients
+/// - `n`: the number of frequencies
+/// - `fs`: the sampling rate ($f_s$)
+/// 
+/// Returns the frequencies, in the units of the sampling rate, and the complex response.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::filter::{ freqz, iir_design, BandType, Prototype };
+/// let (b, a) = iir_design(4, &[100.0], BandType::LowPass, Prototype::Butterworth, 1000.0).to_ba();
+/// let (f, h) = freqz(&b, &a, 500, 1000.0);
+/// 
+/// // Unit gain at zero frequency and -3 dB at the cutoff
+/// assert_eq!(f[100], 100.0);
+/// assert!((h[0].norm() - 1.0).abs() < 1.0e-12);
+/// assert!((h[100].norm() - 0.5_f64.sqrt()).abs() < 1.0e-10);
+/// ```
+pub fn freqz(b: &[f64], a: &[f64], n: usize, fs: f64) -> (Vec<f64>, Vec<Complex64>) {
+
+    let frequencies: Vec<f64> = (0..n).map(|k| 0.5 * fs * k as f64 / n as f64).collect();
+    let response: Vec<Complex64> = frequencies.iter().map(|f| {
+        let z_inv: Complex64 = Complex64::from_polar(1.0, -2.0 * PI * f / fs);
+        polyval_inverse(b, z_inv) / polyval_inverse(a, z_inv)
+    }).collect();
+
+    (frequencies, response)
+}
+
+/// # Frequency response of second-order sections
+/// 
+/// ## Definition
+/// Evaluates the product of the sections on the unit circle, see `freqz`.
+/// 
+/// ## Inputs
+/// - `sos`: the sections, as `[b0, b1, b2, a0, a1, a2]`
+/// - `n`: the number of frequencies
+/// - `fs`: the sampling rate ($f_s$)
+/// 
+/// Returns the frequencies, in the units of the sampling rate, and the complex response.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::filter::{ sosfreqz, iir_design, BandType, Prototype };
+/// let proto = Prototype::Elliptic { ripple: 1.0, attenuation: 80.0 };
+/// let sos = iir_design(8, &[0.2], BandType::HighPass, proto, 1.0).to_sos();
+/// let (f, h) = sosfreqz(&sos, 200, 1.0);
+/// 
+/// // At least 80 dB of attenuation in the stop-band, at most 1 dB of ripple in the pass-band
+/// let db: Vec<f64> = h.iter().map(|v| 20.0 * v.norm().log10()).collect();
+/// assert!(db[..60].iter().all(|v| *v < -80.0 + 1.0e-6));
+/// assert!(db[80..].iter().all(|v| *v > -1.0 - 1.0e-6));
+/// ```
+pub fn sosfreqz(sos: &[[f64; 6]], n: usize, fs: f64) -> (Vec<f64>, Vec<Complex64>) {
+
+    let frequencies: Vec<f64> = (0..n).map(|k| 0.5 * fs * k as f64 / n as f64).collect();
+    let response: Vec<Complex64> = frequencies.iter().map(|f| {
+        let z_inv: Complex64 = Complex64::from_polar(1.0, -2.0 * PI * f / fs);
+        sos.iter().fold(Complex64::new(1.0, 0.0), |acc, section| {
+            acc * polyval_inverse(&section[..3], z_inv) / polyval_inverse(&section[3..], z_inv)
+        })
+    }).collect();
+
+    (frequencies, response)
+}
+
+/// # Group delay of a transfer function
+/// 
+/// ## Definition
+/// The group delay is the negative derivative of the phase of the response:
+/// $$
+/// \tau_g(\omega) = -\frac{d\arg H(e^{i\omega})}{d\omega}
+/// $$
+/// It is computed exactly from the coefficients, with $c = b * \tilde{a}$, the convolution of the numerator
+/// with the reversed denominator:
+/// $$
+/// \tau_g(\omega) = \Re\left(\frac{\sum_k kc_ke^{-ik\omega}}{\sum_k c_ke^{-ik\omega}}\right) - N
+/// $$
+/// The delay is set to zero where the response vanishes.
+/// 
+/// ## Inputs
+/// - `b`: the numerator coefficients
+/// - `a`: the denominator coefficients
+/// - `n`: the number of frequencies, as in `freqz`
+/// - `fs`: the sampling rate ($f_s$)
+/// 
+/// Returns the frequencies, in the units of the sampling rate, and the group delay in samples.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::filter::{ group_delay, firwin, BandType };
+/// # use scilib::signal::window::{ hann, Symmetry };
+/// // Linear phase FIR filters delay all frequencies by half their length
+/// let h = firwin(21, &[0.2], BandType::LowPass, &hann(21, Symmetry::Symmetric), 1.0);
+/// let (_, gd) = group_delay(&h, &[1.0], 64, 1.0);
+/// assert!(gd[..20].iter().all(|d| (d - 10.0).abs() < 1.0e-8));
+/// 
+/// // Pure delay
+/// let (_, gd) = group_delay(&[0.0, 0.0, 1.0], &[1.0], 8, 1.0);
+/// assert!(gd.iter().all(|d| (d - 2.0).abs() < 1.0e-12));
+/// ```
+pub fn group_delay(b: &[f64], a: &[f64], n: usize, fs: f64) -> (Vec<f64>, Vec<f64>) {
+
+    // Convolution of b with the reversed a
+    let mut c: Vec<f64> = vec![0.0; b.len() + a.len() - 1];
+    for (i, bi) in b.iter().enumerate() {
+        for (j, aj) in a.iter().rev().enumerate() {
+            c[i + j] += bi * aj;
+        }
+    }
+    let cr: Vec<f64> = c.iter().enumerate().map(|(k, v)| k as f64 * v).collect();
+
+    let frequencies: Vec<f64> = (0..n).map(|k| 0.5 * fs * k as f64 / n as f64).collect();
+    let delay: Vec<f64> = frequencies.iter().map(|f| {
+        let z_inv: Complex64 = Complex64::from_polar(1.0, -2.0 * PI * f / fs);
+        let den: Complex64 = polyval_inverse(&c, z_inv);
+        if den.norm() < 10.0 * f64::EPSILON * c.iter().fold(0.0, |acc, v| acc + v.abs()) {
+            0.0
+        } else {
+            (polyval_inverse(&cr, z_inv) / den).re - (a.len() - 1) as f64
+        }
+    }).collect();
+
+    (frequencies, delay)
+}
+
+/// # Stability of a transfer function
+/// 
+/// ## Definition
+/// A causal digital filter is stable when all the roots of its denominator are strictly inside the unit circle.
+/// 
+/// ## Inputs
+/// - `a`: the denominator coefficients
+/// 
+/// Returns `true` if the filter is stable.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::filter::is_stable;
+/// assert!(is_stable(&[1.0, -1.5, 0.7]));
+/// assert!(!is_stable(&[1.0, -2.5, 1.0]));
+/// assert!(!is_stable(&[1.0, -1.0]));
+/// ```
+pub fn is_stable(a: &[f64]) -> bool {
+    poly_roots(a).iter().all(|p| p.norm() < 1.0)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Savitzky-Golay coefficients
+/// 
+/// ## Definition
+/// A polynomial of order $p$ is fitted by least squares to the $m$ samples of a window, and its derivative of
+/// order $d$ is evaluated at the center of the window, $c = (m-1)/2$. The estimate is linear in the samples:
+/// $$
+/// y_n = \sum_{j=0}^{m-1} h_jx_{n+c-j}
+/// $$
+/// such that the filter is applied by convolution. The filter preserves the polynomials of order up to $p$,
+/// and the derivatives are scaled by the sample spacing $\Delta$. With an even window length, the center is
+/// between two samples.
+/// 
+/// ## Inputs
+/// - `window`: the window length ($m$), greater than the polynomial order
+/// - `order`: the order of the fitted polynomial ($p$)
+/// - `deriv`: the order of the derivative ($d$), zero for smoothing
+/// - `delta`: the spacing of the samples ($\Delta$), only used for derivatives
+/// 
+/// Returns the coefficients, in convolution order.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::filter::savgol_coeffs;
+/// let smooth = savgol_coeffs(5, 2, 0, 1.0);
+/// let expected: Vec<f64> = vec![-3.0, 12.0, 17.0, 12.0, -3.0];
+/// for (c, e) in smooth.iter().zip(&expected) {
+///     assert!((c - e / 35.0).abs() < 1.0e-14);
+/// }
+/// 
+/// // First derivative, the coefficients being reversed for the convolution
+/// let slope = savgol_coeffs(5, 2, 1, 0.5);
+/// let expected: Vec<f64> = vec![2.0, 1.0, 0.0, -1.0, -2.0];
+/// for (c, e) in slope.iter().zip(&expected) {
+///     assert!((c - e / 5.0).abs() < 1.0e-14);
+/// }
+/// ```
+pub fn savgol_coeffs(window: usize, order: usize, deriv: usize, delta: f64) -> Vec<f64> {
+
+    assert!(order < window, "The polynomial order must be less than the window length!");
+
+    if deriv > order {
+        return vec![0.0; window];
+    }
+
+    let (z, scale): (Vec<f64>, f64) = savgol_positions(window);
+
+    // Row of the pseudo-inverse giving the coefficient of order d of the fit
+    let mut unit: Vec<f64> = vec![0.0; order + 1];
+    unit[deriv] = 1.0;
+    let row: Vec<f64> = solve_linear(savgol_normal(&z, order), unit);
+    let kernel: Poly = Poly::from(&row.iter().copied().enumerate().collect::<Vec<(usize, f64)>>());
+
+    let factor: f64 = basic::factorial(deriv) as f64 / (delta * scale).powi(deriv as i32);
+    z.iter().rev().map(|&v| factor * kernel.compute(v)).collect()
+}
+
+/// # Savitzky-Golay filter
+/// 
+/// ## Definition
+/// Convolves the signal with the Savitzky-Golay coefficients, smoothing it or estimating its derivative while
+/// preserving the polynomials of order up to $p$. Away from the edges, each output is the value or derivative
+/// of the polynomial fitted on the window centered on the sample. On the edges, the signal is either extended,
+/// or the polynomial fitted on the first or last window is evaluated on the edge samples (`SavgolMode::Interp`).
+/// 
+/// ## Inputs
+/// - `x`: the signal
+/// - `window`: the window length, odd and greater than the polynomial order
+/// - `order`: the order of the fitted polynomial
+/// - `deriv`: the order of the derivative, zero for smoothing
+/// - `delta`: the spacing of the samples, only used for derivatives
+/// - `mode`: the handling of the edges
+/// 
+/// Returns the filtered signal, of the same length as the input.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::filter::{ savgol_filter, SavgolMode };
+/// // A cubic is preserved, and so is its derivative
+/// let t: Vec<f64> = (0..40).map(|k| k as f64 * 0.1).collect();
+/// let x: Vec<f64> = t.iter().map(|v| v.powi(3) - 2.0 * v).collect();
+/// 
+/// let y = savgol_filter(&x, 9, 3, 0, 0.1, SavgolMode::Interp);
+/// let dy = savgol_filter(&x, 9, 3, 1, 0.1, SavgolMode::Interp);
+/// for k in 0..40 {
+///     assert!((y[k] - x[k]).abs() < 1.0e-10);
+///     assert!((dy[k] - 3.0 * t[k].powi(2) + 2.0).abs() < 1.0e-9);
+/// }
+/// 
+/// // With an extension, the edges are only approximated
+/// let z = savgol_filter(&x, 9, 3, 0, 0.1, SavgolMode::Mirror);
+/// assert!((z[20] - x[20]).abs() < 1.0e-10);
+/// assert!((z[0] - x[0]).abs() > 1.0e-3);
+/// ```
+pub fn savgol_filter(x: &[f64], window: usize, order: usize, deriv: usize, delta: f64, mode: SavgolMode) -> Vec<f64> {
+
+    assert!(!window.is_multiple_of(2), "The window length must be odd!");
+    assert!(!x.is_empty(), "The signal cannot be empty!");
+
+    let half: usize = window / 2;
+    let coefs: Vec<f64> = savgol_coeffs(window, order, deriv, delta);
+
+    if mode != SavgolMode::Interp {
+        return convolve_auto(&savgol_extension(x, half, mode), &coefs, Mode::Valid);
+    }
+
+    assert!(window <= x.len(), "The window cannot be longer than the signal in interpolation mode!");
+
+    let n: usize = x.len();
+    let mut res: Vec<f64> = vec![0.0; half];
+    res.extend(convolve_auto(x, &coefs, Mode::Valid));
+    res.resize(n, 0.0);
+
+    // Polynomials fitted on the first and last windows, and their derivatives
+    let (z, scale): (Vec<f64>, f64) = savgol_positions(window);
+    let factor: f64 = (delta * scale).powi(deriv as i32).recip();
+
+    for (start, range) in [(0, 0..half), (n - window, n - half..n)] {
+        let samples: &[f64] = &x[start..start + window];
+        let rhs: Vec<f64> = (0..=order).map(|k| z.iter().zip(samples).map(|(v, s)| v.powi(k as i32) * s).sum()).collect();
+        let fit: Vec<f64> = solve_linear(savgol_normal(&z, order), rhs);
+
+        let mut poly: Poly = Poly::from(&fit.iter().copied().enumerate().collect::<Vec<(usize, f64)>>());
+        poly.derive(deriv);
+
+        for i in range {
+            res[i] = factor * poly.compute(z[i - start]);
+        }
+    }
+
+    res
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Elliptic analog prototype
+fn elliptic_prototype(order: usize, ripple: f64, attenuation: f64) -> Zpk {
+
+    let eps_sq: f64 = 10.0_f64.powf(0.1 * ripple) - 1.0;
+    let eps: f64 = eps_sq.sqrt();
+
+    if order == 1 {
+        let p: f64 = -(1.0 / eps_sq).sqrt();
+        return Zpk {
+            zeros: vec![],
+            poles: vec![p.into()],
+            gain: -p
+        };
+    }
+
+    // Selectivity from the degree equation
+    let ck1_sq: f64 = eps_sq / (10.0_f64.powf(0.1 * attenuation) - 1.0);
+    let m: f64 = elliptic_degree(order, ck1_sq);
+    let capk: f64 = ellipk(m);
+
+    let js: Vec<f64> = ((1 - order % 2)..order).step_by(2).map(|j| j as f64).collect();
+    let sncndn: Vec<(f64, f64, f64)> = js.iter().map(|j| ellipj(j * capk / order as f64, m)).collect();
+
+    // Zeros on the imaginary axis
+    let mut zeros: Vec<Complex64> = sncndn.iter().filter(|(s, _, _)| s.abs() > f64::EPSILON).map(|(s, _, _)| {
+        Complex64::new(0.0, 1.0 / (m.sqrt() * s))
+    }).collect();
+    zeros.extend(zeros.clone().iter().map(|z| z.conj()));
+
+    // Poles
+    let r: f64 = arc_jac_sn(Complex64::new(0.0, 1.0 / eps), ck1_sq).im;
+    let v0: f64 = capk * r / (order as f64 * ellipk(ck1_sq));
+    let (sv, cv, dv): (f64, f64, f64) = ellipj(v0, 1.0 - m);
+    let mut poles: Vec<Complex64> = sncndn.iter().map(|(s, c, d)| {
+        -Complex64::new(c * d * sv * cv, s * dv) / (1.0 - (d * sv).powi(2))
+    }).collect();
+    let conj: Vec<Complex64> = poles.iter().filter(|p| p.im.abs() > f64::EPSILON * p.norm()).map(|p| p.conj()).collect();
+    poles.extend(conj);
+
+    let mut gain: f64 = (prod(&poles.iter().map(|p| -p).collect::<Vec<Complex64>>())
+        / prod(&zeros.iter().map(|z| -z).collect::<Vec<Complex64>>())).re;
+    if order.is_multiple_of(2) {
+        gain /= (1.0 + eps_sq).sqrt();
+    }
+
+    Zpk {
+        zeros,
+        poles,
+        gain
+    }
+}
+
+/// # Complete elliptic integral of the first kind $K(m)$, by the arithmetic-geometric mean
+fn ellipk(m: f64) -> f64 {
+
+    let (mut a, mut b): (f64, f64) = (1.0, (1.0 - m).sqrt());
+    while (a - b).abs() > f64::EPSILON * a {
+        (a, b) = (0.5 * (a + b), (a * b).sqrt());
+    }
+
+    FRAC_PI_2 / a
+}
+
+/// # Jacobi elliptic functions $\mathrm{sn}$, $\mathrm{cn}$ and $\mathrm{dn}$, by descending Landen transformations
+fn ellipj(u: f64, m: f64) -> (f64, f64, f64) {
+
+    if m < f64::EPSILON {
+        return (u.sin(), u.cos(), 1.0);
+    }
+
+    // Arithmetic-geometric mean, keeping the ratios c/a
+    let (mut a, mut b): (f64, f64) = (1.0, (1.0 - m).sqrt());
+    let mut ratios: Vec<f64> = Vec::new();
+    while (a - b).abs() > f64::EPSILON * a && ratios.len() < 64 {
+        let c: f64 = 0.5 * (a - b);
+        (a, b) = (0.5 * (a + b), (a * b).sqrt());
+        ratios.push(c / a);
+    }
+
+    // Going back up to the amplitude
+    let mut phi: f64 = 2.0_f64.powi(ratios.len() as i32) * a * u;
+    for ratio in ratios.iter().rev() {
+        phi = 0.5 * (phi + (ratio * phi.sin()).asin());
+    }
+
+    let (sn, cn): (f64, f64) = phi.sin_cos();
+    (sn, cn, (1.0 - m * sn.powi(2)).sqrt())
+}
+
+/// # Nome-based solution of the degree equation of elliptic filters
+fn elliptic_degree(order: usize, m1: f64) -> f64 {
+
+    let q1: f64 = (-PI * ellipk(1.0 - m1) / ellipk(m1)).exp();
+    let q: f64 = q1.powf(1.0 / order as f64);
+
+    let num: f64 = (0..=7).map(|k| q.powi(k * (k + 1))).sum();
+    let den: f64 = 1.0 + 2.0 * (1..=8).map(|k| q.powi(k * k)).sum::<f64>();
+
+    16.0 * q * (num / den).powi(4)
+}
+
+/// # Inverse of the Jacobi $\mathrm{sn}$ function for complex arguments, by Landen transformations
+fn arc_jac_sn(w: Complex64, m: f64) -> Complex64 {
+
+    let complement = |kx: Complex64| ((1.0 - kx) * (1.0 + kx)).sqrt();
+
+    // Descending moduli
+    let mut ks: Vec<f64> = vec![m.sqrt()];
+    while *ks.last().unwrap() != 0.0 && ks.len() < 16 {
+        let k: f64 = *ks.last().unwrap();
+        let kp: f64 = ((1.0 - k) * (1.0 + k)).sqrt();
+        ks.push((1.0 - kp) / (1.0 + kp));
+    }
+    let capk: f64 = ks[1..].iter().map(|k| 1.0 + k).product::<f64>() * FRAC_PI_2;
+
+    let mut wn: Complex64 = w;
+    for pair in ks.windows(2) {
+        wn = 2.0 * wn / ((1.0 + pair[1]) * (1.0 + complement(pair[0] * wn)));
+    }
+
+    capk * 2.0 / PI * wn.asin()
+}
+
+/// # Low-pass to low-pass transformation
+fn lp2lp(proto: &Zpk, wo: f64) -> Zpk {
+
+    let degree: i32 = proto.poles.len() as i32 - proto.zeros.len() as i32;
+
+    Zpk {
+        zeros: proto.zeros.iter().map(|z| z * wo).collect(),
+        poles: proto.poles.iter().map(|p| p * wo).collect(),
+        gain: proto.gain * wo.powi(degree)
+    }
+}
+
+/// # Low-pass to high-pass transformation
+fn lp2hp(proto: &Zpk, wo: f64) -> Zpk {
+
+    let degree: usize = proto.poles.len() - proto.zeros.len();
+
+    let mut zeros: Vec<Complex64> = proto.zeros.iter().map(|z| wo / z).collect();
+    zeros.extend(vec![Complex64::default(); degree]);
+
+    Zpk {
+        zeros,
+        poles: proto.poles.iter().map(|p| wo / p).collect(),
+        gain: proto.gain * (prod(&proto.zeros.iter().map(|z| -z).collect::<Vec<Complex64>>())
+            / prod(&proto.poles.iter().map(|p| -p).collect::<Vec<Complex64>>())).re
+    }
+}
+
+/// # Low-pass to band-pass transformation
+fn lp2bp(proto: &Zpk, wo: f64, bw: f64) -> Zpk {
+
+    let degree: usize = proto.poles.len() - proto.zeros.len();
+    let split = |roots: &[Complex64]| -> Vec<Complex64> {
+        let scaled: Vec<Complex64> = roots.iter().map(|r| r * bw / 2.0).collect();
+        let mut res: Vec<Complex64> = scaled.iter().map(|r| r + (r * r - wo * wo).sqrt()).collect();
+        res.extend(scaled.iter().map(|r| r - (r * r - wo * wo).sqrt()));
+        res
+    };
+
+    let mut zeros: Vec<Complex64> = split(&proto.zeros);
+    zeros.extend(vec![Complex64::default(); degree]);
+
+    Zpk {
+        zeros,
+        poles: split(&proto.poles),
+        gain: proto.gain * bw.powi(degree as i32)
+    }
+}
+
+/// # Low-pass to band-stop transformation
+fn lp2bs(proto: &Zpk, wo: f64, bw: f64) -> Zpk {
+
+    let degree: usize = proto.poles.len() - proto.zeros.len();
+    let split = |roots: &[Complex64]| -> Vec<Complex64> {
+        let inverted: Vec<Complex64> = roots.iter().map(|r| bw / 2.0 / r).collect();
+        let mut res: Vec<Complex64> = inverted.iter().map(|r| r + (r * r - wo * wo).sqrt()).collect();
+        res.extend(inverted.iter().map(|r| r - (r * r - wo * wo).sqrt()));
+        res
+    };
+
+    let mut zeros: Vec<Complex64> = split(&proto.zeros);
+    zeros.extend(vec![Complex64::new(0.0, wo); degree]);
+    zeros.extend(vec![Complex64::new(0.0, -wo); degree]);
+
+    Zpk {
+        zeros,
+        poles: split(&proto.poles),
+        gain: proto.gain * (prod(&proto.zeros.iter().map(|z| -z).collect::<Vec<Complex64>>())
+            / prod(&proto.poles.iter().map(|p| -p).collect::<Vec<Complex64>>())).re
+    }
+}
+
+/// # Bilinear transform of an analog filter
+fn bilinear(analog: &Zpk, fs: f64) -> Zpk {
+
+    let fs2: f64 = 2.0 * fs;
+    let degree: usize = analog.poles.len() - analog.zeros.len();
+
+    let mut zeros: Vec<Complex64> = analog.zeros.iter().map(|z| (fs2 + z) / (fs2 - z)).collect();
+    zeros.extend(vec![Complex64::new(-1.0, 0.0); degree]);
+
+    Zpk {
+        zeros,
+        poles: analog.poles.iter().map(|p| (fs2 + p) / (fs2 - p)).collect(),
+        gain: analog.gain * (prod(&analog.zeros.iter().map(|z| fs2 - z).collect::<Vec<Complex64>>())
+            / prod(&analog.poles.iter().map(|p| fs2 - p).collect::<Vec<Complex64>>())).re
+    }
+}
+
+/// # Polynomial in decreasing powers of $z$, evaluated at $z^{-1}$
+fn polyval_inverse(coefs: &[f64], z_inv: Complex64) -> Complex64 {
+    coefs.iter().rev().fold(Complex64::default(), |acc, c| acc * z_inv + c)
+}
+
+/// # Product of complex numbers
+fn prod(values: &[Complex64]) -> Complex64 {
+    values.iter().fold(Complex64::new(1.0, 0.0), |acc, v| acc * v)
+}
+
+/// # Polynomial with the given roots, in decreasing powers
+fn poly(roots: &[Complex64]) -> Vec<Complex64> {
+
+    let mut coefs: Vec<Complex64> = vec![Complex64::new(1.0, 0.0)];
+    for r in roots {
+        coefs.push(Complex64::default());
+        for k in (1..coefs.len()).rev() {
+            let prev: Complex64 = coefs[k - 1];
+            coefs[k] -= r * prev;
+        }
+    }
+
+    coefs
+}
+
+/// # Roots of a real polynomial given in decreasing powers
+fn poly_roots(coefs: &[f64]) -> Vec<Complex64> {
+    let pow_fac: Vec<(usize, f64)> = coefs.iter().rev().copied().enumerate().collect();
+    Poly::from(&pow_fac).roots()
+}
+
+/// # Making nearly real roots real, and nearly conjugate roots exactly conjugate
+fn clean_conjugates(roots: &[Complex64]) -> Vec<Complex64> {
+
+    let (pairs, reals): (Vec<Complex64>, Vec<f64>) = split_conjugates(roots);
+    let mut res: Vec<Complex64> = Vec::with_capacity(roots.len());
+    for p in pairs {
+        res.push(p);
+        res.push(p.conj());
+    }
+    res.extend(reals.iter().map(|r| Complex64::new(*r, 0.0)));
+
+    res
+}
+
+/// # Splitting roots into complex pairs, represented by their positive imaginary part, and real roots
+fn split_conjugates(roots: &[Complex64]) -> (Vec<Complex64>, Vec<f64>) {
+
+    let tol: f64 = 1.0e-10;
+    let mut pairs: Vec<Complex64> = Vec::new();
+    let mut reals: Vec<f64> = Vec::new();
+
+    for r in roots {
+        if r.im.abs() <= tol * r.norm().max(1.0) {
+            reals.push(r.re);
+        } else if r.im > 0.0 {
+            // Averaging with the closest conjugate for exact symmetry
+            let partner: Option<&Complex64> = roots.iter().filter(|q| q.im < 0.0).min_by(|a, b| {
+                (a.conj() - r).norm().partial_cmp(&(b.conj() - r).norm()).unwrap()
+            });
+            pairs.push(match partner {
+                Some(q) => 0.5 * (r + q.conj()),
+                None => *r
+            });
+        }
+    }
+
+    (pairs, reals)
+}
+
+/// # Barycentric weights of interpolation points
+fn barycentric_weights(x: &[f64]) -> Vec<f64> {
+
+    (0..x.len()).map(|k| {
+        1.0 / (0..x.len()).filter(|j| *j != k).map(|j| 2.0 * (x[k] - x[j])).product::<f64>()
+    }).collect()
+}
+
+/// # Barycentric Lagrange interpolation
+fn barycentric_eval(x: &[f64], gamma: &[f64], values: &[f64], at: f64) -> f64 {
+
+    let mut num: f64 = 0.0;
+    let mut den: f64 = 0.0;
+    for k in 0..x.len() {
+        let diff: f64 = at - x[k];
+        if diff.abs() < 1.0e-14 {
+            return values[k];
+        }
+        num += gamma[k] * values[k] / diff;
+        den += gamma[k] / diff;
+    }
+
+    num / den
+}
+
+/// # Solving a small dense linear system by Gaussian elimination with partial pivoting
+fn solve_linear(mut mat: Vec<Vec<f64>>, mut rhs: Vec<f64>) -> Vec<f64> {
+
+    let n: usize = rhs.len();
+
+    for col in 0..n {
+        let pivot: usize = (col..n).fold(col, |m, r| if mat[r][col].abs() > mat[m][col].abs() { r } else { m });
+        mat.swap(col, pivot);
+        rhs.swap(col, pivot);
+
+        for row in col + 1..n {
+            let factor: f64 = mat[row][col] / mat[col][col];
+            let pivot_row: Vec<f64> = mat[col].clone();
+            for (v, p) in mat[row].iter_mut().zip(&pivot_row).skip(col) {
+                *v -= factor * p;
+            }
+            rhs[row] -= factor * rhs[col];
+        }
+    }
+
+    let mut x: Vec<f64> = vec![0.0; n];
+    for row in (0..n).rev() {
+        let sum: f64 = (row + 1..n).map(|k| mat[row][k] * x[k]).sum();
+        x[row] = (rhs[row] - sum) / mat[row][row];
+    }
+
+    x
+}
+
+/// # Extension of a signal by odd reflection at both ends
+fn odd_extension(x: &[f64], pad: usize) -> Vec<f64> {
+
+    assert!(x.len() > pad, "The signal is too short for the edge extension!");
+
+    let first: f64 = x[0];
+    let last: f64 = x[x.len() - 1];
+
+    let mut ext: Vec<f64> = (1..=pad).rev().map(|k| 2.0 * first - x[k]).collect();
+    ext.extend_from_slice(x);
+    ext.extend((1..=pad).map(|k| 2.0 * last - x[x.len() - 1 - k]));
+
+    ext
+}
+
+/// # Positions of the samples of a Savitzky-Golay window, centered and scaled to at most one
+fn savgol_positions(window: usize) -> (Vec<f64>, f64) {
+
+    let center: f64 = (window - 1) as f64 / 2.0;
+    let scale: f64 = center.max(1.0);
+
+    ((0..window).map(|j| (j as f64 - center) / scale).collect(), scale)
+}
+
+/// # Normal matrix of the least squares polynomial fit
+fn savgol_normal(z: &[f64], order: usize) -> Vec<Vec<f64>> {
+    (0..=order).map(|r| (0..=order).map(|c| z.iter().map(|v| v.powi((r + c) as i32)).sum()).collect()).collect()
+}
+
+/// # Extension of a signal at both ends for the Savitzky-Golay filter
+fn savgol_extension(x: &[f64], pad: usize, mode: SavgolMode) -> Vec<f64> {
+
+    let n: isize = x.len() as isize;
+
+    (-(pad as isize)..n + pad as isize).map(|i| {
+        if (0..n).contains(&i) {
+            return x[i as usize];
+        }
+        match mode {
+            SavgolMode::Constant => 0.0,
+            SavgolMode::Nearest => x[i.clamp(0, n - 1) as usize],
+            SavgolMode::Wrap => x[i.rem_euclid(n) as usize],
+            SavgolMode::Mirror | SavgolMode::Interp => {
+                if n == 1 {
+                    return x[0];
+                }
+                let m: isize = i.rem_euclid(2 * n - 2);
+                x[(if m < n { m } else { 2 * n - 2 - m }) as usize]
+            }
+        }
+    }).collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,1889 @@
+//!
+//! # Signal processing
+//! 
+//! Convolution, Fourier and chirp-Z transforms and analytic signal algorithms, along with the tools built on top of them.
+//! 
+//! Sub-modules:
+//! - **dct**: discrete cosine and sine transforms
+//! - **window**: window functions for spectral analysis
+//! - **spectral**: power spectral density estimation
+//! - **filter**: FIR and IIR filter design and application
+//! - **ntt**: number-theoretic transform and exact integer convolution
+//! - **peaks**: peak detection and characterization
+//! - **resample**: resampling and decimation
+//! - **stft**: short-time Fourier transform and spectrogram
+//! - **wavelet**: discrete and continuous wavelet transforms, wavelet denoising
+//! 
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use std::f64::consts::{     // Using std lib constants
+    PI,                     // Pi
+    TAU                     // Tau
+};
+
+use num_complex::Complex64; // Using complex numbers from the num crate
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub mod dct;
+
+pub mod filter;
+
+pub mod ntt;
+
+pub mod peaks;
+
+pub mod resample;
+
+pub mod spectral;
+
+pub mod stft;
+
+pub mod wavelet;
+
+pub mod window;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Largest prime factor handled by the Cooley-Tukey butterflies, longer primes go through Bluestein
+const MAX_RADIX: usize = 31;
+
+/// Relative cost of an FFT operation compared to a direct multiply-add, used to choose the convolution method
+const FFT_COST: f64 = 6.0;
+
+/// Difference between 2 pi and `TAU`, used to reduce large phases
+const TAU_LOW: f64 = 2.449_293_598_294_706_4e-16;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Convolution
+/// 
+/// Computes the convolution of two vectors, including the edges.
+/// 
+/// ```
+/// # use scilib::signal::convolve;
+/// // Creating two vectors to convolve
+/// let a1: Vec<f64> = vec![2.8, 2.5, 1.0, 0.5, 3.2, 0.25];
+/// let a2: Vec<f64> = vec![0.25, 1.0, 0.5];
+/// let res = convolve(&a1, &a2);
+/// let expected: Vec<f64> = vec![0.7, 3.425, 4.15, 2.375, 1.8, 3.5125, 1.85, 0.125];
+/// 
+/// assert_eq!(res, expected);
+/// ```
+pub fn convolve<T>(a_i: &[T], b_i: &[T]) -> Vec<T>
+where T: std::ops::Mul<Output = T> + std::ops::AddAssign + Default + Copy {
+
+    // We check which box is the smallest
+    let (a, b): (&[T], &[T]) = match a_i.len() < b_i.len() {
+        true => (b_i, a_i),
+        false => (a_i, b_i)
+    };
+
+    // We initialize our variables
+    let mut sum: T;
+    let l_a: usize = a.len();
+    let l_b: usize = b.len();
+    let mut res: Vec<T> = Vec::with_capacity(l_a + 2 * l_b - 2);    // Complete convolution length
+
+    // Box b starts to slide over a
+    for n in 0..(l_b - 1) {
+        sum = T::default();
+        for m in 0..=n {
+            sum += a[n-m] * b[m];
+
+        }
+        res.push(sum);
+    }
+
+    // Box b covers a completely
+    for n in (l_b - 1)..=(l_a - 1) {
+        sum = T::default();
+        for m in 0..l_b {
+            sum += a[n-m] * b[m];
+        }
+        res.push(sum);
+    }
+
+    // Box b exits the slide over a
+    for n in l_a..(l_a + l_b - 1) {
+        sum = T::default();
+        for m in (n - l_a + 1)..l_b {
+            sum += a[n-m] * b[m];
+        }
+        res.push(sum);
+    }
+
+    res
+}
+
+/// # Exact convolution
+/// 
+/// Computes the convolution of two vectors, only for points where both vectors
+/// are completely covered.
+/// 
+/// ```
+/// # use scilib::signal::convolve_full;
+/// // Creating two vectors to convolve
+/// let a1: Vec<f64> = vec![2.8, 2.5, 1.0, 12.2];
+/// let a2: Vec<f64> = vec![0.25, 1.0, 23.2, 0.25, 1.3, 2.1];
+/// let res = convolve_full(&a1, &a2);
+/// let expected: Vec<f64> = vec![62.75, 39.665, 292.42];
+/// 
+/// for (e, c) in expected.iter().zip(&res) {
+///     assert!((e - c).abs() < 1.0e-10);
+/// }
+/// ```
+pub fn convolve_full<T>(a_i: &[T], b_i: &[T]) -> Vec<T>
+where T: std::ops::Mul<Output = T> + std::ops::AddAssign + Default + Copy {
+
+    // We check which box is the smallest
+    let (a, b): (&[T], &[T]) = match a_i.len() < b_i.len() {
+        true => (b_i, a_i),
+        false => (a_i, b_i)
+    };
+
+    // We initialize our variables
+    let mut sum: T;
+    let l_a: usize = a.len();
+    let l_b: usize = b.len();
+    let mut res: Vec<T> = Vec::with_capacity(l_a - l_b + 1);    // Exact convolution length
+
+    // Box b covers a completely only
+    for n in (l_b - 1)..=(l_a - 1) {
+        sum = T::default();
+        for m in 0..l_b {
+            sum += a[n-m] * b[m];
+        }
+        res.push(sum);
+    }
+
+    res
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Output size of a convolution
+/// 
+/// For two arrays of lengths $n \ge m$:
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+    /// All the points where the arrays overlap, $n + m - 1$ values, as `convolve`
+    Full,
+    /// The central part of the full result, $n$ values
+    Same,
+    /// Only the points where the arrays overlap completely, $n - m + 1$ values, as `convolve_full`
+    Valid
+}
+
+/// # Direction of a Fourier transform
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    /// Forward transform, with the $\exp(-2i\pi kn/N)$ kernel
+    Forward,
+    /// Inverse transform, with the $\exp(2i\pi kn/N)$ kernel and the $1/N$ normalization
+    Inverse
+}
+
+/// # Algorithm retained by a plan
+#[derive(Clone, Debug)]
+enum Algorithm {
+    /// Lengths 0 and 1, nothing to compute
+    Trivial,
+    /// Mixed-radix Cooley-Tukey, with the radices and the full table of roots of unity
+    Radix {
+        factors: Vec<usize>,
+        twiddles: Vec<Complex64>
+    },
+    /// Bluestein, as a chirp-Z transform on the unit circle
+    Bluestein(CztPlan)
+}
+
+/// # Fast Fourier transform plan
+/// 
+/// ## Definition
+/// Precomputes everything needed to transform arrays of a given length in a given direction:
+/// the factorization of the length, the twiddle factors and, for lengths with large prime factors,
+/// the chirp tables of Bluestein's algorithm. A plan can then be reused on as many arrays as needed,
+/// which avoids computing complex exponentials at each call.
+/// 
+/// Lengths whose prime factors are small are computed with a mixed-radix
+/// [Cooley-Tukey](https://en.wikipedia.org/wiki/Cooley%E2%80%93Tukey_FFT_algorithm) algorithm,
+/// the others with [Bluestein's algorithm](https://en.wikipedia.org/wiki/Chirp_Z-transform#Bluestein.27s_algorithm),
+/// such that any length is computed in $O(n\log n)$.
+/// 
+/// ## Example
+/// ```
+/// # use num_complex::Complex64;
+/// # use scilib::signal::{ FftPlan, Direction, fft };
+/// let forward = FftPlan::new(12, Direction::Forward);
+/// let inverse = FftPlan::new(12, Direction::Inverse);
+/// 
+/// for shift in 0..4 {
+///     let s: Vec<Complex64> = (0..12).map(|k| Complex64::new((k + shift) as f64, 0.5)).collect();
+///     let mut buffer = s.clone();
+/// 
+///     // Same result as the one-shot function
+///     forward.process(&mut buffer);
+///     for (p, f) in buffer.iter().zip(&fft(&s)) {
+///         assert!((p - f).norm() < 1.0e-12);
+///     }
+/// 
+///     // And back to the original values
+///     inverse.process(&mut buffer);
+///     for (b, v) in buffer.iter().zip(&s) {
+///         assert!((b - v).norm() < 1.0e-12);
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct FftPlan {
+    length: usize,
+    direction: Direction,
+    algorithm: Algorithm
+}
+
+impl FftPlan {
+
+    /// # Creates a new plan
+    /// 
+    /// ## Inputs
+    /// - `length`: the length of the arrays to transform
+    /// - `direction`: the direction of the transform
+    /// 
+    /// Returns the plan, with all twiddle factors and chirp tables computed.
+    /// 
+    /// ## Example
+    /// ```
+    /// # use scilib::signal::{ FftPlan, Direction };
+    /// let plan = FftPlan::new(1031, Direction::Inverse);    // Prime length, uses Bluestein
+    /// assert_eq!(plan.length(), 1031);
+    /// assert_eq!(plan.direction(), Direction::Inverse);
+    /// ```
+    pub fn new(length: usize, direction: Direction) -> Self {
+
+        let inverse: bool = direction == Direction::Inverse;
+
+        let algorithm: Algorithm = if length <= 1 {
+            Algorithm::Trivial
+        } else {
+            match radix_factors(length) {
+                Some(factors) => Algorithm::Radix {
+                    factors,
+                    twiddles: twiddles(length, inverse)
+                },
+                None => Self::bluestein_tables(length, inverse)
+            }
+        };
+
+        Self {
+            length,
+            direction,
+            algorithm
+        }
+    }
+
+    /// # Length of the plan
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    /// # Direction of the plan
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    /// # In-place transform
+    /// 
+    /// ## Definition
+    /// Replaces the content of `data` by its transform. The inverse transform includes the
+    /// $1/N$ normalization, such that the inverse plan undoes the forward one.
+    /// 
+    /// ## Inputs
+    /// - `data`: the array to transform, of the length of the plan
+    /// 
+    /// ## Example
+    /// ```
+    /// # use num_complex::Complex64;
+    /// # use scilib::signal::{ FftPlan, Direction };
+    /// let plan = FftPlan::new(4, Direction::Forward);
+    /// let mut data: Vec<Complex64> = vec![1.0.into(), 0.0.into(), 0.0.into(), 0.0.into()];
+    /// plan.process(&mut data);
+    /// 
+    /// // The impulse has a flat spectrum
+    /// assert!(data.iter().all(|v| (v - 1.0).norm() < 1.0e-15));
+    /// ```
+    pub fn process(&self, data: &mut [Complex64]) {
+
+        let mut scratch: Vec<Complex64> = vec![Complex64::default(); self.scratch_length()];
+        self.process_with_scratch(data, &mut scratch);
+    }
+
+    /// # Length of the scratch buffer
+    /// 
+    /// Returns the length of the buffer needed by `process_with_scratch`, which is zero for the
+    /// lengths 0 and 1, the length of the plan for the Cooley-Tukey algorithm, and a few times
+    /// the length for Bluestein's algorithm.
+    pub fn scratch_length(&self) -> usize {
+
+        match &self.algorithm {
+            Algorithm::Trivial => 0,
+            Algorithm::Radix { .. } => self.length,
+            Algorithm::Bluestein(czt) => czt.scratch_length()
+        }
+    }
+
+    /// # In-place transform with a scratch buffer
+    /// 
+    /// ## Definition
+    /// Same as `process`, but the working memory is taken from `scratch` instead of being allocated
+    /// at each call. This is the method to use when transforming many arrays in a loop.
+    /// 
+    /// ## Inputs
+    /// - `data`: the array to transform, of the length of the plan
+    /// - `scratch`: the working memory, of at least `scratch_length` values; its content is overwritten
+    /// 
+    /// ## Example
+    /// ```
+    /// # use num_complex::Complex64;
+    /// # use scilib::signal::{ FftPlan, Direction, fft };
+    /// // Prime length, computed with Bluestein's algorithm
+    /// let plan = FftPlan::new(101, Direction::Forward);
+    /// let mut scratch: Vec<Complex64> = vec![Complex64::default(); plan.scratch_length()];
+    /// 
+    /// for shift in 0..4 {
+    ///     let s: Vec<Complex64> = (0..101).map(|k| Complex64::new(((k + shift) as f64).sin(), 0.0)).collect();
+    ///     let mut buffer = s.clone();
+    ///     plan.process_with_scratch(&mut buffer, &mut scratch);
+    /// 
+    ///     for (p, f) in buffer.iter().zip(&fft(&s)) {
+    ///         assert!((p - f).norm() < 1.0e-10);
+    ///     }
+    /// }
+    /// ```
+    pub fn process_with_scratch(&self, data: &mut [Complex64], scratch: &mut [Complex64]) {
+
+        assert_eq!(data.len(), self.length, "The array length must match the plan length!");
+        assert!(scratch.len() >= self.scratch_length(), "The scratch buffer is too short!");
+
+        self.process_raw(data, scratch);
+
+        if self.direction == Direction::Inverse {
+            let norm: f64 = self.length as f64;
+            data.iter_mut().for_each(|val| *val /= norm);
+        }
+    }
+
+    /// # Unnormalized transform
+    fn process_raw(&self, data: &mut [Complex64], scratch: &mut [Complex64]) {
+
+        match &self.algorithm {
+            Algorithm::Trivial => {},
+            Algorithm::Radix { factors, twiddles } => {
+                let inverse: bool = self.direction == Direction::Inverse;
+                let input: &mut [Complex64] = &mut scratch[..self.length];
+                input.copy_from_slice(data);
+                cooley_tukey(data, input, 1, factors, twiddles, 1, inverse);
+            },
+            Algorithm::Bluestein(czt) => {
+                let res: &[Complex64] = czt.convolve(data, scratch);
+                for (d, (r, p)) in data.iter_mut().zip(res.iter().zip(&czt.post)) {
+                    *d = r.conj() * p;
+                }
+            }
+        }
+    }
+
+    /// # Precomputation for Bluestein's algorithm
+    fn bluestein_tables(length: usize, inverse: bool) -> Algorithm {
+
+        let sign: f64 = if inverse { 1.0 } else { -1.0 };
+
+        // The chirp exp(-i pi k^2 / n), with k^2 reduced modulo 2n to keep the phase accurate
+        let chirp: Vec<Complex64> = (0..length).map(|k| {
+            let k2: u128 = (k as u128).pow(2) % (2 * length as u128);
+            Complex64::from_polar(1.0, sign * PI * k2 as f64 / length as f64)
+        }).collect();
+
+        let kernel: Vec<Complex64> = chirp.iter().map(|c| c.conj()).collect();
+
+        Algorithm::Bluestein(CztPlan::from_chirps(chirp.clone(), chirp, &kernel))
+    }
+}
+
+/// # Chirp-Z transform plan
+/// 
+/// ## Definition
+/// The [chirp-Z transform](https://en.wikipedia.org/wiki/Chirp_Z-transform) evaluates the Z-transform of
+/// $N$ points on $M$ points of a spiral of the complex plane:
+/// $$
+/// X_k = \sum_{n=0}^{N-1} x_nz_k^{-n}, \quad z_k = AW^{-k}
+/// $$
+/// It is computed with Bluestein's algorithm, which writes $nk = (n^2 + k^2 - (k-n)^2)/2$ to turn the sum into a
+/// convolution with the chirp $W^{-j^2/2}$, done by FFT in $O((N+M)\log(N+M))$. This is the algorithm used by
+/// `FftPlan` for lengths with large prime factors, with $W = e^{-2i\pi/N}$, $A = 1$ and $M = N$.
+/// 
+/// The plan stores the chirps and the transformed filter, such that it can be reused on many arrays.
+/// When $|W|\neq1$, the chirps grow or decay as $|W|^{k^2/2}$, which limits the usable lengths.
+/// 
+/// ## Example
+/// ```
+/// # use num_complex::Complex64;
+/// # use scilib::signal::{ CztPlan, fft };
+/// // With the DFT parameters, the transform is the FFT
+/// let s: Vec<Complex64> = (0..9).map(|k| Complex64::new(k as f64, (k * k) as f64 / 10.0)).collect();
+/// let w: Complex64 = Complex64::from_polar(1.0, -std::f64::consts::TAU / 9.0);
+/// let plan = CztPlan::new(9, 9, w, Complex64::new(1.0, 0.0));
+/// 
+/// for (c, f) in plan.process(&s).iter().zip(&fft(&s)) {
+///     assert!((c - f).norm() < 1.0e-11);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CztPlan {
+    input_length: usize,
+    output_length: usize,
+    /// Modulation of the input, $A^{-n}W^{n^2/2}$
+    pre: Vec<Complex64>,
+    /// Modulation of the output, $W^{k^2/2}$
+    post: Vec<Complex64>,
+    /// Transformed chirp filter, including the normalization of the inverse transform
+    filter: Vec<Complex64>,
+    /// Power of two plan for the convolution
+    inner: Box<FftPlan>
+}
+
+impl CztPlan {
+
+    /// # Creates a new plan
+    /// 
+    /// ## Inputs
+    /// - `n`: the length of the arrays to transform ($N$)
+    /// - `m`: the number of output points ($M$)
+    /// - `w`: the ratio between consecutive points of the spiral ($W$)
+    /// - `a`: the starting point of the spiral ($A$)
+    /// 
+    /// Returns the plan, with the chirps and the transformed filter computed.
+    /// 
+    /// ## Example
+    /// ```
+    /// # use num_complex::Complex64;
+    /// # use scilib::signal::CztPlan;
+    /// let plan = CztPlan::new(100, 20, Complex64::from_polar(1.0, -0.01), Complex64::new(1.0, 0.0));
+    /// assert_eq!((plan.input_length(), plan.output_length()), (100, 20));
+    /// ```
+    pub fn new(n: usize, m: usize, w: Complex64, a: Complex64) -> Self {
+
+        assert!(n > 0 && m > 0, "The input and output lengths must be positive!");
+
+        // Powers W^(j^2/2), from the logarithm of W. The phase is kept as an exact product, and reduced
+        // modulo 2 pi in two parts, to keep it accurate for long transforms
+        let (log_r, theta): (f64, f64) = (w.norm().ln(), w.arg());
+        let chirp = |j: usize| {
+            let half_sq: f64 = (j as f64).powi(2) / 2.0;
+            let phase: f64 = theta * half_sq;
+            let error: f64 = theta.mul_add(half_sq, -phase);
+            let turns: f64 = (phase / TAU).round();
+            let reduced: f64 = turns.mul_add(-TAU_LOW, turns.mul_add(-TAU, phase)) + error;
+            Complex64::from_polar((log_r * half_sq).exp(), reduced)
+        };
+
+        let (a_r, a_theta): (f64, f64) = (a.norm(), a.arg());
+        let pre: Vec<Complex64> = (0..n).map(|k| {
+            chirp(k) * Complex64::from_polar(a_r.powi(-(k as i32)), -a_theta * k as f64)
+        }).collect();
+        let post: Vec<Complex64> = (0..m).map(chirp).collect();
+        let kernel: Vec<Complex64> = (0..n.max(m)).map(|j| chirp(j).inv()).collect();
+
+        Self::from_chirps(pre, post, &kernel)
+    }
+
+    /// # Length of the input arrays
+    pub fn input_length(&self) -> usize {
+        self.input_length
+    }
+
+    /// # Number of output points
+    pub fn output_length(&self) -> usize {
+        self.output_length
+    }
+
+    /// # Transform
+    /// 
+    /// ## Inputs
+    /// - `data`: the array to transform, of the input length of the plan
+    /// 
+    /// Returns the $M$ values of the transform.
+    /// 
+    /// ## Example
+    /// ```
+    /// # use num_complex::Complex64;
+    /// # use scilib::signal::CztPlan;
+    /// // Z-transform of a geometric sequence, on the real axis
+    /// let s: Vec<Complex64> = (0..6).map(|k| Complex64::new(0.5_f64.powi(k), 0.0)).collect();
+    /// let plan = CztPlan::new(6, 3, Complex64::new(0.5, 0.0), Complex64::new(2.0, 0.0));
+    /// let res = plan.process(&s);
+    /// 
+    /// // z = 2, 4, 8
+    /// for (k, r) in res.iter().enumerate() {
+    ///     let z: f64 = 2.0_f64.powi(k as i32 + 1);
+    ///     let expected: f64 = (0..6).map(|n| (0.5 / z).powi(n)).sum();
+    ///     assert!((r.re - expected).abs() < 1.0e-12 && r.im.abs() < 1.0e-12);
+    /// }
+    /// ```
+    pub fn process(&self, data: &[Complex64]) -> Vec<Complex64> {
+
+        assert_eq!(data.len(), self.input_length, "The array length must match the plan length!");
+
+        let mut scratch: Vec<Complex64> = vec![Complex64::default(); self.scratch_length()];
+        let res: &[Complex64] = self.convolve(data, &mut scratch);
+
+        res.iter().zip(&self.post).map(|(r, p)| r.conj() * p).collect()
+    }
+
+    /// # Length of the working memory of `convolve`
+    fn scratch_length(&self) -> usize {
+        self.inner.length + self.inner.scratch_length()
+    }
+
+    /// # Convolution of the modulated input with the chirp filter
+    /// 
+    /// Returns the conjugate of the result, before the output modulation, as the start of `scratch`.
+    fn convolve<'a>(&self, data: &[Complex64], scratch: &'a mut [Complex64]) -> &'a [Complex64] {
+
+        let (buffer, inner_scratch) = scratch.split_at_mut(self.inner.length);
+
+        // Modulated input, zero padded
+        buffer.fill(Complex64::default());
+        for (b, (d, p)) in buffer.iter_mut().zip(data.iter().zip(&self.pre)) {
+            *b = d * p;
+        }
+
+        // Circular convolution with the chirp filter; the inverse transform is
+        // obtained from the forward one by conjugation, the normalization being in the filter
+        self.inner.process_raw(buffer, inner_scratch);
+        for (b, f) in buffer.iter_mut().zip(&self.filter) {
+            *b = (*b * f).conj();
+        }
+        self.inner.process_raw(buffer, inner_scratch);
+
+        buffer
+    }
+
+    /// # Plan from the modulations and the chirp kernel $W^{-j^2/2}$, given for $j < \max(N, M)$
+    fn from_chirps(pre: Vec<Complex64>, post: Vec<Complex64>, kernel: &[Complex64]) -> Self {
+
+        let (n, m): (usize, usize) = (pre.len(), post.len());
+        let padded: usize = (n + m - 1).next_power_of_two();
+
+        // Chirp filter for the lags -(N-1) to M-1, zero padded, transformed once and for all
+        let mut filter: Vec<Complex64> = vec![Complex64::default(); padded];
+        for (j, c) in kernel.iter().enumerate() {
+            if j < m {
+                filter[j] = c / padded as f64;
+            }
+            if j > 0 && j < n {
+                filter[padded - j] = c / padded as f64;
+            }
+        }
+
+        let inner: FftPlan = FftPlan::new(padded, Direction::Forward);
+        inner.process(&mut filter);
+
+        Self {
+            input_length: n,
+            output_length: m,
+            pre,
+            post,
+            filter,
+            inner: Box::new(inner)
+        }
+    }
+}
+
+/// # Fast Fourier transform
+/// 
+/// Computes the FFT for a one-dimensional array, based on the discrete Fourier transform.
+/// This function accepts complex input. Lengths whose prime factors are small are computed with a
+/// mixed-radix [Cooley-Tukey](https://en.wikipedia.org/wiki/Cooley%E2%80%93Tukey_FFT_algorithm) algorithm,
+/// the others with [Bluestein's algorithm](https://en.wikipedia.org/wiki/Chirp_Z-transform#Bluestein.27s_algorithm),
+/// such that any length is computed in $O(n\log n)$.
+/// 
+/// This is a one-shot wrapper around `FftPlan`, which should be preferred when transforming
+/// many arrays of the same length.
+/// 
+/// ```
+/// # use num_complex::Complex64;
+/// # use scilib::range;
+/// # use std::f64::consts::PI;
+/// # use scilib::signal::fft;
+/// // We create a Vec with the value sin(v) + cos(v)i
+/// let r = range::linear(0.0, 10.0, 15);
+/// let s: Vec<Complex64> = r.iter().map(|val| (Complex64::i() * val.cos()) + val.sin()).collect();
+/// let res = fft(&s);
+/// 
+/// // Checking for some values...
+/// assert!((res[0].re - 2.19227873).abs() < 1.0e-8 && (res[0].im - -0.64850436).abs() < 1.0e-8);
+/// assert!((res[4].re - 0.73245756).abs() < 1.0e-8 && (res[4].im - 0.44922173).abs() < 1.0e-8);
+/// assert!((res[9].re - -0.02709553).abs() < 1.0e-8 && (res[9].im - 1.02037473).abs() < 1.0e-8);
+/// assert!((res[14].re - 4.77371673).abs() < 1.0e-8 && (res[14].im - -2.58964065).abs() < 1.0e-8);
+/// 
+/// // Prime lengths are supported as well, here a ramp with known spectrum
+/// let p: Vec<f64> = (0..17).map(|k| k as f64).collect();
+/// let res_p = fft(&p);
+/// assert!((res_p[0].re - 136.0).abs() < 1.0e-10 && res_p[0].im.abs() < 1.0e-10);
+/// assert!((res_p[1].re - -8.5).abs() < 1.0e-10 && (res_p[1].im - 8.5 / (PI / 17.0).tan()).abs() < 1.0e-10);
+/// ```
+pub fn fft<T>(data: &[T]) -> Vec<Complex64>
+where T: Into<Complex64> + Copy {
+
+    let mut buffer: Vec<Complex64> = data.iter().map(|val| (*val).into()).collect();
+    FftPlan::new(buffer.len(), Direction::Forward).process(&mut buffer);
+
+    buffer
+}
+
+/// # Inverse fast Fourier transform
+/// 
+/// Computes the IFFT for a one-dimensional array, based on the discrete Fourier transform.
+/// This function accepts complex input. The algorithm is the same as for the `fft` function,
+/// with the opposite exponent sign and a normalization by the length of the array.
+/// 
+/// This is a one-shot wrapper around `FftPlan`, which should be preferred when transforming
+/// many arrays of the same length.
+/// 
+/// This function yields `v = ifft(fft(v))`, within numerical errors.
+/// 
+/// ```
+/// # use num_complex::Complex64;
+/// # use scilib::range;
+/// # use scilib::signal::{ fft, ifft };
+/// // We create a Vec with the value sin(v) + cos(v)i
+/// let r = range::linear(0.0, 10.0, 15);
+/// let s: Vec<f64> = r.iter().map(|val| val.sin()).collect();
+/// let res = ifft(&fft(&s));
+/// 
+/// for (ori, comp) in s.iter().zip(&res) {
+///     assert!((ori - comp.re).abs() < 1.0e-14 && comp.im < 1.0e-14);
+/// }
+/// ```
+pub fn ifft<T>(data: &[T]) -> Vec<Complex64>
+where T: Into<Complex64> + Copy {
+
+    let mut buffer: Vec<Complex64> = data.iter().map(|val| (*val).into()).collect();
+    FftPlan::new(buffer.len(), Direction::Inverse).process(&mut buffer);
+
+    buffer
+}
+
+/// # Real input fast Fourier transform
+/// 
+/// Computes the FFT of a real array. As the spectrum of a real signal is Hermitian
+/// ($X_{N-k} = X_k^*$), only the $N/2+1$ first bins are returned, from the zero frequency to the Nyquist frequency.
+/// 
+/// For even lengths, the real array is packed into a complex array of half the length, whose transform
+/// is then split into the even and odd contributions, such that the cost is about half that of the complex `fft`.
+/// 
+/// ```
+/// # use scilib::signal::{ fft, rfft };
+/// let s: Vec<f64> = (0..10).map(|k| (0.7 * k as f64).sin() + 0.2 * k as f64).collect();
+/// let half = rfft(&s);
+/// let full = fft(&s);
+/// 
+/// assert_eq!(half.len(), 6);
+/// for (h, f) in half.iter().zip(&full) {
+///     assert!((h - f).norm() < 1.0e-12);
+/// }
+/// ```
+pub fn rfft(data: &[f64]) -> Vec<Complex64> {
+
+    let length: usize = data.len();
+
+    // Odd lengths cannot be packed, we use the complex transform
+    if !length.is_multiple_of(2) {
+        let mut res: Vec<Complex64> = fft(data);
+        res.truncate(length / 2 + 1);
+        return res;
+    }
+
+    let half: usize = length / 2;
+    if half == 0 {
+        return vec![];
+    }
+
+    // Even samples as real part, odd samples as imaginary part
+    let mut packed: Vec<Complex64> = data.chunks_exact(2).map(|pair| Complex64::new(pair[0], pair[1])).collect();
+    FftPlan::new(half, Direction::Forward).process(&mut packed);
+
+    // Separating the transforms of the even and odd samples
+    let tw: Vec<Complex64> = twiddles(length, false);
+    (0..=half).map(|k| {
+        let z: Complex64 = packed[k % half];
+        let z_rev: Complex64 = packed[(half - k) % half].conj();
+        let even: Complex64 = (z + z_rev) * 0.5;
+        let odd: Complex64 = (z - z_rev) * Complex64::new(0.0, -0.5);
+        even + tw[k % length] * odd
+    }).collect()
+}
+
+/// # Inverse real fast Fourier transform
+/// 
+/// Computes the real signal of length `n` whose Hermitian half-spectrum is given, such that it
+/// undoes `rfft`. The imaginary parts of the zero frequency bin, and of the Nyquist bin for even `n`,
+/// are ignored as they must vanish for a real signal.
+/// 
+/// The length is required as both $2m-2$ and $2m-1$ signal lengths give $m$ bins.
+/// 
+/// ```
+/// # use scilib::signal::{ rfft, irfft };
+/// for n in [9, 16] {
+///     let s: Vec<f64> = (0..n).map(|k| (1.3 * k as f64).cos() - 0.1 * k as f64).collect();
+///     let res = irfft(&rfft(&s), n);
+/// 
+///     assert_eq!(res.len(), n);
+///     for (ori, comp) in s.iter().zip(&res) {
+///         assert!((ori - comp).abs() < 1.0e-14);
+///     }
+/// }
+/// ```
+pub fn irfft(data: &[Complex64], n: usize) -> Vec<f64> {
+
+    if n == 0 {
+        return vec![];
+    }
+    assert_eq!(data.len(), n / 2 + 1, "The spectrum must have n/2+1 bins!");
+
+    // Odd lengths: we rebuild the full Hermitian spectrum
+    if !n.is_multiple_of(2) {
+        let mut full: Vec<Complex64> = Vec::with_capacity(n);
+        full.push(data[0].re.into());
+        full.extend_from_slice(&data[1..]);
+        full.extend(data[1..].iter().rev().map(|val| val.conj()));
+        return ifft(&full).iter().map(|val| val.re).collect();
+    }
+
+    let half: usize = n / 2;
+
+    // Recombining the even and odd transforms into the packed array
+    let tw: Vec<Complex64> = twiddles(n, true);
+    let mut packed: Vec<Complex64> = (0..half).map(|k| {
+        let x: Complex64 = if k == 0 { data[0].re.into() } else { data[k] };
+        let x_rev: Complex64 = if k == 0 { data[half].re.into() } else { data[half - k].conj() };
+        let even: Complex64 = (x + x_rev) * 0.5;
+        let odd: Complex64 = (x - x_rev) * 0.5 * tw[k];
+        even + Complex64::i() * odd
+    }).collect();
+    FftPlan::new(half, Direction::Inverse).process(&mut packed);
+
+    packed.iter().flat_map(|val| [val.re, val.im]).collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Two-dimensional fast Fourier transform
+/// 
+/// Computes the FFT of an image stored in row-major order, by transforming first all rows and then all columns.
+/// 
+/// ## Inputs
+/// - `data`: the values, row after row
+/// - `rows`: the number of rows
+/// - `cols`: the number of columns
+/// 
+/// Returns the transform, in row-major order as well.
+/// 
+/// ```
+/// # use num_complex::Complex64;
+/// # use scilib::signal::fft2;
+/// // A single frequency along the columns only
+/// let img: Vec<f64> = (0..12).map(|k| (std::f64::consts::PI * (k % 4) as f64 / 2.0).cos()).collect();
+/// let res = fft2(&img, 3, 4);
+/// 
+/// for (k, val) in res.iter().enumerate() {
+///     let expected: f64 = if k == 1 || k == 3 { 6.0 } else { 0.0 };
+///     assert!((val - expected).norm() < 1.0e-12);
+/// }
+/// ```
+pub fn fft2<T>(data: &[T], rows: usize, cols: usize) -> Vec<Complex64>
+where T: Into<Complex64> + Copy {
+    fftn(data, &[rows, cols])
+}
+
+/// # Two-dimensional inverse fast Fourier transform
+/// 
+/// Computes the IFFT of an image stored in row-major order, such that `v = ifft2(fft2(v))`.
+/// 
+/// ## Inputs
+/// - `data`: the values, row after row
+/// - `rows`: the number of rows
+/// - `cols`: the number of columns
+/// 
+/// ```
+/// # use scilib::signal::{ fft2, ifft2 };
+/// let img: Vec<f64> = (0..35).map(|k| (k as f64 * 0.3).sin()).collect();
+/// let res = ifft2(&fft2(&img, 5, 7), 5, 7);
+/// 
+/// for (ori, comp) in img.iter().zip(&res) {
+///     assert!((ori - comp.re).abs() < 1.0e-14 && comp.im.abs() < 1.0e-14);
+/// }
+/// ```
+pub fn ifft2<T>(data: &[T], rows: usize, cols: usize) -> Vec<Complex64>
+where T: Into<Complex64> + Copy {
+    ifftn(data, &[rows, cols])
+}
+
+/// # N-dimensional fast Fourier transform
+/// 
+/// Computes the FFT of an array of any dimension stored in row-major order (the last index varying the fastest),
+/// by successively transforming along each axis. A single plan is created per axis.
+/// 
+/// ## Inputs
+/// - `data`: the values, in row-major order
+/// - `shape`: the length along each axis, whose product must be the length of `data`
+/// 
+/// Returns the transform, in row-major order as well.
+/// 
+/// ```
+/// # use num_complex::Complex64;
+/// # use scilib::signal::{ fft, fftn };
+/// // On a 2x3x4 cube, the zero frequency is the sum of all the values
+/// let cube: Vec<f64> = (0..24).map(|k| k as f64).collect();
+/// let res = fftn(&cube, &[2, 3, 4]);
+/// assert!((res[0] - 276.0).norm() < 1.0e-12);
+/// 
+/// // And a one-dimensional shape gives back the FFT
+/// let flat = fftn(&cube, &[24]);
+/// for (f, v) in flat.iter().zip(&fft(&cube)) {
+///     assert!((f - v).norm() < 1.0e-12);
+/// }
+/// 
+/// // An empty axis gives an empty transform
+/// assert!(fftn::<f64>(&[], &[0, 3]).is_empty());
+/// ```
+pub fn fftn<T>(data: &[T], shape: &[usize]) -> Vec<Complex64>
+where T: Into<Complex64> + Copy {
+
+    let mut buffer: Vec<Complex64> = data.iter().map(|val| (*val).into()).collect();
+    transform_axes(&mut buffer, shape, Direction::Forward);
+
+    buffer
+}
+
+/// # N-dimensional inverse fast Fourier transform
+/// 
+/// Computes the IFFT of an array of any dimension stored in row-major order, such that `v = ifftn(fftn(v))`.
+/// 
+/// ## Inputs
+/// - `data`: the values, in row-major order
+/// - `shape`: the length along each axis, whose product must be the length of `data`
+/// 
+/// ```
+/// # use scilib::signal::{ fftn, ifftn };
+/// let cube: Vec<f64> = (0..60).map(|k| (k as f64 * 0.7).cos()).collect();
+/// let res = ifftn(&fftn(&cube, &[3, 4, 5]), &[3, 4, 5]);
+/// 
+/// for (ori, comp) in cube.iter().zip(&res) {
+///     assert!((ori - comp.re).abs() < 1.0e-14 && comp.im.abs() < 1.0e-14);
+/// }
+/// ```
+pub fn ifftn<T>(data: &[T], shape: &[usize]) -> Vec<Complex64>
+where T: Into<Complex64> + Copy {
+
+    let mut buffer: Vec<Complex64> = data.iter().map(|val| (*val).into()).collect();
+    transform_axes(&mut buffer, shape, Direction::Inverse);
+
+    buffer
+}
+
+/// # Zero frequency centering
+/// 
+/// Shifts the zero frequency to the center of the array along every axis, which is convenient
+/// to display spectra. For an axis of length $n$, the element $k$ is moved to $(k + \lfloor n/2\rfloor) \bmod n$.
+/// 
+/// ## Inputs
+/// - `data`: the values, in row-major order
+/// - `shape`: the length along each axis, `&[data.len()]` for one-dimensional arrays
+/// 
+/// ```
+/// # use scilib::signal::fftshift;
+/// let freq: Vec<i32> = vec![0, 1, 2, -2, -1];
+/// assert_eq!(fftshift(&freq, &[5]), vec![-2, -1, 0, 1, 2]);
+/// 
+/// let img: Vec<i32> = vec![0, 1, 2, 3, 4, 5, 6, 7];
+/// assert_eq!(fftshift(&img, &[2, 4]), vec![6, 7, 4, 5, 2, 3, 0, 1]);
+/// ```
+pub fn fftshift<T: Copy>(data: &[T], shape: &[usize]) -> Vec<T> {
+    shift_axes(data, shape, false)
+}
+
+/// # Inverse zero frequency centering
+/// 
+/// Undoes `fftshift`, which differs from it for odd lengths.
+/// 
+/// ## Inputs
+/// - `data`: the values, in row-major order
+/// - `shape`: the length along each axis, `&[data.len()]` for one-dimensional arrays
+/// 
+/// ```
+/// # use scilib::signal::{ fftshift, ifftshift };
+/// let freq: Vec<i32> = vec![-2, -1, 0, 1, 2];
+/// assert_eq!(ifftshift(&freq, &[5]), vec![0, 1, 2, -2, -1]);
+/// 
+/// let cube: Vec<i32> = (0..30).collect();
+/// assert_eq!(ifftshift(&fftshift(&cube, &[2, 3, 5]), &[2, 3, 5]), cube);
+/// ```
+pub fn ifftshift<T: Copy>(data: &[T], shape: &[usize]) -> Vec<T> {
+    shift_axes(data, shape, true)
+}
+
+/// # Chirp-Z transform
+/// 
+/// Evaluates the Z-transform of the array on $M$ points of the spiral $z_k = AW^{-k}$:
+/// $$
+/// X_k = \sum_{n=0}^{N-1} x_nA^{-n}W^{nk}
+/// $$
+/// This is a one-shot wrapper around `CztPlan`, which should be preferred when transforming
+/// many arrays with the same parameters.
+/// 
+/// ## Inputs
+/// - `data`: the array to transform
+/// - `m`: the number of output points
+/// - `w`: the ratio between consecutive points of the spiral
+/// - `a`: the starting point of the spiral
+/// 
+/// Returns the $M$ values of the transform, or an empty vector if the array is empty or $M = 0$.
+/// 
+/// ```
+/// # use num_complex::Complex64;
+/// # use scilib::signal::czt;
+/// // Decaying oscillation, evaluated on a circle of radius 0.9
+/// let s: Vec<f64> = (0..50).map(|k| 0.95_f64.powi(k) * (0.4 * k as f64).cos()).collect();
+/// let w: Complex64 = Complex64::from_polar(1.0, -0.05);
+/// let res = czt(&s, 30, w, Complex64::new(0.9, 0.0));
+/// 
+/// for (k, r) in res.iter().enumerate() {
+///     let z: Complex64 = Complex64::from_polar(0.9, 0.05 * k as f64);
+///     let direct: Complex64 = s.iter().enumerate().map(|(n, v)| v * z.powi(-(n as i32))).sum();
+///     assert!((r - direct).norm() < 1.0e-9 * direct.norm().max(1.0));
+/// }
+/// 
+/// assert!(czt::<f64>(&[], 30, w, Complex64::new(0.9, 0.0)).is_empty());
+/// assert!(czt(&s, 0, w, Complex64::new(0.9, 0.0)).is_empty());
+/// ```
+pub fn czt<T>(data: &[T], m: usize, w: Complex64, a: Complex64) -> Vec<Complex64>
+where T: Into<Complex64> + Copy {
+
+    // The plan needs at least one input and one output point
+    if data.is_empty() || m == 0 {
+        return vec![];
+    }
+
+    let buffer: Vec<Complex64> = data.iter().map(|val| (*val).into()).collect();
+    CztPlan::new(buffer.len(), m, w, a).process(&buffer)
+}
+
+/// # Zoom FFT
+/// 
+/// Evaluates the discrete-time Fourier transform on $M$ frequencies evenly spaced from $f_1$ to $f_2$,
+/// the last one excluded, with a chirp-Z transform on an arc of the unit circle:
+/// $$
+/// X(f_k) = \sum_{n=0}^{N-1} x_ne^{-2i\pi nf_k/f_s}, \quad f_k = f_1 + k\frac{f_2 - f_1}{M}
+/// $$
+/// This gives a fine frequency resolution in a narrow band for a cost of $O((N+M)\log(N+M))$,
+/// instead of zero padding the whole transform. With $f_1 = 0$, $f_2 = f_s$ and $M = N$, it is the FFT.
+/// 
+/// ## Inputs
+/// - `data`: the signal
+/// - `m`: the number of frequencies
+/// - `f1`: the first frequency
+/// - `f2`: the end of the band, excluded
+/// - `fs`: the sampling rate
+/// 
+/// Returns the frequencies and the values of the transform, both empty if the signal is empty or $M = 0$.
+/// 
+/// ```
+/// # use scilib::signal::zoom_fft;
+/// // Two close tones at 100.0 and 100.8 Hz, sampled at 1 kHz for 2.5 s
+/// let x: Vec<f64> = (0..2500).map(|k| {
+///     let t: f64 = k as f64 / 1000.0;
+///     (std::f64::consts::TAU * 100.0 * t).sin() + (std::f64::consts::TAU * 100.8 * t).sin()
+/// }).collect();
+/// 
+/// let (freqs, res) = zoom_fft(&x, 200, 99.0, 102.0, 1000.0);
+/// assert_eq!(freqs[0], 99.0);
+/// assert!((freqs[199] - 101.985).abs() < 1.0e-12);
+/// 
+/// // Both lines are resolved, with a dip in between
+/// let mag: Vec<f64> = res.iter().map(|v| v.norm()).collect();
+/// let bin = |f: f64| ((f - 99.0) / 0.015).round() as usize;
+/// assert!(mag[bin(100.0)] > 1000.0 && mag[bin(100.8)] > 1000.0);
+/// assert!(mag[bin(100.4)] < 0.5 * mag[bin(100.0)]);
+/// 
+/// let (freqs, res) = zoom_fft::<f64>(&[], 200, 99.0, 102.0, 1000.0);
+/// assert!(freqs.is_empty() && res.is_empty());
+/// ```
+pub fn zoom_fft<T>(data: &[T], m: usize, f1: f64, f2: f64, fs: f64) -> (Vec<f64>, Vec<Complex64>)
+where T: Into<Complex64> + Copy {
+
+    if data.is_empty() || m == 0 {
+        return (vec![], vec![]);
+    }
+
+    let step: f64 = (f2 - f1) / m as f64;
+    let w: Complex64 = Complex64::from_polar(1.0, -TAU * step / fs);
+    let a: Complex64 = Complex64::from_polar(1.0, TAU * f1 / fs);
+
+    let freqs: Vec<f64> = (0..m).map(|k| f1 + k as f64 * step).collect();
+    (freqs, czt(data, m, w, a))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # FFT convolution
+/// 
+/// Computes the convolution of two real vectors through the convolution theorem, using
+/// real transforms zero padded to a length with small prime factors.
+/// The cost is $O((n+m)\log(n+m))$ instead of $O(nm)$ for `convolve`, which is much faster for long kernels,
+/// at the price of rounding errors of the order of the machine precision times the magnitude of the result.
+/// 
+/// ## Inputs
+/// - `a`: the first vector
+/// - `b`: the second vector
+/// - `mode`: the size of the output
+/// 
+/// ```
+/// # use scilib::signal::{ convolve, convolve_full, fft_convolve, Mode };
+/// let a1: Vec<f64> = vec![2.8, 2.5, 1.0, 0.5, 3.2, 0.25];
+/// let a2: Vec<f64> = vec![0.25, 1.0, 0.5];
+/// 
+/// let full = fft_convolve(&a1, &a2, Mode::Full);
+/// let same = fft_convolve(&a1, &a2, Mode::Same);
+/// let valid = fft_convolve(&a1, &a2, Mode::Valid);
+/// 
+/// // Consistent with the direct functions
+/// for (f, d) in full.iter().zip(&convolve(&a1, &a2)) {
+///     assert!((f - d).abs() < 1.0e-12);
+/// }
+/// for (f, d) in valid.iter().zip(&convolve_full(&a1, &a2)) {
+///     assert!((f - d).abs() < 1.0e-12);
+/// }
+/// assert_eq!((full.len(), same.len(), valid.len()), (8, 6, 4));
+/// assert!((same[0] - 3.425).abs() < 1.0e-12);
+/// ```
+pub fn fft_convolve(a: &[f64], b: &[f64], mode: Mode) -> Vec<f64> {
+
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+
+    let length: usize = a.len() + b.len() - 1;
+    let padded: usize = fast_length(length);
+
+    let mut a_pad: Vec<f64> = a.to_vec();
+    let mut b_pad: Vec<f64> = b.to_vec();
+    a_pad.resize(padded, 0.0);
+    b_pad.resize(padded, 0.0);
+
+    // Product of the spectra
+    let prod: Vec<Complex64> = rfft(&a_pad).iter().zip(&rfft(&b_pad)).map(|(x, y)| x * y).collect();
+    let mut res: Vec<f64> = irfft(&prod, padded);
+    res.truncate(length);
+
+    crop(res, a.len(), b.len(), mode)
+}
+
+/// # Complex FFT convolution
+/// 
+/// Same as `fft_convolve`, for complex vectors.
+/// 
+/// ## Inputs
+/// - `a`: the first vector
+/// - `b`: the second vector
+/// - `mode`: the size of the output
+/// 
+/// ```
+/// # use num_complex::Complex64;
+/// # use scilib::signal::{ convolve, fft_convolve_complex, Mode };
+/// let a1: Vec<Complex64> = (0..7).map(|k| Complex64::new(k as f64, 1.0 - k as f64)).collect();
+/// let a2: Vec<Complex64> = vec![Complex64::new(0.5, 2.0), Complex64::new(-1.0, 0.3)];
+/// 
+/// let res = fft_convolve_complex(&a1, &a2, Mode::Full);
+/// for (f, d) in res.iter().zip(&convolve(&a1, &a2)) {
+///     assert!((f - d).norm() < 1.0e-12);
+/// }
+/// ```
+pub fn fft_convolve_complex(a: &[Complex64], b: &[Complex64], mode: Mode) -> Vec<Complex64> {
+
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+
+    let length: usize = a.len() + b.len() - 1;
+    let padded: usize = fast_length(length);
+
+    let mut a_pad: Vec<Complex64> = a.to_vec();
+    let mut b_pad: Vec<Complex64> = b.to_vec();
+    a_pad.resize(padded, Complex64::default());
+    b_pad.resize(padded, Complex64::default());
+
+    // Product of the spectra
+    let forward: FftPlan = FftPlan::new(padded, Direction::Forward);
+    forward.process(&mut a_pad);
+    forward.process(&mut b_pad);
+    a_pad.iter_mut().zip(&b_pad).for_each(|(x, y)| *x *= y);
+    FftPlan::new(padded, Direction::Inverse).process(&mut a_pad);
+    a_pad.truncate(length);
+
+    crop(a_pad, a.len(), b.len(), mode)
+}
+
+/// # Convolution with automatic method
+/// 
+/// Computes the convolution of two real vectors, choosing between the direct sum of `convolve` and
+/// the FFT of `fft_convolve` based on an estimate of the number of operations of each method.
+/// 
+/// ## Inputs
+/// - `a`: the first vector
+/// - `b`: the second vector
+/// - `mode`: the size of the output
+/// 
+/// ```
+/// # use scilib::signal::{ convolve_auto, fft_convolve, convolve, Mode };
+/// // A short kernel uses the direct sum
+/// let a1: Vec<f64> = vec![2.8, 2.5, 1.0, 0.5, 3.2, 0.25];
+/// let a2: Vec<f64> = vec![0.25, 1.0, 0.5];
+/// assert_eq!(convolve_auto(&a1, &a2, Mode::Full), convolve(&a1, &a2));
+/// 
+/// // A long one the FFT
+/// let s: Vec<f64> = (0..5000).map(|k| (k as f64 * 0.01).sin()).collect();
+/// let kernel: Vec<f64> = vec![1.0 / 2000.0; 2000];
+/// assert_eq!(convolve_auto(&s, &kernel, Mode::Valid), fft_convolve(&s, &kernel, Mode::Valid));
+/// ```
+pub fn convolve_auto(a: &[f64], b: &[f64], mode: Mode) -> Vec<f64> {
+
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+
+    if prefer_fft(a.len(), b.len()) {
+        fft_convolve(a, b, mode)
+    } else {
+        crop(convolve(a, b), a.len(), b.len(), mode)
+    }
+}
+
+/// # Cross-correlation
+/// 
+/// Computes the cross-correlation of two real vectors:
+/// $$
+/// c_k = \sum_n a_{n+k}b_n
+/// $$
+/// which is the convolution of `a` with the reversed `b`. The output is ordered from the largest
+/// negative lag to the largest positive one, and the method is chosen as in `convolve_auto`.
+/// 
+/// ## Inputs
+/// - `a`: the first vector
+/// - `b`: the second vector
+/// - `mode`: the size of the output
+/// 
+/// ```
+/// # use scilib::signal::{ correlate, Mode };
+/// let a: Vec<f64> = vec![1.0, 2.0, 3.0];
+/// let b: Vec<f64> = vec![0.0, 1.0, 0.5];
+/// 
+/// let full = correlate(&a, &b, Mode::Full);
+/// let expected: Vec<f64> = vec![0.5, 2.0, 3.5, 3.0, 0.0];
+/// for (c, e) in full.iter().zip(&expected) {
+///     assert!((c - e).abs() < 1.0e-12);
+/// }
+/// 
+/// assert!((correlate(&a, &b, Mode::Valid)[0] - 3.5).abs() < 1.0e-12);
+/// ```
+pub fn correlate(a: &[f64], b: &[f64], mode: Mode) -> Vec<f64> {
+
+    let b_rev: Vec<f64> = b.iter().rev().copied().collect();
+    convolve_auto(a, &b_rev, mode)
+}
+
+/// # Autocorrelation
+/// 
+/// Computes the cross-correlation of a real vector with itself, see `correlate`.
+/// The zero lag is at the center of the `Full` and `Same` outputs.
+/// 
+/// ## Inputs
+/// - `a`: the vector
+/// - `mode`: the size of the output
+/// 
+/// ```
+/// # use scilib::signal::{ autocorrelate, Mode };
+/// let a: Vec<f64> = vec![1.0, -2.0, 3.0, 0.5];
+/// let res = autocorrelate(&a, Mode::Full);
+/// 
+/// // Symmetric, with the energy at zero lag
+/// assert_eq!(res.len(), 7);
+/// assert!((res[3] - 14.25).abs() < 1.0e-12);
+/// for k in 0..3 {
+///     assert!((res[k] - res[6 - k]).abs() < 1.0e-12);
+/// }
+/// ```
+pub fn autocorrelate(a: &[f64], mode: Mode) -> Vec<f64> {
+    correlate(a, a, mode)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Block convolution method
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BlockMethod {
+    /// Each block is convolved with the kernel, the overlapping tails being added to the next blocks
+    OverlapAdd,
+    /// Each block is extended by the end of the previous input, the wrapped around outputs being discarded
+    OverlapSave
+}
+
+/// # Streaming block convolver
+/// 
+/// ## Definition
+/// Convolves a stream of arbitrary length with a fixed kernel, by blocks computed with FFTs of fixed length,
+/// using the [overlap-add](https://en.wikipedia.org/wiki/Overlap%E2%80%93add_method) or
+/// [overlap-save](https://en.wikipedia.org/wiki/Overlap%E2%80%93save_method) methods.
+/// The input is fed by chunks of any size, and the outputs are returned as soon as they are final.
+/// Once the stream is over, `flush` returns the remaining values, such that the concatenation of all
+/// the outputs is the same as `convolve` on the concatenation of all the inputs.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::{ BlockConvolver, BlockMethod, convolve };
+/// let kernel: Vec<f64> = vec![0.2, -0.5, 1.0, 0.3, 0.1];
+/// let signal: Vec<f64> = (0..100).map(|k| (k as f64 * 0.2).sin()).collect();
+/// let expected = convolve(&signal, &kernel);
+/// 
+/// for method in [BlockMethod::OverlapAdd, BlockMethod::OverlapSave] {
+///     let mut conv = BlockConvolver::with_fft_length(&kernel, method, 16);
+///     let mut res: Vec<f64> = Vec::new();
+/// 
+///     // Chunks of various sizes
+///     for chunk in signal.chunks(7) {
+///         res.extend(conv.process(chunk));
+///     }
+///     res.extend(conv.flush());
+/// 
+///     assert_eq!(res.len(), expected.len());
+///     for (r, e) in res.iter().zip(&expected) {
+///         assert!((r - e).abs() < 1.0e-12);
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct BlockConvolver {
+    method: BlockMethod,
+    kernel_len: usize,
+    step: usize,
+    kernel_fft: Vec<Complex64>,
+    forward: FftPlan,
+    inverse: FftPlan,
+    scratch: Vec<Complex64>,
+    pending: Vec<f64>,
+    overlap: Vec<f64>
+}
+
+impl BlockConvolver {
+
+    /// # Creates a new block convolver
+    /// 
+    /// The FFT length is chosen as the power of two above eight times the kernel length, which
+    /// keeps the cost per output sample close to its minimum.
+    /// 
+    /// ## Inputs
+    /// - `kernel`: the kernel to convolve the stream with
+    /// - `method`: overlap-add or overlap-save
+    /// 
+    /// ## Example
+    /// ```
+    /// # use scilib::signal::{ BlockConvolver, BlockMethod };
+    /// let mut conv = BlockConvolver::new(&[0.5, 0.5], BlockMethod::OverlapAdd);
+    /// let mut res = conv.process(&[1.0, 2.0, 3.0]);
+    /// res.extend(conv.flush());
+    /// 
+    /// let expected: Vec<f64> = vec![0.5, 1.5, 2.5, 1.5];
+    /// for (r, e) in res.iter().zip(&expected) {
+    ///     assert!((r - e).abs() < 1.0e-14);
+    /// }
+    /// ```
+    pub fn new(kernel: &[f64], method: BlockMethod) -> Self {
+        Self::with_fft_length(kernel, method, (8 * kernel.len()).next_power_of_two().max(64))
+    }
+
+    /// # Creates a new block convolver with a given FFT length
+    /// 
+    /// Each block produces `fft_length - kernel.len() + 1` output values; smaller lengths reduce the latency,
+    /// larger ones the cost per sample.
+    /// 
+    /// ## Inputs
+    /// - `kernel`: the kernel to convolve the stream with
+    /// - `method`: overlap-add or overlap-save
+    /// - `fft_length`: the length of the transforms, at least the length of the kernel
+    pub fn with_fft_length(kernel: &[f64], method: BlockMethod, fft_length: usize) -> Self {
+
+        assert!(!kernel.is_empty(), "The kernel cannot be empty!");
+        assert!(fft_length >= kernel.len(), "The FFT length must be at least the kernel length!");
+
+        let forward: FftPlan = FftPlan::new(fft_length, Direction::Forward);
+        let inverse: FftPlan = FftPlan::new(fft_length, Direction::Inverse);
+
+        // The kernel spectrum is computed once
+        let mut kernel_fft: Vec<Complex64> = vec![Complex64::default(); fft_length];
+        for (k, val) in kernel_fft.iter_mut().zip(kernel) {
+            *k = val.into();
+        }
+        forward.process(&mut kernel_fft);
+
+        // Working memory of the transforms, shared by all the blocks
+        let scratch: Vec<Complex64> = vec![Complex64::default(); forward.scratch_length().max(inverse.scratch_length())];
+
+        Self {
+            method,
+            kernel_len: kernel.len(),
+            step: fft_length - kernel.len() + 1,
+            kernel_fft,
+            forward,
+            inverse,
+            scratch,
+            pending: Vec::new(),
+            overlap: vec![0.0; kernel.len() - 1]
+        }
+    }
+
+    /// # Feeding a chunk of the stream
+    /// 
+    /// ## Inputs
+    /// - `chunk`: the next values of the stream, of any length
+    /// 
+    /// Returns the output values that are complete, possibly none if the chunk did not fill a block.
+    pub fn process(&mut self, chunk: &[f64]) -> Vec<f64> {
+
+        self.pending.extend_from_slice(chunk);
+
+        let n_blocks: usize = self.pending.len() / self.step;
+        let mut res: Vec<f64> = Vec::with_capacity(n_blocks * self.step);
+
+        let pending: Vec<f64> = self.pending.drain(..n_blocks * self.step).collect();
+        for block in pending.chunks_exact(self.step) {
+            res.extend(self.block(block, self.step));
+        }
+
+        res
+    }
+
+    /// # Ending the stream
+    /// 
+    /// Returns the remaining output values, including the tail of the kernel. The convolver is then
+    /// reset and can be used for a new stream.
+    pub fn flush(&mut self) -> Vec<f64> {
+
+        let pending: Vec<f64> = std::mem::take(&mut self.pending);
+        let remaining: usize = pending.len() + self.kernel_len - 1;
+
+        let res: Vec<f64> = match self.method {
+            BlockMethod::OverlapAdd => {
+                // The last partial block, whose convolution extends over the tail
+                let mut res: Vec<f64> = self.block(&pending, pending.len());
+                res.extend_from_slice(&self.overlap);
+                res.truncate(remaining);
+                res
+            },
+            BlockMethod::OverlapSave => {
+                // Feeding zeros until the whole tail is out
+                let mut padded: Vec<f64> = pending;
+                padded.resize(remaining.next_multiple_of(self.step), 0.0);
+
+                let mut res: Vec<f64> = Vec::with_capacity(padded.len());
+                for block in padded.chunks_exact(self.step) {
+                    res.extend(self.block(block, self.step));
+                }
+                res.truncate(remaining);
+                res
+            }
+        };
+
+        self.reset();
+        res
+    }
+
+    /// # Resetting the state
+    /// 
+    /// Discards the buffered input and the overlap, as if no value was fed.
+    pub fn reset(&mut self) {
+        self.pending.clear();
+        self.overlap.iter_mut().for_each(|val| *val = 0.0);
+    }
+
+    /// # Convolution of a single block
+    /// 
+    /// Returns `n_out` values, and updates the overlap for the next block.
+    fn block(&mut self, block: &[f64], n_out: usize) -> Vec<f64> {
+
+        let fft_length: usize = self.kernel_fft.len();
+        let n_overlap: usize = self.kernel_len - 1;
+        let mut buffer: Vec<Complex64> = vec![Complex64::default(); fft_length];
+
+        // The input of the block, after the previous input for overlap-save
+        let offset: usize = match self.method {
+            BlockMethod::OverlapAdd => 0,
+            BlockMethod::OverlapSave => n_overlap
+        };
+        if self.method == BlockMethod::OverlapSave {
+            for (b, val) in buffer.iter_mut().zip(&self.overlap) {
+                *b = val.into();
+            }
+        }
+        for (b, val) in buffer[offset..].iter_mut().zip(block) {
+            *b = val.into();
+        }
+
+        self.forward.process_with_scratch(&mut buffer, &mut self.scratch);
+        buffer.iter_mut().zip(&self.kernel_fft).for_each(|(b, k)| *b *= k);
+        self.inverse.process_with_scratch(&mut buffer, &mut self.scratch);
+
+        match self.method {
+            BlockMethod::OverlapAdd => {
+                let mut res: Vec<f64> = buffer[..n_out].iter().map(|val| val.re).collect();
+                for (r, o) in res.iter_mut().zip(&self.overlap) {
+                    *r += o;
+                }
+
+                // New overlap: what is left of the previous one and the tail of this block
+                let mut overlap: Vec<f64> = self.overlap.get(n_out..).unwrap_or_default().to_vec();
+                overlap.resize(n_overlap, 0.0);
+                for (o, val) in overlap.iter_mut().zip(&buffer[n_out..]) {
+                    *o += val.re;
+                }
+                self.overlap = overlap;
+
+                res
+            },
+            BlockMethod::OverlapSave => {
+                // The last inputs are kept for the next block
+                let mut history: Vec<f64> = self.overlap.clone();
+                history.extend_from_slice(block);
+                self.overlap = history[history.len() - n_overlap..].to_vec();
+
+                buffer[n_overlap..n_overlap + n_out].iter().map(|val| val.re).collect()
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Analytic signal
+/// 
+/// ## Definition
+/// Computes the [analytic signal](https://en.wikipedia.org/wiki/Analytic_signal) of a real signal,
+/// $x_a = x + i\mathcal{H}(x)$, where $\mathcal{H}$ is the
+/// [Hilbert transform](https://en.wikipedia.org/wiki/Hilbert_transform). It is obtained by removing
+/// the negative frequencies of the spectrum and doubling the positive ones:
+/// $$
+/// X_a(k) = X(k)\left(1 + \mathrm{sgn}(k)\right)
+/// $$
+/// the zero frequency and the Nyquist frequency of even lengths being kept unchanged.
+/// The signal is assumed periodic, which causes edge effects otherwise.
+/// 
+/// ## Inputs
+/// - `x`: the real signal
+/// 
+/// Returns the analytic signal, whose real part is `x` and imaginary part its Hilbert transform.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::hilbert;
+/// // The Hilbert transform of a cosine is a sine
+/// let n: usize = 64;
+/// let x: Vec<f64> = (0..n).map(|k| (std::f64::consts::TAU * 5.0 * k as f64 / n as f64).cos()).collect();
+/// let xa = hilbert(&x);
+/// for (k, v) in xa.iter().enumerate() {
+///     assert!((v.re - x[k]).abs() < 1.0e-12);
+///     assert!((v.im - (std::f64::consts::TAU * 5.0 * k as f64 / n as f64).sin()).abs() < 1.0e-12);
+/// }
+/// ```
+pub fn hilbert(x: &[f64]) -> Vec<Complex64> {
+
+    let n: usize = x.len();
+    let mut spectrum: Vec<Complex64> = fft(x);
+
+    // Doubling the positive frequencies and removing the negative ones
+    for (k, val) in spectrum.iter_mut().enumerate().skip(1) {
+        if 2 * k < n {
+            *val *= 2.0;
+        } else if 2 * k > n {
+            *val = Complex64::default();
+        }
+    }
+
+    ifft(&spectrum)
+}
+
+/// # Envelope of a signal
+/// 
+/// ## Definition
+/// The envelope, or instantaneous amplitude, is the modulus of the analytic signal:
+/// $$
+/// A(t) = |x_a(t)| = \sqrt{x^2(t) + \mathcal{H}(x)^2(t)}
+/// $$
+/// 
+/// ## Inputs
+/// - `x`: the real signal
+/// 
+/// Returns the envelope.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::envelope;
+/// // Amplitude modulated carrier
+/// let n: usize = 1000;
+/// let amplitude = |t: f64| 1.0 + 0.5 * (std::f64::consts::TAU * 2.0 * t).cos();
+/// let x: Vec<f64> = (0..n).map(|k| {
+///     let t: f64 = k as f64 / n as f64;
+///     amplitude(t) * (std::f64::consts::TAU * 100.0 * t).cos()
+/// }).collect();
+/// 
+/// let env = envelope(&x);
+/// for (k, a) in env.iter().enumerate() {
+///     assert!((a - amplitude(k as f64 / n as f64)).abs() < 1.0e-10);
+/// }
+/// ```
+pub fn envelope(x: &[f64]) -> Vec<f64> {
+    hilbert(x).iter().map(|val| val.norm()).collect()
+}
+
+/// # Instantaneous phase of a signal
+/// 
+/// ## Definition
+/// The instantaneous phase is the argument of the analytic signal, unwrapped with `unwrap`:
+/// $$
+/// \phi(t) = \arg x_a(t)
+/// $$
+/// 
+/// ## Inputs
+/// - `x`: the real signal
+/// 
+/// Returns the unwrapped phase, in radians.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::instantaneous_phase;
+/// let n: usize = 200;
+/// let x: Vec<f64> = (0..n).map(|k| (std::f64::consts::TAU * 10.0 * k as f64 / n as f64 + 0.3).cos()).collect();
+/// 
+/// let phase = instantaneous_phase(&x);
+/// for (k, p) in phase.iter().enumerate() {
+///     assert!((p - (std::f64::consts::TAU * 10.0 * k as f64 / n as f64 + 0.3)).abs() < 1.0e-10);
+/// }
+/// ```
+pub fn instantaneous_phase(x: &[f64]) -> Vec<f64> {
+
+    let phase: Vec<f64> = hilbert(x).iter().map(|val| val.arg()).collect();
+    unwrap(&phase)
+}
+
+/// # Instantaneous frequency of a signal
+/// 
+/// ## Definition
+/// The instantaneous frequency is the derivative of the instantaneous phase, estimated by finite differences:
+/// $$
+/// f_k = \frac{f_s}{2\pi}\left(\phi_{k+1} - \phi_k\right)
+/// $$
+/// 
+/// ## Inputs
+/// - `x`: the real signal
+/// - `fs`: the sampling rate ($f_s$)
+/// 
+/// Returns the instantaneous frequency between consecutive samples, with one point less than the signal.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::instantaneous_frequency;
+/// // Linear chirp from 50 Hz to 150 Hz, sampled at 2 kHz
+/// let fs: f64 = 2000.0;
+/// let x: Vec<f64> = (0..2000).map(|k| {
+///     let t: f64 = k as f64 / fs;
+///     (std::f64::consts::TAU * (50.0 * t + 50.0 * t * t)).cos()
+/// }).collect();
+/// 
+/// let freq = instantaneous_frequency(&x, fs);
+/// assert_eq!(freq.len(), 1999);
+/// for (k, f) in freq.iter().enumerate().skip(100).take(1800) {
+///     let t: f64 = (k as f64 + 0.5) / fs;
+///     assert!((f - (50.0 + 100.0 * t)).abs() < 0.5);
+/// }
+/// ```
+pub fn instantaneous_frequency(x: &[f64], fs: f64) -> Vec<f64> {
+
+    let phase: Vec<f64> = instantaneous_phase(x);
+    phase.windows(2).map(|p| (p[1] - p[0]) * fs / TAU).collect()
+}
+
+/// # Phase unwrapping
+/// 
+/// ## Definition
+/// Removes the jumps of the phase larger than $\pi$ between consecutive samples, by adding multiples of $2\pi$,
+/// such that the phase is continuous.
+/// 
+/// ## Inputs
+/// - `phase`: the wrapped phase, in radians
+/// 
+/// Returns the unwrapped phase.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::unwrap;
+/// let wrapped: Vec<f64> = vec![0.0, 3.0, -3.0, -0.5, 2.5, -2.5];
+/// let res = unwrap(&wrapped);
+/// let tau: f64 = std::f64::consts::TAU;
+/// let expected: Vec<f64> = vec![0.0, 3.0, tau - 3.0, tau - 0.5, tau + 2.5, 2.0 * tau - 2.5];
+/// for (r, e) in res.iter().zip(&expected) {
+///     assert!((r - e).abs() < 1.0e-12);
+/// }
+/// ```
+pub fn unwrap(phase: &[f64]) -> Vec<f64> {
+
+    let mut offset: f64 = 0.0;
+    let mut res: Vec<f64> = Vec::with_capacity(phase.len());
+
+    for (k, p) in phase.iter().enumerate() {
+        if k > 0 {
+            let jump: f64 = p - phase[k - 1];
+            if jump.abs() > PI {
+                offset -= TAU * (jump / TAU).round();
+            }
+        }
+        res.push(p + offset);
+    }
+
+    res
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Internal FFT machinery
+
+/// # Factorization of the length
+/// 
+/// Splits the length in radices, favoring radix 4. Returns `None` if a prime factor is above `MAX_RADIX`.
+fn radix_factors(length: usize) -> Option<Vec<usize>> {
+
+    let mut rest: usize = length;
+    let mut factors: Vec<usize> = Vec::new();
+
+    // Radix 4 first, as it has the cheapest butterfly per point
+    while rest.is_multiple_of(4) {
+        factors.push(4);
+        rest /= 4;
+    }
+
+    let mut p: usize = 2;
+    while rest > 1 {
+        if p > MAX_RADIX {
+            return None;
+        }
+        while rest.is_multiple_of(p) {
+            factors.push(p);
+            rest /= p;
+        }
+        p += 1;
+    }
+
+    Some(factors)
+}
+
+/// # Twiddle factors
+/// 
+/// The roots of unity $\exp(\mp 2i\pi k/n)$ for $k\in[0, n)$, each computed directly to avoid error accumulation.
+fn twiddles(length: usize, inverse: bool) -> Vec<Complex64> {
+
+    let sign: f64 = if inverse { 1.0 } else { -1.0 };
+    (0..length).map(|k| Complex64::from_polar(1.0, sign * TAU * k as f64 / length as f64)).collect()
+}
+
+/// # Recursive mixed-radix Cooley-Tukey
+/// 
+/// Decimation in time: `res` receives the transform of the elements of `data` taken every `stride`,
+/// the twiddles of the sub-transforms being the full table taken every `tw_stride`.
+fn cooley_tukey(res: &mut [Complex64], data: &[Complex64], stride: usize, factors: &[usize], tw: &[Complex64], tw_stride: usize, inverse: bool) {
+
+    let p: usize = factors[0];
+    let m: usize = res.len() / p;
+
+    // Splitting into p sub-transforms of length m
+    if m == 1 {
+        for (q, val) in res.iter_mut().enumerate() {
+            *val = data[q * stride];
+        }
+    } else {
+        for (q, chunk) in res.chunks_exact_mut(m).enumerate() {
+            cooley_tukey(chunk, &data[q * stride..], stride * p, &factors[1..], tw, tw_stride * p, inverse);
+        }
+    }
+
+    // Recombining them
+    match p {
+        2 => butterfly_2(res, m, tw, tw_stride),
+        4 => butterfly_4(res, m, tw, tw_stride, inverse),
+        _ => butterfly_generic(res, p, m, tw, tw_stride)
+    }
+}
+
+/// # Radix 2 butterfly
+fn butterfly_2(res: &mut [Complex64], m: usize, tw: &[Complex64], tw_stride: usize) {
+
+    let (low, high) = res.split_at_mut(m);
+    for (k, (a, b)) in low.iter_mut().zip(high.iter_mut()).enumerate() {
+        let t: Complex64 = *b * tw[k * tw_stride];
+        *b = *a - t;
+        *a += t;
+    }
+}
+
+/// # Radix 4 butterfly
+fn butterfly_4(res: &mut [Complex64], m: usize, tw: &[Complex64], tw_stride: usize, inverse: bool) {
+
+    // Fourth root of unity, exactly
+    let j: Complex64 = if inverse { Complex64::i() } else { -Complex64::i() };
+
+    for k in 0..m {
+        let a0: Complex64 = res[k];
+        let a1: Complex64 = res[k + m] * tw[k * tw_stride];
+        let a2: Complex64 = res[k + 2 * m] * tw[2 * k * tw_stride];
+        let a3: Complex64 = res[k + 3 * m] * tw[3 * k * tw_stride];
+
+        let t0: Complex64 = a0 + a2;
+        let t1: Complex64 = a0 - a2;
+        let t2: Complex64 = a1 + a3;
+        let t3: Complex64 = (a1 - a3) * j;
+
+        res[k] = t0 + t2;
+        res[k + m] = t1 + t3;
+        res[k + 2 * m] = t0 - t2;
+        res[k + 3 * m] = t1 - t3;
+    }
+}
+
+/// # Generic odd radix butterfly
+fn butterfly_generic(res: &mut [Complex64], p: usize, m: usize, tw: &[Complex64], tw_stride: usize) {
+
+    let mut scratch: Vec<Complex64> = vec![Complex64::default(); p];
+
+    for k in 0..m {
+        for (q, val) in scratch.iter_mut().enumerate() {
+            *val = res[q * m + k] * tw[q * k * tw_stride];
+        }
+        for q2 in 0..p {
+            res[q2 * m + k] = scratch.iter().enumerate().fold(Complex64::default(), |sum, (q, val)| {
+                sum + val * tw[(q * q2 % p) * m * tw_stride]
+            });
+        }
+    }
+}
+
+/// # Transform along every axis of a row-major array
+fn transform_axes(data: &mut [Complex64], shape: &[usize], direction: Direction) {
+
+    assert_eq!(shape.iter().product::<usize>(), data.len(), "The shape does not match the number of values!");
+
+    // An empty axis leaves no line to transform
+    if data.is_empty() {
+        return;
+    }
+
+    let mut line: Vec<Complex64> = Vec::new();
+    let mut scratch: Vec<Complex64> = Vec::new();
+
+    for (axis, &length) in shape.iter().enumerate() {
+
+        let plan: FftPlan = FftPlan::new(length, direction);
+        let stride: usize = shape[axis + 1..].iter().product();
+        line.resize(length, Complex64::default());
+        scratch.resize(plan.scratch_length(), Complex64::default());
+
+        // Going through every line along the current axis
+        for block in data.chunks_exact_mut(length * stride) {
+            for offset in 0..stride {
+                for (k, val) in line.iter_mut().enumerate() {
+                    *val = block[offset + k * stride];
+                }
+                plan.process_with_scratch(&mut line, &mut scratch);
+                for (k, val) in line.iter().enumerate() {
+                    block[offset + k * stride] = *val;
+                }
+            }
+        }
+    }
+}
+
+/// # Circular shift of half of every axis of a row-major array
+fn shift_axes<T: Copy>(data: &[T], shape: &[usize], inverse: bool) -> Vec<T> {
+
+    assert_eq!(shape.iter().product::<usize>(), data.len(), "The shape does not match the number of values!");
+
+    let mut res: Vec<T> = data.to_vec();
+
+    for (idx, val) in data.iter().enumerate() {
+
+        // Moving the index along each axis, starting from the fastest varying one
+        let mut rest: usize = idx;
+        let mut target: usize = 0;
+        let mut stride: usize = 1;
+        for &length in shape.iter().rev() {
+            let k: usize = rest % length;
+            let shift: usize = if inverse { length - length / 2 } else { length / 2 };
+            target += ((k + shift) % length) * stride;
+            rest /= length;
+            stride *= length;
+        }
+
+        res[target] = *val;
+    }
+
+    res
+}
+
+/// # Smallest even length above `n` with only 2, 3 and 5 as prime factors, such that `rfft` packs the input
+fn fast_length(n: usize) -> usize {
+
+    let mut m: usize = n.max(2).next_multiple_of(2);
+    loop {
+        let mut rest: usize = m;
+        for p in [2, 3, 5] {
+            while rest.is_multiple_of(p) {
+                rest /= p;
+            }
+        }
+        if rest == 1 {
+            return m;
+        }
+        m += 2;
+    }
+}
+
+/// # Comparing the costs of the direct and FFT convolutions
+fn prefer_fft(l_a: usize, l_b: usize) -> bool {
+
+    let n: f64 = fast_length(l_a + l_b - 1) as f64;
+    (l_a * l_b) as f64 > FFT_COST * n * n.log2()
+}
+
+/// # Extracting the requested part of a full convolution
+fn crop<T>(mut full: Vec<T>, l_a: usize, l_b: usize, mode: Mode) -> Vec<T> {
+
+    let (long, short): (usize, usize) = (l_a.max(l_b), l_a.min(l_b));
+
+    let (start, length): (usize, usize) = match mode {
+        Mode::Full => return full,
+        Mode::Same => ((short - 1) / 2, long),
+        Mode::Valid => (short - 1, long - short + 1)
+    };
+
+    full.truncate(start + length);
+    full.drain(..start);
+    full
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,376 @@
+//!
+//! # Number-theoretic transform
+//! 
+//! The [number-theoretic transform](https://en.wikipedia.org/wiki/Discrete_Fourier_transform_over_a_ring) (NTT)
+//! is the discrete Fourier transform over the integers modulo a prime $p$, where a root of unity of order $N$
+//! exists when $N$ divides $p-1$. It has the same convolution theorem and the same $O(N\log N)$ algorithms as
+//! the FFT, but its arithmetic is exact.
+//! 
+//! Convolutions of integer sequences are computed modulo three primes close to $2^{62}$, and the exact result
+//! is rebuilt with the [Chinese remainder theorem](https://en.wikipedia.org/wiki/Chinese_remainder_theorem),
+//! as long as it fits in 128 bits.
+//! 
+//! ```
+//! # use scilib::signal::ntt::exact_convolve_signed;
+//! # use scilib::math::polynomial::Poly;
+//! // Expanding x(x - 1)(x - 2)...(x - 11), whose coefficients are the signed Stirling numbers
+//! let mut coefs: Vec<i128> = vec![1];
+//! for k in 0..12_i64 {
+//!     let current: Vec<i64> = coefs.iter().map(|&c| c as i64).collect();
+//!     coefs = exact_convolve_signed(&current, &[-k, 1]);
+//! }
+//! 
+//! for (k, c) in coefs.iter().enumerate() {
+//!     assert_eq!(*c, Poly::stirling_number_signed(12, k) as i128);
+//! }
+//! ```
+//! 
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Supported primes, of the form $c2^k+1$
+pub const NTT_PRIMES: [u64; 7] = [
+    998_244_353,                // 119 * 2^23 + 1
+    167_772_161,                // 5 * 2^25 + 1
+    469_762_049,                // 7 * 2^26 + 1
+    754_974_721,                // 45 * 2^24 + 1
+    4_179_340_454_199_820_289,  // 29 * 2^57 + 1
+    2_485_986_994_308_513_793,  // 69 * 2^55 + 1
+    1_945_555_039_024_054_273   // 27 * 2^56 + 1
+];
+
+/// Primes used for the exact convolutions, whose product is above $2^{183}$
+const CRT_PRIMES: [u64; 3] = [
+    4_179_340_454_199_820_289,
+    2_485_986_994_308_513_793,
+    1_945_555_039_024_054_273
+];
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Number-theoretic transform
+/// 
+/// ## Definition
+/// For a prime $p$ with primitive root $g$, and a length $N$ dividing $p-1$, the transform is:
+/// $$
+/// X_k = \sum_{n=0}^{N-1} x_n\omega^{nk} \mod p, \quad \omega = g^{(p-1)/N}
+/// $$
+/// 
+/// ## Inputs
+/// - `data`: the values, reduced modulo $p$ if needed
+/// - `modulus`: the prime $p$, one of `NTT_PRIMES`
+/// 
+/// Returns the transform. The length must be a power of two, at most the largest power of two dividing $p-1$.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::ntt::{ ntt, intt };
+/// let p: u64 = 998_244_353;
+/// let x: Vec<u64> = vec![5, 1, 4, 1, 5, 9, 2, 6];
+/// let res = ntt(&x, p);
+/// 
+/// // The first value is the sum, and the inverse gives back the values
+/// assert_eq!(res[0], 33);
+/// assert_eq!(intt(&res, p), x);
+/// ```
+pub fn ntt(data: &[u64], modulus: u64) -> Vec<u64> {
+
+    let mut res: Vec<u64> = data.iter().map(|v| v % modulus).collect();
+    transform(&mut res, modulus, false);
+    res
+}
+
+/// # Inverse number-theoretic transform
+/// 
+/// ## Definition
+/// Undoes the transform:
+/// $$
+/// x_n = N^{-1}\sum_{k=0}^{N-1} X_k\omega^{-nk} \mod p
+/// $$
+/// 
+/// ## Inputs
+/// - `data`: the transform, reduced modulo $p$ if needed
+/// - `modulus`: the prime $p$, one of `NTT_PRIMES`
+/// 
+/// Returns the values, between $0$ and $p-1$.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::ntt::{ ntt, intt };
+/// let p: u64 = 4_179_340_454_199_820_289;
+/// let x: Vec<u64> = (0..1024).map(|k| (k * k * 7919) % p).collect();
+/// assert_eq!(intt(&ntt(&x, p), p), x);
+/// ```
+pub fn intt(data: &[u64], modulus: u64) -> Vec<u64> {
+
+    let mut res: Vec<u64> = data.iter().map(|v| v % modulus).collect();
+    transform(&mut res, modulus, true);
+    res
+}
+
+/// # Modular convolution
+/// 
+/// ## Definition
+/// Computes the full convolution of two sequences modulo a prime:
+/// $$
+/// c_n = \sum_k a_kb_{n-k} \mod p
+/// $$
+/// through the product of their transforms, zero padded to a power of two.
+/// 
+/// ## Inputs
+/// - `a`: the first sequence
+/// - `b`: the second sequence
+/// - `modulus`: the prime $p$, one of `NTT_PRIMES`
+/// 
+/// Returns the $n + m - 1$ values of the convolution, between $0$ and $p-1$.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::ntt::ntt_convolve;
+/// let p: u64 = 998_244_353;
+/// assert_eq!(ntt_convolve(&[1, 2, 3], &[4, 5], p), vec![4, 13, 22, 15]);
+/// 
+/// // Reduced modulo p
+/// assert_eq!(ntt_convolve(&[p - 1, 1], &[2], p), vec![p - 2, 2]);
+/// ```
+pub fn ntt_convolve(a: &[u64], b: &[u64], modulus: u64) -> Vec<u64> {
+
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+
+    let length: usize = a.len() + b.len() - 1;
+    let padded: usize = length.next_power_of_two();
+
+    let mut a_pad: Vec<u64> = a.iter().map(|v| v % modulus).collect();
+    let mut b_pad: Vec<u64> = b.iter().map(|v| v % modulus).collect();
+    a_pad.resize(padded, 0);
+    b_pad.resize(padded, 0);
+
+    transform(&mut a_pad, modulus, false);
+    transform(&mut b_pad, modulus, false);
+    a_pad.iter_mut().zip(&b_pad).for_each(|(x, y)| *x = mul_mod(*x, *y, modulus));
+    transform(&mut a_pad, modulus, true);
+
+    a_pad.truncate(length);
+    a_pad
+}
+
+/// # Exact convolution of unsigned integers
+/// 
+/// ## Definition
+/// Computes the full convolution modulo three primes, and rebuilds each value with the Chinese remainder
+/// theorem. The result is exact as long as it fits in a `u128`, which is checked from the largest values of
+/// the inputs: $\min(n, m)\max_k a_k\max_k b_k < 2^{128}$.
+/// 
+/// ## Inputs
+/// - `a`: the first sequence
+/// - `b`: the second sequence
+/// 
+/// Returns the $n + m - 1$ values of the convolution.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::ntt::exact_convolve;
+/// let a: Vec<u64> = vec![u64::MAX, 3, u64::MAX / 7];
+/// let b: Vec<u64> = vec![1 << 62, 5];
+/// 
+/// let res = exact_convolve(&a, &b);
+/// let (m, q): (u128, u128) = (u64::MAX as u128, 1 << 62);
+/// assert_eq!(res, vec![m * q, 5 * m + 3 * q, 15 + (m / 7) * q, 5 * (m / 7)]);
+/// ```
+pub fn exact_convolve(a: &[u64], b: &[u64]) -> Vec<u128> {
+
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+
+    let max_a: u128 = *a.iter().max().unwrap() as u128;
+    let max_b: u128 = *b.iter().max().unwrap() as u128;
+    assert!(
+        max_a.checked_mul(max_b).and_then(|v| v.checked_mul(a.len().min(b.len()) as u128)).is_some(),
+        "The convolution could overflow 128 bits!"
+    );
+
+    let residues: Vec<Vec<u64>> = CRT_PRIMES.iter().map(|&p| ntt_convolve(a, b, p)).collect();
+    let inverses: [u64; 3] = crt_inverses();
+
+    (0..a.len() + b.len() - 1).map(|k| {
+        let (value, _) = garner(residues[0][k], residues[1][k], residues[2][k], inverses);
+        value
+    }).collect()
+}
+
+/// # Exact convolution of signed integers
+/// 
+/// ## Definition
+/// Same as `exact_convolve`, the negative values being represented by their residues. The result is exact as
+/// long as it fits in an `i128`, which is checked from the largest magnitudes of the inputs.
+/// 
+/// ## Inputs
+/// - `a`: the first sequence
+/// - `b`: the second sequence
+/// 
+/// Returns the $n + m - 1$ values of the convolution.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::ntt::exact_convolve_signed;
+/// let a: Vec<i64> = vec![i64::MIN, -5, 7];
+/// let b: Vec<i64> = vec![i64::MAX, -2];
+/// 
+/// let res = exact_convolve_signed(&a, &b);
+/// let (lo, hi): (i128, i128) = (i64::MIN as i128, i64::MAX as i128);
+/// assert_eq!(res, vec![lo * hi, -2 * lo - 5 * hi, 10 + 7 * hi, -14]);
+/// ```
+pub fn exact_convolve_signed(a: &[i64], b: &[i64]) -> Vec<i128> {
+
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+
+    let max_a: u128 = a.iter().map(|v| v.unsigned_abs()).max().unwrap() as u128;
+    let max_b: u128 = b.iter().map(|v| v.unsigned_abs()).max().unwrap() as u128;
+    assert!(
+        max_a.checked_mul(max_b).and_then(|v| v.checked_mul(a.len().min(b.len()) as u128)).is_some_and(|v| v <= i128::MAX as u128),
+        "The convolution could overflow 128 bits!"
+    );
+
+    let residues: Vec<Vec<u64>> = CRT_PRIMES.iter().map(|&p| {
+        let to_residue = |v: &i64| if *v < 0 { (p - v.unsigned_abs() % p) % p } else { *v as u64 % p };
+        let a_mod: Vec<u64> = a.iter().map(to_residue).collect();
+        let b_mod: Vec<u64> = b.iter().map(to_residue).collect();
+        ntt_convolve(&a_mod, &b_mod, p)
+    }).collect();
+
+    let product: u128 = CRT_PRIMES.iter().fold(1_u128, |acc, &p| acc.wrapping_mul(p as u128));
+    let inverses: [u64; 3] = crt_inverses();
+
+    (0..a.len() + b.len() - 1).map(|k| {
+        let (value, negative) = garner(residues[0][k], residues[1][k], residues[2][k], inverses);
+        if negative {
+            // Removing the product of the primes, modulo 2^128
+            value.wrapping_sub(product) as i128
+        } else {
+            value as i128
+        }
+    }).collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Primitive root and two-adicity of a supported prime
+fn prime_info(modulus: u64) -> (u64, u32) {
+    match modulus {
+        998_244_353 => (3, 23),
+        167_772_161 => (3, 25),
+        469_762_049 => (3, 26),
+        754_974_721 => (11, 24),
+        4_179_340_454_199_820_289 => (3, 57),
+        2_485_986_994_308_513_793 => (5, 55),
+        1_945_555_039_024_054_273 => (5, 56),
+        _ => panic!("Unsupported modulus, see NTT_PRIMES!")
+    }
+}
+
+/// # In-place iterative radix-2 transform, on reduced values
+fn transform(data: &mut [u64], modulus: u64, inverse: bool) {
+
+    let (root, adicity): (u64, u32) = prime_info(modulus);
+    let n: usize = data.len();
+
+    assert!(n.is_power_of_two(), "The length must be a power of two!");
+    assert!(n.trailing_zeros() <= adicity, "The length is too large for this modulus!");
+
+    if n == 1 {
+        return;
+    }
+
+    // Bit reversal permutation
+    let bits: u32 = n.trailing_zeros();
+    for i in 0..n {
+        let j: usize = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+
+    // Butterflies
+    let mut len: usize = 2;
+    while len <= n {
+        let mut w_len: u64 = pow_mod(root, (modulus - 1) / len as u64, modulus);
+        if inverse {
+            w_len = pow_mod(w_len, modulus - 2, modulus);
+        }
+
+        for block in data.chunks_exact_mut(len) {
+            let (low, high) = block.split_at_mut(len / 2);
+            let mut w: u64 = 1;
+            for (u, v) in low.iter_mut().zip(high.iter_mut()) {
+                let t: u64 = mul_mod(*v, w, modulus);
+                *v = if *u >= t { *u - t } else { *u + modulus - t };
+                *u = if *u + t >= modulus { *u + t - modulus } else { *u + t };
+                w = mul_mod(w, w_len, modulus);
+            }
+        }
+
+        len <<= 1;
+    }
+
+    if inverse {
+        let n_inv: u64 = pow_mod(n as u64 % modulus, modulus - 2, modulus);
+        data.iter_mut().for_each(|v| *v = mul_mod(*v, n_inv, modulus));
+    }
+}
+
+/// # Modular product, through 128 bits
+fn mul_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    (a as u128 * b as u128 % modulus as u128) as u64
+}
+
+/// # Modular power, by squaring
+fn pow_mod(mut base: u64, mut exp: u64, modulus: u64) -> u64 {
+
+    let mut res: u64 = 1 % modulus;
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            res = mul_mod(res, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exp >>= 1;
+    }
+
+    res
+}
+
+/// # Inverses of the first primes modulo the next ones, for Garner's algorithm
+fn crt_inverses() -> [u64; 3] {
+
+    let [p1, p2, p3]: [u64; 3] = CRT_PRIMES;
+
+    [pow_mod(p1 % p2, p2 - 2, p2), pow_mod(p1 % p3, p3 - 2, p3), pow_mod(p2 % p3, p3 - 2, p3)]
+}
+
+/// # Rebuilding a value from its residues with Garner's algorithm
+/// 
+/// Returns the value modulo $2^{128}$, and whether it is in the upper half of the range of the product of the primes.
+fn garner(r1: u64, r2: u64, r3: u64, inverses: [u64; 3]) -> (u128, bool) {
+
+    let [p1, p2, p3]: [u64; 3] = CRT_PRIMES;
+    let [inv_p1_p2, inv_p1_p3, inv_p2_p3]: [u64; 3] = inverses;
+
+    // Mixed radix digits: x = t1 + p1 t2 + p1 p2 t3
+    let t1: u64 = r1;
+    let t2: u64 = mul_mod((r2 + p2 - t1 % p2) % p2, inv_p1_p2, p2);
+    let s: u64 = mul_mod((r3 + p3 - t1 % p3) % p3, inv_p1_p3, p3);
+    let t3: u64 = mul_mod((s + p3 - t2 % p3) % p3, inv_p2_p3, p3);
+
+    let value: u128 = (t1 as u128)
+        .wrapping_add((p1 as u128).wrapping_mul(t2 as u128))
+        .wrapping_add((p1 as u128).wrapping_mul(p2 as u128).wrapping_mul(t3 as u128));
+
+    (value, t3 > p3 / 2)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
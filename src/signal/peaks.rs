@@ -0,0 +1,381 @@
+//!
+//! # Peak detection
+//! 
+//! Detection of the local maxima of a signal, selected by their height, their vertical distance to the
+//! neighbouring samples, their horizontal distance to each other, their
+//! [prominence](https://en.wikipedia.org/wiki/Topographic_prominence) and their width. Each peak is
+//! characterized by its prominence, its width at a relative height of the prominence, and a sub-sample
+//! position from a parabolic interpolation.
+//! 
+//! Minima, such as absorption lines or transit dips, are found as the peaks of the negated signal.
+//! 
+//! ```
+//! # use scilib::signal::peaks::{ find_peaks, PeakOptions };
+//! // Two Gaussian lines on a small ripple
+//! let x: Vec<f64> = (0..200).map(|k| {
+//!     let t: f64 = k as f64;
+//!     3.0 * (-(t - 60.3).powi(2) / 50.0).exp() + 2.0 * (-(t - 140.0).powi(2) / 18.0).exp() + 0.01 * (t / 2.0).sin()
+//! }).collect();
+//! 
+//! let options = PeakOptions { prominence: Some((1.0, f64::INFINITY)), ..PeakOptions::default() };
+//! let res = find_peaks(&x, options);
+//! assert_eq!(res.indices, vec![60, 140]);
+//! assert!((res.positions[0] - 60.3).abs() < 0.1);
+//! ```
+//! 
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Options of the peak detection
+/// 
+/// The bounds are given as `(minimum, maximum)`, with an infinite maximum for no upper bound. Criteria set to
+/// `None` are not checked.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PeakOptions {
+    /// Bounds of the height of the peaks
+    pub height: Option<(f64, f64)>,
+    /// Bounds of the vertical distance between the peaks and their neighbouring samples
+    pub threshold: Option<(f64, f64)>,
+    /// Minimum horizontal distance between neighbouring peaks, in samples, the smaller peaks being removed first
+    pub distance: Option<usize>,
+    /// Bounds of the prominence of the peaks
+    pub prominence: Option<(f64, f64)>,
+    /// Bounds of the width of the peaks, in samples
+    pub width: Option<(f64, f64)>,
+    /// Length of the window around each peak in which its prominence is searched, the whole signal if `None`
+    pub wlen: Option<usize>,
+    /// Height at which the widths are measured, as a fraction of the prominence below the peak
+    pub rel_height: f64
+}
+
+impl Default for PeakOptions {
+    fn default() -> Self {
+        Self {
+            height: None,
+            threshold: None,
+            distance: None,
+            prominence: None,
+            width: None,
+            wlen: None,
+            rel_height: 0.5
+        }
+    }
+}
+
+/// # Peaks of a signal
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Peaks {
+    /// Indices of the peaks, the middle of the plateau for flat peaks
+    pub indices: Vec<usize>,
+    /// Sub-sample positions of the peaks
+    pub positions: Vec<f64>,
+    /// Values of the signal at the peaks
+    pub heights: Vec<f64>,
+    /// Prominences of the peaks
+    pub prominences: Vec<f64>,
+    /// Indices of the lowest points on each side of the peaks, defining their prominence
+    pub bases: Vec<(usize, usize)>,
+    /// Widths of the peaks at the relative height
+    pub widths: Vec<f64>,
+    /// Heights at which the widths are measured
+    pub width_heights: Vec<f64>,
+    /// Interpolated positions of the left and right edges of the widths
+    pub edges: Vec<(f64, f64)>
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Peak detection
+/// 
+/// ## Definition
+/// Finds all the local maxima of the signal, a flat peak of several equal samples being located at its middle,
+/// and keeps those satisfying each criterion in turn: height, threshold, distance, prominence and width.
+/// The edges of the signal are never peaks.
+/// 
+/// The sub-sample position of a peak $p$ is the vertex of the parabola through its neighbouring samples:
+/// $$
+/// \hat p = p + \frac{1}{2}\frac{x_{p-1} - x_{p+1}}{x_{p-1} - 2x_p + x_{p+1}}
+/// $$
+/// and the middle of the plateau for flat peaks.
+/// 
+/// ## Inputs
+/// - `x`: the signal
+/// - `options`: the selection criteria, the prominence window and the relative height of the widths
+/// 
+/// Returns the indices of the peaks in increasing order, along with their characterization.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::peaks::{ find_peaks, PeakOptions };
+/// let x: Vec<f64> = vec![0.0, 2.0, 1.0, 4.0, 4.0, 4.0, 4.0, 1.0, 3.0, 2.5, 5.0, 0.0];
+/// 
+/// let all = find_peaks(&x, PeakOptions::default());
+/// assert_eq!(all.indices, vec![1, 4, 8, 10]);
+/// assert_eq!(all.positions[1], 4.5);
+/// 
+/// // Height and distance criteria
+/// let options = PeakOptions { height: Some((2.5, f64::INFINITY)), distance: Some(4), ..PeakOptions::default() };
+/// let res = find_peaks(&x, options);
+/// assert_eq!(res.indices, vec![4, 10]);
+/// assert_eq!(res.prominences, vec![3.0, 5.0]);
+/// ```
+pub fn find_peaks(x: &[f64], options: PeakOptions) -> Peaks {
+
+    let mut peaks: Vec<usize> = local_maxima(x);
+
+    if let Some(bounds) = options.height {
+        peaks.retain(|&p| within(x[p], bounds));
+    }
+
+    if let Some(bounds) = options.threshold {
+        peaks.retain(|&p| {
+            let (left, right): (f64, f64) = (x[p] - x[p - 1], x[p] - x[p + 1]);
+            left.min(right) >= bounds.0 && left.max(right) <= bounds.1
+        });
+    }
+
+    if let Some(distance) = options.distance {
+        peaks = select_by_distance(x, &peaks, distance);
+    }
+
+    let (mut prominence, mut bases): (Vec<f64>, Vec<(usize, usize)>) = prominences(x, &peaks, options.wlen);
+
+    if let Some(bounds) = options.prominence {
+        let keep: Vec<bool> = prominence.iter().map(|&v| within(v, bounds)).collect();
+        retain_mask(&mut peaks, &keep);
+        retain_mask(&mut prominence, &keep);
+        retain_mask(&mut bases, &keep);
+    }
+
+    let (mut width, mut width_heights, mut edges) = widths_from(x, &peaks, &prominence, &bases, options.rel_height);
+
+    if let Some(bounds) = options.width {
+        let keep: Vec<bool> = width.iter().map(|&v| within(v, bounds)).collect();
+        retain_mask(&mut peaks, &keep);
+        retain_mask(&mut prominence, &keep);
+        retain_mask(&mut bases, &keep);
+        retain_mask(&mut width, &keep);
+        retain_mask(&mut width_heights, &keep);
+        retain_mask(&mut edges, &keep);
+    }
+
+    Peaks {
+        positions: peaks.iter().map(|&p| interpolate(x, p)).collect(),
+        heights: peaks.iter().map(|&p| x[p]).collect(),
+        indices: peaks,
+        prominences: prominence,
+        bases,
+        widths: width,
+        width_heights,
+        edges
+    }
+}
+
+/// # Prominence of peaks
+/// 
+/// ## Definition
+/// From each peak, the signal is followed on both sides until a higher sample or the end of the window,
+/// and the base on each side is its minimum along the way. The prominence is the height of the peak above the
+/// higher of its two bases.
+/// 
+/// ## Inputs
+/// - `x`: the signal
+/// - `peaks`: the indices of the peaks
+/// - `wlen`: the length of the window centered on each peak, the whole signal if `None`
+/// 
+/// Returns the prominences, and the indices of the left and right bases.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::peaks::prominences;
+/// let x: Vec<f64> = vec![1.0, 0.0, 5.0, 2.0, 3.0, -1.0, 4.0, 0.5];
+/// 
+/// let (prom, bases) = prominences(&x, &[2, 4, 6], None);
+/// assert_eq!(prom, vec![5.0, 1.0, 3.5]);
+/// assert_eq!(bases, vec![(1, 5), (3, 5), (5, 7)]);
+/// ```
+pub fn prominences(x: &[f64], peaks: &[usize], wlen: Option<usize>) -> (Vec<f64>, Vec<(usize, usize)>) {
+
+    peaks.iter().map(|&p| {
+        assert!(p < x.len(), "The peaks must be inside the signal!");
+
+        let (start, end): (usize, usize) = match wlen {
+            Some(w) => (p.saturating_sub(w / 2), (p + w / 2).min(x.len() - 1)),
+            None => (0, x.len() - 1)
+        };
+
+        // Minimum on each side, until a higher sample
+        let (mut left_min, mut left_base): (f64, usize) = (x[p], p);
+        for i in (start..=p).rev() {
+            if x[i] > x[p] {
+                break;
+            }
+            if x[i] < left_min {
+                (left_min, left_base) = (x[i], i);
+            }
+        }
+
+        let (mut right_min, mut right_base): (f64, usize) = (x[p], p);
+        for (i, &v) in x.iter().enumerate().take(end + 1).skip(p) {
+            if v > x[p] {
+                break;
+            }
+            if v < right_min {
+                (right_min, right_base) = (v, i);
+            }
+        }
+
+        (x[p] - left_min.max(right_min), (left_base, right_base))
+    }).unzip()
+}
+
+/// # Width of peaks
+/// 
+/// ## Definition
+/// The width of a peak $p$ of prominence $P$ is measured at the height:
+/// $$
+/// h = x_p - rP
+/// $$
+/// between the first crossings of that height on each side of the peak, found by linear interpolation and
+/// limited to the bases of the prominence. With $r=0.5$, this is the full width at half prominence.
+/// 
+/// ## Inputs
+/// - `x`: the signal
+/// - `peaks`: the indices of the peaks
+/// - `rel_height`: the fraction of the prominence below the peak ($r$)
+/// - `wlen`: the length of the window used for the prominence, the whole signal if `None`
+/// 
+/// Returns the widths, the heights at which they are measured, and the positions of the left and right edges.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::peaks::widths;
+/// // A triangle of height 4 and base 8
+/// let x: Vec<f64> = vec![0.0, 0.0, 1.0, 2.0, 3.0, 4.0, 3.0, 2.0, 1.0, 0.0, 0.0];
+/// 
+/// let (w, heights, edges) = widths(&x, &[5], 0.5, None);
+/// assert_eq!(w, vec![4.0]);
+/// assert_eq!(heights, vec![2.0]);
+/// assert_eq!(edges, vec![(3.0, 7.0)]);
+/// 
+/// let (w, _, _) = widths(&x, &[5], 1.0, None);
+/// assert_eq!(w, vec![8.0]);
+/// ```
+pub fn widths(x: &[f64], peaks: &[usize], rel_height: f64, wlen: Option<usize>) -> (Vec<f64>, Vec<f64>, Vec<(f64, f64)>) {
+    let (prominence, bases): (Vec<f64>, Vec<(usize, usize)>) = prominences(x, peaks, wlen);
+    widths_from(x, peaks, &prominence, &bases, rel_height)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Local maxima, located at the middle of flat peaks
+fn local_maxima(x: &[f64]) -> Vec<usize> {
+
+    let mut res: Vec<usize> = vec![];
+    let mut i: usize = 1;
+
+    while i + 1 < x.len() {
+        if x[i - 1] < x[i] {
+            // Skipping the samples equal to the current one
+            let mut ahead: usize = i + 1;
+            while ahead + 1 < x.len() && x[ahead] == x[i] {
+                ahead += 1;
+            }
+            if x[ahead] < x[i] {
+                res.push((i + ahead - 1) / 2);
+                i = ahead;
+            }
+        }
+        i += 1;
+    }
+
+    res
+}
+
+/// # Removal of the smaller peaks closer than the distance to a higher one
+fn select_by_distance(x: &[f64], peaks: &[usize], distance: usize) -> Vec<usize> {
+
+    let mut keep: Vec<bool> = vec![true; peaks.len()];
+
+    // From the highest peak to the lowest
+    let mut order: Vec<usize> = (0..peaks.len()).collect();
+    order.sort_by(|&a, &b| x[peaks[a]].total_cmp(&x[peaks[b]]));
+
+    for &j in order.iter().rev() {
+        if !keep[j] {
+            continue;
+        }
+        for k in (0..j).rev().take_while(|&k| peaks[j] - peaks[k] < distance) {
+            keep[k] = false;
+        }
+        for k in (j + 1..peaks.len()).take_while(|&k| peaks[k] - peaks[j] < distance) {
+            keep[k] = false;
+        }
+    }
+
+    peaks.iter().zip(&keep).filter(|(_, &k)| k).map(|(&p, _)| p).collect()
+}
+
+/// # Widths of peaks with known prominences and bases
+fn widths_from(x: &[f64], peaks: &[usize], prominence: &[f64], bases: &[(usize, usize)], rel_height: f64) -> (Vec<f64>, Vec<f64>, Vec<(f64, f64)>) {
+
+    assert!(rel_height >= 0.0, "The relative height cannot be negative!");
+
+    let mut res: (Vec<f64>, Vec<f64>, Vec<(f64, f64)>) = (vec![], vec![], vec![]);
+
+    for ((&p, &prom), &(left_base, right_base)) in peaks.iter().zip(prominence).zip(bases) {
+        let height: f64 = x[p] - prom * rel_height;
+
+        // Left crossing
+        let mut i: usize = p;
+        while left_base < i && height < x[i] {
+            i -= 1;
+        }
+        let mut left: f64 = i as f64;
+        if x[i] < height {
+            left += (height - x[i]) / (x[i + 1] - x[i]);
+        }
+
+        // Right crossing
+        let mut i: usize = p;
+        while i < right_base && height < x[i] {
+            i += 1;
+        }
+        let mut right: f64 = i as f64;
+        if x[i] < height {
+            right -= (height - x[i]) / (x[i - 1] - x[i]);
+        }
+
+        res.0.push(right - left);
+        res.1.push(height);
+        res.2.push((left, right));
+    }
+
+    res
+}
+
+/// # Vertex of the parabola through the peak and its neighbours, or middle of the plateau
+fn interpolate(x: &[f64], p: usize) -> f64 {
+
+    if x[p - 1] == x[p] || x[p + 1] == x[p] {
+        // Middle of a flat peak
+        let left: usize = (0..p).rev().take_while(|&i| x[i] == x[p]).last().unwrap_or(p);
+        let right: usize = (p + 1..x.len()).take_while(|&i| x[i] == x[p]).last().unwrap_or(p);
+        return (left + right) as f64 / 2.0;
+    }
+
+    p as f64 + 0.5 * (x[p - 1] - x[p + 1]) / (x[p - 1] - 2.0 * x[p] + x[p + 1])
+}
+
+/// # Whether a value is within bounds
+fn within(value: f64, bounds: (f64, f64)) -> bool {
+    value >= bounds.0 && value <= bounds.1
+}
+
+/// # Keeps the elements of a vector flagged by a mask
+fn retain_mask<T>(v: &mut Vec<T>, keep: &[bool]) {
+    let mut flags = keep.iter();
+    v.retain(|_| *flags.next().unwrap());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,342 @@
+//!
+//! # Resampling
+//! 
+//! Changing the sampling rate of evenly sampled signals, real or complex:
+//! - `resample`: Fourier method, exact for periodic band-limited signals
+//! - `resample_poly`: polyphase filtering for rational factors `up / down`
+//! - `decimate`: anti-aliasing filter followed by downsampling
+//! 
+//! As for `range::linear`, lengths are given in number of points. The time axis of the resampled signal
+//! is given by `resample_times`.
+//! 
+//! ```
+//! # use scilib::signal::resample::{ resample_poly, resample_times };
+//! # use scilib::range;
+//! // A 48 kHz recording converted to 44.1 kHz
+//! let t: Vec<f64> = range::linear(0.0, 0.01, 480);
+//! let x: Vec<f64> = t.iter().map(|v| (std::f64::consts::TAU * 1000.0 * v).sin()).collect();
+//! 
+//! let y: Vec<f64> = resample_poly(&x, 147, 160);
+//! let t_new: Vec<f64> = resample_times(&t, y.len());
+//! assert_eq!(y.len(), 441);
+//! for (v, tn) in y[50..400].iter().zip(&t_new[50..400]) {
+//!     assert!((v - (std::f64::consts::TAU * 1000.0 * tn).sin()).abs() < 1.0e-2);
+//! }
+//! ```
+//! 
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use std::ops::{             // Operations required on the samples
+    Add,                    // Addition
+    Mul                     // Multiplication by a real
+};
+
+use super::{                // Using the parent module
+    fft,                    // Forward transform
+    ifft,                   // Inverse transform
+    filter,                 // Anti-aliasing filters
+    window                  // Windows of the FIR filters
+};
+
+use num_complex::Complex64; // Using complex numbers from the num crate
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Shape parameter of the Kaiser window of the polyphase filters
+const KAISER_BETA: f64 = 5.0;
+
+/// Half-length of the polyphase filters, per unit of the largest rate
+const POLY_HALF_LENGTH: usize = 10;
+
+/// Order of the Chebyshev anti-aliasing filter of `decimate`
+const CHEBYSHEV_ORDER: usize = 8;
+
+/// Pass-band ripple of the Chebyshev anti-aliasing filter of `decimate`, in decibels
+const CHEBYSHEV_RIPPLE: f64 = 0.05;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Sample of a signal
+/// 
+/// Implemented for real (`f64`) and complex (`Complex64`) samples.
+pub trait Sample: Copy + Default + Add<Output = Self> + Mul<f64, Output = Self> {
+    /// Conversion to a complex number
+    fn to_complex(self) -> Complex64;
+    /// Conversion from a complex number, dropping the imaginary part for real samples
+    fn from_complex(z: Complex64) -> Self;
+}
+
+impl Sample for f64 {
+    fn to_complex(self) -> Complex64 {
+        Complex64::new(self, 0.0)
+    }
+    fn from_complex(z: Complex64) -> Self {
+        z.re
+    }
+}
+
+impl Sample for Complex64 {
+    fn to_complex(self) -> Complex64 {
+        self
+    }
+    fn from_complex(z: Complex64) -> Self {
+        z
+    }
+}
+
+/// # Anti-aliasing filter of the decimation
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AntiAliasing {
+    /// Order 8 Chebyshev I filter, applied forward and backward
+    Chebyshev,
+    /// Hamming-windowed FIR filter of length $20q + 1$, applied with zero phase
+    Fir
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Fourier resampling
+/// 
+/// ## Definition
+/// Resamples the signal to `num` points with the Fourier method: the spectrum of the signal is truncated
+/// or zero-padded to the new length, and transformed back. The Nyquist component of even lengths is split or
+/// merged, such that the result is real for real signals.
+/// 
+/// The signal is assumed periodic, which makes the method exact for band-limited periodic signals,
+/// but can cause ringing at the edges otherwise.
+/// 
+/// ## Inputs
+/// - `x`: the signal, real or complex
+/// - `num`: the number of points of the resampled signal
+/// 
+/// Returns the resampled signal.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::resample::resample;
+/// let n: usize = 20;
+/// let signal = |t: f64| (std::f64::consts::TAU * 3.0 * t).cos() + 0.5 * (std::f64::consts::TAU * 2.0 * t).sin();
+/// let x: Vec<f64> = (0..n).map(|k| signal(k as f64 / n as f64)).collect();
+/// 
+/// // Exact on the new grid
+/// let y = resample(&x, 47);
+/// for (k, v) in y.iter().enumerate() {
+///     assert!((v - signal(k as f64 / 47.0)).abs() < 1.0e-12);
+/// }
+/// 
+/// let z = resample(&y, 20);
+/// for (v, w) in z.iter().zip(&x) {
+///     assert!((v - w).abs() < 1.0e-12);
+/// }
+/// ```
+pub fn resample<T: Sample>(x: &[T], num: usize) -> Vec<T> {
+
+    let nx: usize = x.len();
+    if nx == 0 || num == 0 {
+        return vec![];
+    }
+
+    let spectrum: Vec<Complex64> = fft(&x.iter().map(|v| v.to_complex()).collect::<Vec<Complex64>>());
+    let mut y: Vec<Complex64> = vec![Complex64::default(); num];
+
+    // Positive frequencies up to the Nyquist frequency, then negative frequencies
+    let n: usize = num.min(nx);
+    let nyq: usize = n / 2 + 1;
+    y[..nyq].copy_from_slice(&spectrum[..nyq]);
+    for k in 1..=n.saturating_sub(nyq) {
+        y[num - k] = spectrum[nx - k];
+    }
+
+    // Splitting or merging the Nyquist component
+    if n.is_multiple_of(2) {
+        if num < nx {
+            y[n / 2] += spectrum[nx - n / 2];
+        } else if nx < num {
+            y[n / 2] *= 0.5;
+            y[num - n / 2] = y[n / 2];
+        }
+    }
+
+    let scale: f64 = num as f64 / nx as f64;
+    ifft(&y).iter().map(|v| T::from_complex(v * scale)).collect()
+}
+
+/// # Time axis of a resampled signal
+/// 
+/// ## Definition
+/// Computes the sampling times after resampling to `num` points. The resampled signal covers the same
+/// duration as the original one, $n\Delta t$, and starts at the same time:
+/// $$
+/// t'_k = t_0 + k\frac{n\Delta t}{n'}
+/// $$
+/// 
+/// ## Inputs
+/// - `t`: the evenly spaced sampling times of the original signal, as built by `range::linear`
+/// - `num`: the number of points of the resampled signal ($n'$)
+/// 
+/// Returns the new sampling times.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::resample::resample_times;
+/// # use scilib::range;
+/// let t: Vec<f64> = range::linear(0.0, 0.9, 10);
+/// let t_new = resample_times(&t, 5);
+/// let expected: Vec<f64> = vec![0.0, 0.2, 0.4, 0.6, 0.8];
+/// for (v, e) in t_new.iter().zip(&expected) {
+///     assert!((v - e).abs() < 1.0e-12);
+/// }
+/// ```
+pub fn resample_times(t: &[f64], num: usize) -> Vec<f64> {
+
+    if t.is_empty() || num == 0 {
+        return vec![];
+    }
+    if t.len() == 1 {
+        return vec![t[0]; num.min(1)];
+    }
+
+    let dt: f64 = (t[t.len() - 1] - t[0]) / (t.len() - 1) as f64;
+    let new_dt: f64 = dt * t.len() as f64 / num as f64;
+
+    (0..num).map(|k| t[0] + k as f64 * new_dt).collect()
+}
+
+/// # Polyphase resampling
+/// 
+/// ## Definition
+/// Resamples the signal by the rational factor `up / down`: the signal is upsampled by inserting zeros,
+/// filtered by a low-pass FIR filter, and downsampled. The polyphase implementation only computes the
+/// kept outputs, from the non-zero inputs.
+/// 
+/// The filter is designed with `filter::firwin`, with a Kaiser window ($\beta = 5$) of $20\max(u, d) + 1$
+/// points and a cutoff at the lowest of the two Nyquist frequencies. Its delay is compensated, such that
+/// the output is aligned with the input, and has $\lceil nu/d \rceil$ points.
+/// 
+/// ## Inputs
+/// - `x`: the signal, real or complex
+/// - `up`: the upsampling factor ($u$)
+/// - `down`: the downsampling factor ($d$)
+/// 
+/// Returns the resampled signal.
+/// 
+/// ## Example
+/// ```
+/// # use num_complex::Complex64;
+/// # use scilib::signal::resample::resample_poly;
+/// // Complex tone upsampled by 3 / 2
+/// let x: Vec<Complex64> = (0..200).map(|k| Complex64::from_polar(1.0, 0.3 * k as f64)).collect();
+/// let y = resample_poly(&x, 3, 2);
+/// assert_eq!(y.len(), 300);
+/// for (k, v) in y.iter().enumerate().skip(30).take(240) {
+///     assert!((v - Complex64::from_polar(1.0, 0.2 * k as f64)).norm() < 1.0e-2);
+/// }
+/// ```
+pub fn resample_poly<T: Sample>(x: &[T], up: usize, down: usize) -> Vec<T> {
+
+    assert!(up > 0 && down > 0, "The resampling factors must be positive!");
+
+    // Reduced factors
+    let g: usize = gcd(up, down);
+    let (up, down): (usize, usize) = (up / g, down / g);
+    if up == 1 && down == 1 {
+        return x.to_vec();
+    }
+
+    let max_rate: usize = up.max(down);
+    let numtaps: usize = 2 * POLY_HALF_LENGTH * max_rate + 1;
+    let win: Vec<f64> = window::kaiser(numtaps, KAISER_BETA, window::Symmetry::Symmetric);
+    let h: Vec<f64> = filter::firwin(numtaps, &[0.5 / max_rate as f64], filter::BandType::LowPass, &win, 1.0)
+        .iter().map(|v| v * up as f64).collect();
+
+    upfirdn_centered(x, &h, up, down)
+}
+
+/// # Decimation
+/// 
+/// ## Definition
+/// Downsamples the signal by an integer factor $q$, keeping one sample out of $q$ after an anti-aliasing
+/// low-pass filter. Both filters are applied with zero phase:
+/// - `Chebyshev`: order 8 Chebyshev I filter with 0.05 dB of ripple, with a cutoff at 80% of the new
+///   Nyquist frequency, applied with `filter::sosfiltfilt`
+/// - `Fir`: Hamming-windowed filter with a cutoff at the new Nyquist frequency, applied as in `resample_poly`
+/// 
+/// ## Inputs
+/// - `x`: the signal, real or complex
+/// - `q`: the downsampling factor
+/// - `method`: the anti-aliasing filter
+/// 
+/// Returns the decimated signal, with $\lceil n/q \rceil$ points.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::resample::{ decimate, AntiAliasing };
+/// // A slow tone is kept, a tone above the new Nyquist frequency is removed
+/// let x: Vec<f64> = (0..1000).map(|k| (0.02 * k as f64).sin() + (2.5 * k as f64).sin()).collect();
+/// 
+/// for method in [AntiAliasing::Chebyshev, AntiAliasing::Fir] {
+///     let y = decimate(&x, 4, method);
+///     assert_eq!(y.len(), 250);
+///     for (k, v) in y.iter().enumerate().skip(20).take(210) {
+///         assert!((v - (0.08 * k as f64).sin()).abs() < 2.0e-2);
+///     }
+/// }
+/// ```
+pub fn decimate<T: Sample>(x: &[T], q: usize, method: AntiAliasing) -> Vec<T> {
+
+    assert!(q > 0, "The decimation factor must be positive!");
+    if q == 1 {
+        return x.to_vec();
+    }
+
+    match method {
+        AntiAliasing::Chebyshev => {
+            let proto: filter::Prototype = filter::Prototype::ChebyshevI { ripple: CHEBYSHEV_RIPPLE };
+            let sos: Vec<[f64; 6]> = filter::iir_design(CHEBYSHEV_ORDER, &[0.4 / q as f64], filter::BandType::LowPass, proto, 1.0)
+                .to_sos();
+
+            // Real and imaginary parts are filtered separately
+            let z: Vec<Complex64> = x.iter().map(|v| v.to_complex()).collect();
+            let re: Vec<f64> = filter::sosfiltfilt(&sos, &z.iter().map(|v| v.re).collect::<Vec<f64>>());
+            let im: Vec<f64> = if z.iter().any(|v| v.im != 0.0) {
+                filter::sosfiltfilt(&sos, &z.iter().map(|v| v.im).collect::<Vec<f64>>())
+            } else {
+                vec![0.0; z.len()]
+            };
+
+            re.iter().zip(&im).step_by(q).map(|(r, i)| T::from_complex(Complex64::new(*r, *i))).collect()
+        },
+        AntiAliasing::Fir => {
+            let numtaps: usize = 2 * POLY_HALF_LENGTH * q + 1;
+            let win: Vec<f64> = window::hamming(numtaps, window::Symmetry::Symmetric);
+            let h: Vec<f64> = filter::firwin(numtaps, &[0.5 / q as f64], filter::BandType::LowPass, &win, 1.0);
+
+            upfirdn_centered(x, &h, 1, q)
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Upsampling, filtering by an odd length linear phase filter, and downsampling, with the filter delay removed
+fn upfirdn_centered<T: Sample>(x: &[T], h: &[f64], up: usize, down: usize) -> Vec<T> {
+
+    let half: usize = h.len() / 2;
+    let n_out: usize = (x.len() * up).div_ceil(down);
+
+    // Output m is the filtered upsampled signal at index m * down + half
+    (0..n_out).map(|m| {
+        let j: usize = m * down + half;
+        let first: usize = (j + 1).saturating_sub(h.len()).div_ceil(up);
+        let last: usize = (j / up).min(x.len().saturating_sub(1));
+        (first..=last).fold(T::default(), |acc, l| acc + x[l] * h[j - l * up])
+    }).collect()
+}
+
+/// # Greatest common divisor
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
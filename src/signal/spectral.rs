@@ -0,0 +1,736 @@
+//!
+//! # Spectral density estimation
+//! 
+//! Estimators of the [power spectral density](https://en.wikipedia.org/wiki/Spectral_density) (PSD) of sampled signals,
+//! calibrated in physical units from the sampling rate:
+//! - **Periodogram**: the squared modulus of the FFT of the (windowed) signal
+//! - **Welch**: the average of the periodograms of overlapping segments, which reduces the variance
+//! - **Multitaper**: the average of the periodograms obtained with orthogonal DPSS tapers
+//! - **Lomb-Scargle**: the least squares fit of sinusoids, for unevenly sampled signals
+//! 
+//! With the `Density` scaling, the PSD is in units of $V^2/Hz$ for a signal in $V$ sampled in $Hz$,
+//! such that its integral over the frequencies is the variance of the signal. With the `Spectrum` scaling,
+//! it is in units of $V^2$, such that a sinusoid of amplitude $A$ at a bin frequency gives a peak of $A^2/2$
+//! for one-sided spectra.
+//! 
+//! ```
+//! # use scilib::signal::spectral::{ welch, SpectralOptions };
+//! # use scilib::signal::window::{ hann, Symmetry };
+//! // Pseudo-random sequence of ±1 values, sampled at 100 Hz
+//! let data: Vec<f64> = (0..4096).map(|k| if (k * 7919) % 13 < 6 { 1.0 } else { -1.0 }).collect();
+//! let psd = welch(&data, 100.0, &hann(256, Symmetry::Periodic), 128, SpectralOptions::default());
+//! 
+//! // The integral of the density gives back the power
+//! let df: f64 = psd.frequencies[1] - psd.frequencies[0];
+//! let power: f64 = psd.values.iter().sum::<f64>() * df;
+//! assert!((power - 1.0).abs() < 0.05);
+//! ```
+//! 
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use std::f64::consts::{     // Using std lib constants
+    PI,                     // Pi
+    TAU                     // Tau
+};
+
+use super::{                // Using parts from the signal module
+    rfft,                   // Real FFT
+    window,                 // DPSS tapers
+    FftPlan,                // Plan for the fast Lomb-Scargle sums
+    Direction               // Direction of the plan
+};
+
+use crate::math::basic;     // Log gamma for the false alarm probability
+
+use num_complex::Complex64; // Using complex numbers from the num crate
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Oversampling of the grid used by the fast Lomb-Scargle periodogram
+const OVERSAMPLING: usize = 5;
+
+/// Number of grid points each value is extirpolated on
+const EXTIRPOLATION: usize = 6;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Trend removed from each segment
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Detrend {
+    /// Data used as is
+    None,
+    /// Mean removed
+    Constant,
+    /// Least squares line removed
+    Linear
+}
+
+/// # Frequencies covered by the estimate
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Sides {
+    /// Frequencies from zero to Nyquist, the power of the negative frequencies being folded on the positive ones
+    OneSided,
+    /// All frequencies, in the FFT order: zero, positive frequencies, then negative frequencies
+    TwoSided
+}
+
+/// # Normalization of the estimate
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Scaling {
+    /// Power spectral density, in units squared per unit of the sampling rate
+    Density,
+    /// Power spectrum, in units squared
+    Spectrum
+}
+
+/// # Options shared by the estimators
+/// 
+/// The default is a constant detrend, with a one-sided density.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SpectralOptions {
+    /// Trend removed from each segment
+    pub detrend: Detrend,
+    /// One-sided or two-sided output
+    pub sides: Sides,
+    /// Density or spectrum normalization
+    pub scaling: Scaling
+}
+
+impl Default for SpectralOptions {
+    fn default() -> Self {
+        Self {
+            detrend: Detrend::Constant,
+            sides: Sides::OneSided,
+            scaling: Scaling::Density
+        }
+    }
+}
+
+/// # Spectral estimate
+/// 
+/// The frequencies, in the units of the sampling rate, and the associated estimated values.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Psd {
+    /// Frequency of each bin
+    pub frequencies: Vec<f64>,
+    /// Estimated power in each bin
+    pub values: Vec<f64>
+}
+
+/// # Sinusoidal model fitted by the Lomb-Scargle periodogram
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Model {
+    /// Classic periodogram: the weighted mean is removed, and a pure sinusoid is fitted
+    Classic,
+    /// Generalized periodogram: the mean is fitted along with the sinusoid at each frequency
+    FloatingMean
+}
+
+/// # Method to estimate the false alarm probability of a Lomb-Scargle peak
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FapMethod {
+    /// Probability at a single frequency, with no look-elsewhere effect
+    Single,
+    /// Independent frequencies, their number being estimated from the baseline
+    Naive,
+    /// Davies upper bound
+    Davies,
+    /// Baluev's approximation, accurate for small probabilities
+    Baluev
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Periodogram
+/// 
+/// ## Definition
+/// The [periodogram](https://en.wikipedia.org/wiki/Periodogram) of a signal $x$ tapered by a window $w$ is:
+/// $$
+/// P(f_k) = \frac{\left|\sum_n w_nx_ne^{-2i\pi kn/N}\right|^2}{f_s\sum_n w_n^2}
+/// $$
+/// for the `Density` scaling, the denominator being $\left(\sum_n w_n\right)^2$ for the `Spectrum` scaling.
+/// 
+/// ## Inputs
+/// - `data`: the signal
+/// - `fs`: the sampling rate ($f_s$)
+/// - `window`: the window, of the same length as the signal; ones for the raw periodogram
+/// - `options`: detrend, sides and scaling of the estimate
+/// 
+/// Returns the frequencies and the estimated values.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::spectral::{ periodogram, SpectralOptions, Scaling };
+/// // Sinusoid of amplitude 2 at 10 Hz, sampled at 80 Hz
+/// let data: Vec<f64> = (0..80).map(|k| 2.0 * (std::f64::consts::TAU * 10.0 * k as f64 / 80.0).sin()).collect();
+/// let options = SpectralOptions { scaling: Scaling::Spectrum, ..SpectralOptions::default() };
+/// let psd = periodogram(&data, 80.0, &[1.0; 80], options);
+/// 
+/// assert_eq!(psd.values.len(), 41);
+/// assert!((psd.frequencies[10] - 10.0).abs() < 1.0e-12);
+/// assert!((psd.values[10] - 2.0).abs() < 1.0e-12);    // A^2 / 2
+/// 
+/// // An empty signal has an empty estimate
+/// assert!(periodogram(&[], 80.0, &[], options).values.is_empty());
+/// ```
+pub fn periodogram(data: &[f64], fs: f64, window: &[f64], options: SpectralOptions) -> Psd {
+
+    assert_eq!(data.len(), window.len(), "The window must have the length of the data!");
+
+    if data.is_empty() {
+        return Psd { frequencies: vec![], values: vec![] };
+    }
+
+    let values: Vec<f64> = modified_periodogram(data, window, fs, options);
+
+    Psd {
+        frequencies: frequencies(data.len(), fs, options.sides),
+        values
+    }
+}
+
+/// # Welch's method
+/// 
+/// ## Definition
+/// [Welch's method](https://en.wikipedia.org/wiki/Welch%27s_method) splits the signal in overlapping segments
+/// of the length of the window, computes the modified periodogram of each segment and averages them.
+/// The variance of the estimate is reduced by the number of segments, at the cost of the frequency resolution.
+/// The samples after the last complete segment are ignored.
+/// 
+/// ## Inputs
+/// - `data`: the signal
+/// - `fs`: the sampling rate ($f_s$)
+/// - `window`: the window, whose length is the length of the segments
+/// - `overlap`: the number of samples shared by consecutive segments, smaller than the segment length
+/// - `options`: detrend, sides and scaling of the estimate
+/// 
+/// Returns the frequencies and the estimated values.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::spectral::{ welch, periodogram, SpectralOptions };
+/// # use scilib::signal::window::{ hann, Symmetry };
+/// let data: Vec<f64> = (0..1000).map(|k| (0.3 * k as f64).sin() + 0.1 * (k as f64).cos()).collect();
+/// let w = hann(128, Symmetry::Periodic);
+/// 
+/// let psd = welch(&data, 1.0, &w, 64, SpectralOptions::default());
+/// assert_eq!(psd.values.len(), 65);
+/// 
+/// // A single segment is the windowed periodogram
+/// let single = welch(&data[..128], 1.0, &w, 64, SpectralOptions::default());
+/// let direct = periodogram(&data[..128], 1.0, &w, SpectralOptions::default());
+/// assert_eq!(single, direct);
+/// ```
+pub fn welch(data: &[f64], fs: f64, window: &[f64], overlap: usize, options: SpectralOptions) -> Psd {
+
+    let n_seg: usize = window.len();
+    assert!(n_seg > 0 && n_seg <= data.len(), "The segments must be shorter than the data!");
+    assert!(overlap < n_seg, "The overlap must be smaller than the segment length!");
+
+    let step: usize = n_seg - overlap;
+    let n_avg: usize = (data.len() - n_seg) / step + 1;
+
+    // Averaging the periodograms of the segments
+    let mut values: Vec<f64> = vec![0.0; frequencies(n_seg, fs, options.sides).len()];
+    for s in 0..n_avg {
+        let segment: &[f64] = &data[s * step..s * step + n_seg];
+        for (v, p) in values.iter_mut().zip(modified_periodogram(segment, window, fs, options)) {
+            *v += p / n_avg as f64;
+        }
+    }
+
+    Psd {
+        frequencies: frequencies(n_seg, fs, options.sides),
+        values
+    }
+}
+
+/// # Multitaper estimate
+/// 
+/// ## Definition
+/// The [multitaper method](https://en.wikipedia.org/wiki/Multitaper) averages the periodograms obtained
+/// with the `k` first discrete prolate spheroidal sequences of `window::dpss`, which are orthogonal
+/// and concentrated in the band $[-W, W]$ with $W = NW f_s / N$. The variance is reduced by about
+/// the number of tapers, while the resolution is the bandwidth $2W$. The usual choice is $k = 2NW - 1$.
+/// 
+/// As the tapers have unit energy, the `Spectrum` scaling returns the density multiplied by the bin width $f_s/N$.
+/// 
+/// ## Inputs
+/// - `data`: the signal
+/// - `fs`: the sampling rate ($f_s$)
+/// - `nw`: the time-bandwidth product ($NW$)
+/// - `k`: the number of tapers
+/// - `options`: detrend, sides and scaling of the estimate
+/// 
+/// Returns the frequencies and the estimated values.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::spectral::{ multitaper, SpectralOptions };
+/// // Sinusoid at 50 Hz sampled at 1 kHz, with a small constant offset
+/// let data: Vec<f64> = (0..1000).map(|k| 0.5 + (std::f64::consts::TAU * 50.0 * k as f64 / 1000.0).sin()).collect();
+/// let psd = multitaper(&data, 1000.0, 4.0, 7, SpectralOptions::default());
+/// 
+/// // The peak is found at the right frequency
+/// let peak: usize = (0..psd.values.len()).fold(0, |m, k| if psd.values[k] > psd.values[m] { k } else { m });
+/// assert!((psd.frequencies[peak] - 50.0).abs() < 1.0e-12);
+/// 
+/// // And the total power is preserved
+/// let df: f64 = psd.frequencies[1] - psd.frequencies[0];
+/// let power: f64 = psd.values.iter().sum::<f64>() * df;
+/// assert!((power - 0.5).abs() < 1.0e-3);
+/// ```
+pub fn multitaper(data: &[f64], fs: f64, nw: f64, k: usize, options: SpectralOptions) -> Psd {
+
+    assert!(k > 0, "At least one taper is required!");
+
+    let n: usize = data.len();
+    let tapers: Vec<Vec<f64>> = window::dpss(n, nw, k);
+
+    // Each taper is normalized as a density, the spectrum scaling is then the density over a bin
+    let density: SpectralOptions = SpectralOptions { scaling: Scaling::Density, ..options };
+    let mut values: Vec<f64> = vec![0.0; frequencies(n, fs, options.sides).len()];
+    for taper in &tapers {
+        for (v, p) in values.iter_mut().zip(modified_periodogram(data, taper, fs, density)) {
+            *v += p / k as f64;
+        }
+    }
+
+    if options.scaling == Scaling::Spectrum {
+        values.iter_mut().for_each(|v| *v *= fs / n as f64);
+    }
+
+    Psd {
+        frequencies: frequencies(n, fs, options.sides),
+        values
+    }
+}
+
+/// # Removing the trend of a signal
+/// 
+/// ## Inputs
+/// - `data`: the signal
+/// - `detrend`: the trend to remove
+/// 
+/// Returns the signal without its trend.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::spectral::{ detrend, Detrend };
+/// let data: Vec<f64> = (0..10).map(|k| 1.5 + 0.3 * k as f64).collect();
+/// 
+/// assert!(detrend(&data, Detrend::Linear).iter().all(|v| v.abs() < 1.0e-12));
+/// assert!(detrend(&data, Detrend::Constant).iter().sum::<f64>().abs() < 1.0e-12);
+/// assert_eq!(detrend(&data, Detrend::None), data);
+/// ```
+pub fn detrend(data: &[f64], detrend: Detrend) -> Vec<f64> {
+
+    let n: f64 = data.len() as f64;
+    let mean: f64 = data.iter().sum::<f64>() / n;
+
+    match detrend {
+        Detrend::None => data.to_vec(),
+        Detrend::Constant => data.iter().map(|v| v - mean).collect(),
+        Detrend::Linear => {
+
+            // Least squares slope, around the center of the segment
+            let center: f64 = (n - 1.0) / 2.0;
+            let (num, den): (f64, f64) = data.iter().enumerate().fold((0.0, 0.0), |(num, den), (k, v)| {
+                let t: f64 = k as f64 - center;
+                (num + t * (v - mean), den + t.powi(2))
+            });
+            let slope: f64 = if den > 0.0 { num / den } else { 0.0 };
+
+            data.iter().enumerate().map(|(k, v)| v - mean - slope * (k as f64 - center)).collect()
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Lomb-Scargle periodogram
+/// 
+/// ## Definition
+/// The [Lomb-Scargle periodogram](https://en.wikipedia.org/wiki/Least-squares_spectral_analysis) fits a sinusoid
+/// to unevenly sampled data at each frequency, by weighted least squares with weights $w_i = 1/\sigma_i^2$.
+/// The power is normalized such that:
+/// $$
+/// P(f) = 1 - \frac{\chi^2(f)}{\chi^2_\mathrm{ref}}
+/// $$
+/// where $\chi^2(f)$ is the residual of the sinusoidal fit and $\chi^2_\mathrm{ref}$ the residual of the constant model,
+/// which gives a power between 0 and 1. The `Classic` model removes the weighted mean before fitting a pure sinusoid,
+/// the `FloatingMean` model ([Zechmeister & Kürster, 2009](https://arxiv.org/abs/0901.2573)) fits the mean at each frequency.
+/// 
+/// The sums are computed directly, in $O(nm)$ for $n$ points and $m$ frequencies; see `lomb_scargle_fast` for large problems.
+/// It is well suited to search for periodic signals in photometric time series, such as transits or stellar pulsations.
+/// 
+/// ## Inputs
+/// - `t`: the times of the observations
+/// - `y`: the observations
+/// - `dy`: the uncertainties on the observations, `None` for uniform weights
+/// - `frequencies`: the frequencies to evaluate, in cycles per unit of `t` (not angular frequencies)
+/// - `model`: classic or floating mean
+/// 
+/// Returns the normalized power at each frequency.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::spectral::{ lomb_scargle, autofrequency, Model };
+/// // Irregular sampling of a signal of period 2.5
+/// let t: Vec<f64> = (0..80).map(|k| k as f64 * 0.37 + (k as f64 * 1.7).sin().abs()).collect();
+/// let y: Vec<f64> = t.iter().map(|x| 3.0 + (std::f64::consts::TAU * x / 2.5).sin()).collect();
+/// 
+/// let freq = autofrequency(&t, 10.0, 1.0);
+/// let power = lomb_scargle(&t, &y, None, &freq, Model::FloatingMean);
+/// 
+/// let best: usize = (0..power.len()).fold(0, |m, k| if power[k] > power[m] { k } else { m });
+/// assert!((1.0 / freq[best] - 2.5).abs() < 0.01);
+/// assert!(power[best] > 0.99);
+/// ```
+pub fn lomb_scargle(t: &[f64], y: &[f64], dy: Option<&[f64]>, frequencies: &[f64], model: Model) -> Vec<f64> {
+
+    let (w, y_c, yy): (Vec<f64>, Vec<f64>, f64) = lomb_scargle_setup(t, y, dy);
+    let wy: Vec<f64> = w.iter().zip(&y_c).map(|(a, b)| a * b).collect();
+
+    frequencies.iter().map(|f| {
+
+        // Direct computation of the trigonometric sums
+        let mut sums: TrigSums = TrigSums::default();
+        for ((ti, wi), wyi) in t.iter().zip(&w).zip(&wy) {
+            let (sin, cos): (f64, f64) = (TAU * f * ti).sin_cos();
+            let (sin2, cos2): (f64, f64) = (2.0 * TAU * f * ti).sin_cos();
+            sums.sh += wyi * sin;
+            sums.ch += wyi * cos;
+            sums.s += wi * sin;
+            sums.c += wi * cos;
+            sums.s2 += wi * sin2;
+            sums.c2 += wi * cos2;
+        }
+
+        sums.power(yy, model)
+    }).collect()
+}
+
+/// # Fast Lomb-Scargle periodogram
+/// 
+/// ## Definition
+/// Computes the same periodogram as `lomb_scargle` on the regular frequency grid $f_k = f_0 + k\Delta f$,
+/// in $O(n + m\log m)$ with the method of [Press & Rybicki (1989)](https://doi.org/10.1086/167197):
+/// the data are extirpolated on a regular grid with Lagrange polynomials, such that the trigonometric sums
+/// are obtained by FFTs. The result is an approximation, the error on the power being typically below $10^{-3}$.
+/// 
+/// ## Inputs
+/// - `t`: the times of the observations
+/// - `y`: the observations
+/// - `dy`: the uncertainties on the observations, `None` for uniform weights
+/// - `f0`: the first frequency of the grid ($f_0$)
+/// - `df`: the step of the grid ($\Delta f$)
+/// - `n`: the number of frequencies
+/// - `model`: classic or floating mean
+/// 
+/// Returns the normalized power at each frequency of the grid.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::spectral::{ lomb_scargle, lomb_scargle_fast, Model };
+/// let t: Vec<f64> = (0..500).map(|k| k as f64 * 0.21 + (k as f64 * 0.9).cos().powi(2)).collect();
+/// let y: Vec<f64> = t.iter().map(|x| (x * 1.3).sin() + 0.3 * (x * 0.2).cos()).collect();
+/// let dy: Vec<f64> = (0..500).map(|k| 0.1 + 0.05 * (k % 3) as f64).collect();
+/// 
+/// let (f0, df, n): (f64, f64, usize) = (0.01, 0.002, 1000);
+/// let freq: Vec<f64> = (0..n).map(|k| f0 + k as f64 * df).collect();
+/// 
+/// let exact = lomb_scargle(&t, &y, Some(&dy), &freq, Model::FloatingMean);
+/// let fast = lomb_scargle_fast(&t, &y, Some(&dy), f0, df, n, Model::FloatingMean);
+/// for (e, f) in exact.iter().zip(&fast) {
+///     assert!((e - f).abs() < 1.0e-3);
+/// }
+/// ```
+pub fn lomb_scargle_fast(t: &[f64], y: &[f64], dy: Option<&[f64]>, f0: f64, df: f64, n: usize, model: Model) -> Vec<f64> {
+
+    let (w, y_c, yy): (Vec<f64>, Vec<f64>, f64) = lomb_scargle_setup(t, y, dy);
+    let wy: Vec<f64> = w.iter().zip(&y_c).map(|(a, b)| a * b).collect();
+
+    let h: Vec<Complex64> = trig_sum(t, &wy, f0, df, n, 1.0);
+    let g: Vec<Complex64> = trig_sum(t, &w, f0, df, n, 1.0);
+    let g2: Vec<Complex64> = trig_sum(t, &w, f0, df, n, 2.0);
+
+    (0..n).map(|k| {
+        let sums: TrigSums = TrigSums {
+            sh: h[k].im,
+            ch: h[k].re,
+            s: g[k].im,
+            c: g[k].re,
+            s2: g2[k].im,
+            c2: g2[k].re
+        };
+        sums.power(yy, model)
+    }).collect()
+}
+
+/// # Automatic frequency grid
+/// 
+/// ## Definition
+/// Builds a regular frequency grid suited to the sampling of the data: the step is $1/(sT)$ for a baseline $T$
+/// and $s$ points per peak, starting at half a step, and the grid extends up to a multiple of the average
+/// Nyquist frequency $n/(2T)$. As uneven sampling can reveal frequencies above the average Nyquist frequency,
+/// factors larger than 1 are common.
+/// 
+/// ## Inputs
+/// - `t`: the times of the observations
+/// - `samples_per_peak`: the number of frequencies across a typical peak ($s$)
+/// - `nyquist_factor`: the maximum frequency, in units of the average Nyquist frequency
+/// 
+/// Returns the frequencies, in cycles per unit of `t`.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::spectral::autofrequency;
+/// let t: Vec<f64> = (0..=100).map(|k| k as f64).collect();
+/// let freq = autofrequency(&t, 5.0, 1.0);
+/// 
+/// assert!((freq[0] - 0.001).abs() < 1.0e-12);
+/// assert!((freq[1] - freq[0] - 0.002).abs() < 1.0e-12);
+/// assert!((freq.last().unwrap() - 0.505).abs() < 1.0e-12);
+/// ```
+pub fn autofrequency(t: &[f64], samples_per_peak: f64, nyquist_factor: f64) -> Vec<f64> {
+
+    let t_min: f64 = t.iter().fold(f64::MAX, |m, v| m.min(*v));
+    let t_max: f64 = t.iter().fold(f64::MIN, |m, v| m.max(*v));
+    let baseline: f64 = t_max - t_min;
+
+    let df: f64 = 1.0 / (baseline * samples_per_peak);
+    let f_min: f64 = 0.5 * df;
+    let f_max: f64 = nyquist_factor * 0.5 * t.len() as f64 / baseline;
+    let n: usize = 1 + ((f_max - f_min) / df).round() as usize;
+
+    (0..n).map(|k| f_min + k as f64 * df).collect()
+}
+
+/// # False alarm probability of a Lomb-Scargle peak
+/// 
+/// ## Definition
+/// Estimates the probability that a peak of the given normalized power, or higher, appears in the periodogram
+/// of pure Gaussian noise, when searching frequencies up to $f_\mathrm{max}$. At a single frequency, the probability is:
+/// $$
+/// P_1(z) = (1 - z)^{(N-3)/2}
+/// $$
+/// for $N$ observations. The look-elsewhere effect of searching many frequencies is accounted for by:
+/// - `Naive`: $1 - (1 - P_1)^{N_\mathrm{eff}}$ with $N_\mathrm{eff} = f_\mathrm{max}T$ independent frequencies
+/// - `Davies`: the upper bound $P_1 + \tau$, where $\tau$ is the expected number of up-crossings of $z$
+/// - `Baluev`: $1 - (1 - P_1)e^{-\tau}$, from [Baluev (2008)](https://arxiv.org/abs/0711.0330)
+/// 
+/// ## Inputs
+/// - `power`: the normalized power of the peak ($z$)
+/// - `f_max`: the maximum frequency searched ($f_\mathrm{max}$)
+/// - `t`: the times of the observations
+/// - `dy`: the uncertainties on the observations, `None` for uniform weights
+/// - `method`: the estimation method
+/// 
+/// Returns the false alarm probability.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::spectral::{ false_alarm_probability, FapMethod };
+/// let t: Vec<f64> = (0..100).map(|k| k as f64 + 0.3 * (k as f64).sin()).collect();
+/// 
+/// let single = false_alarm_probability(0.2, 0.5, &t, None, FapMethod::Single);
+/// let naive = false_alarm_probability(0.2, 0.5, &t, None, FapMethod::Naive);
+/// let baluev = false_alarm_probability(0.2, 0.5, &t, None, FapMethod::Baluev);
+/// let davies = false_alarm_probability(0.2, 0.5, &t, None, FapMethod::Davies);
+/// 
+/// // Searching many frequencies makes a peak much more likely
+/// assert!((single - 0.8_f64.powf(48.5)).abs() < 1.0e-15);
+/// assert!(single < baluev && baluev < davies);
+/// assert!(naive > single);
+/// ```
+pub fn false_alarm_probability(power: f64, f_max: f64, t: &[f64], dy: Option<&[f64]>, method: FapMethod) -> f64 {
+
+    let n: f64 = t.len() as f64;
+    let (n_h, n_k): (f64, f64) = (n - 1.0, n - 3.0);    // Degrees of freedom of the constant and sinusoidal models
+    let fap_single: f64 = (1.0 - power).powf(0.5 * n_k);
+
+    // Expected number of up-crossings, from the effective baseline
+    let tau = || -> f64 {
+        let (w, _, _): (Vec<f64>, Vec<f64>, f64) = lomb_scargle_setup(t, t, dy);
+        let mean: f64 = w.iter().zip(t).map(|(a, b)| a * b).sum();
+        let var: f64 = w.iter().zip(t).map(|(a, b)| a * (b - mean).powi(2)).sum();
+        let w_eff: f64 = f_max * (4.0 * PI * var).sqrt();
+        let gamma: f64 = (2.0 / n_h).sqrt() * (basic::ln_gamma(n_h / 2.0) - basic::ln_gamma((n_h - 1.0) / 2.0)).exp();
+        gamma * w_eff * (1.0 - power).powf(0.5 * (n_k - 1.0)) * (0.5 * n_h * power).sqrt()
+    };
+
+    match method {
+        FapMethod::Single => fap_single,
+        FapMethod::Naive => {
+            let t_min: f64 = t.iter().fold(f64::MAX, |m, v| m.min(*v));
+            let t_max: f64 = t.iter().fold(f64::MIN, |m, v| m.max(*v));
+            let n_eff: f64 = f_max * (t_max - t_min);
+            -(n_eff * (-fap_single).ln_1p()).exp_m1()
+        },
+        FapMethod::Davies => fap_single + tau(),
+        FapMethod::Baluev => 1.0 - (1.0 - fap_single) * (-tau()).exp()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Frequencies of the bins for a segment of length `n`
+fn frequencies(n: usize, fs: f64, sides: Sides) -> Vec<f64> {
+
+    let df: f64 = fs / n as f64;
+
+    match sides {
+        Sides::OneSided => (0..=n / 2).map(|k| k as f64 * df).collect(),
+        Sides::TwoSided => (0..n).map(|k| {
+            if k <= (n - 1) / 2 { k as f64 * df } else { (k as f64 - n as f64) * df }
+        }).collect()
+    }
+}
+
+/// # Periodogram of a single segment with a window
+fn modified_periodogram(data: &[f64], window: &[f64], fs: f64, options: SpectralOptions) -> Vec<f64> {
+
+    let n: usize = data.len();
+    if n == 0 {
+        return vec![];
+    }
+
+    let tapered: Vec<f64> = detrend(data, options.detrend).iter().zip(window).map(|(d, w)| d * w).collect();
+    let norm: f64 = match options.scaling {
+        Scaling::Density => fs * window.iter().map(|w| w.powi(2)).sum::<f64>(),
+        Scaling::Spectrum => window.iter().sum::<f64>().powi(2)
+    };
+
+    let half: Vec<f64> = rfft(&tapered).iter().map(|val| val.norm_sqr() / norm).collect();
+
+    match options.sides {
+        Sides::OneSided => {
+            // Folding the negative frequencies, except for the zero and Nyquist bins
+            let last: usize = if n.is_multiple_of(2) { half.len() - 1 } else { half.len() };
+            half.iter().enumerate().map(|(k, p)| if k == 0 || k == last { *p } else { 2.0 * p }).collect()
+        },
+        Sides::TwoSided => {
+            // Rebuilding the negative frequencies from the Hermitian symmetry
+            let mut full: Vec<f64> = half.clone();
+            full.extend(half[1..].iter().rev().skip(1 - n % 2));
+            full
+        }
+    }
+}
+
+/// # Trigonometric sums of the Lomb-Scargle periodogram at one frequency
+#[derive(Default)]
+struct TrigSums {
+    sh: f64,
+    ch: f64,
+    s: f64,
+    c: f64,
+    s2: f64,
+    c2: f64
+}
+
+impl TrigSums {
+
+    /// # Normalized power from the sums
+    fn power(&self, yy: f64, model: Model) -> f64 {
+
+        // Phase offset making the sine and cosine terms orthogonal
+        let tan_2wt: f64 = match model {
+            Model::Classic => self.s2 / self.c2,
+            Model::FloatingMean => (self.s2 - 2.0 * self.s * self.c) / (self.c2 - (self.c.powi(2) - self.s.powi(2)))
+        };
+        let c2w: f64 = 1.0 / (1.0 + tan_2wt.powi(2)).sqrt();
+        let s2w: f64 = tan_2wt * c2w;
+        let cw: f64 = (0.5 * (1.0 + c2w)).sqrt();
+        let sw: f64 = s2w.signum() * (0.5 * (1.0 - c2w)).sqrt();
+
+        let yc: f64 = self.ch * cw + self.sh * sw;
+        let ys: f64 = self.sh * cw - self.ch * sw;
+        let mut cc: f64 = 0.5 * (1.0 + self.c2 * c2w + self.s2 * s2w);
+        let mut ss: f64 = 0.5 * (1.0 - self.c2 * c2w - self.s2 * s2w);
+
+        if model == Model::FloatingMean {
+            cc -= (self.c * cw + self.s * sw).powi(2);
+            ss -= (self.s * cw - self.c * sw).powi(2);
+        }
+
+        (yc.powi(2) / cc + ys.powi(2) / ss) / yy
+    }
+}
+
+/// # Normalized weights, weighted-centered data and their weighted variance
+fn lomb_scargle_setup(t: &[f64], y: &[f64], dy: Option<&[f64]>) -> (Vec<f64>, Vec<f64>, f64) {
+
+    assert_eq!(t.len(), y.len(), "There must be as many times as observations!");
+
+    let mut w: Vec<f64> = match dy {
+        Some(err) => {
+            assert_eq!(err.len(), y.len(), "There must be as many uncertainties as observations!");
+            err.iter().map(|e| e.powi(-2)).collect()
+        },
+        None => vec![1.0; y.len()]
+    };
+    let total: f64 = w.iter().sum();
+    w.iter_mut().for_each(|v| *v /= total);
+
+    let mean: f64 = w.iter().zip(y).map(|(a, b)| a * b).sum();
+    let y_c: Vec<f64> = y.iter().map(|v| v - mean).collect();
+    let yy: f64 = w.iter().zip(&y_c).map(|(a, b)| a * b.powi(2)).sum();
+
+    (w, y_c, yy)
+}
+
+/// # Trigonometric sums on a regular frequency grid
+/// 
+/// Computes $\sum_i h_i e^{2i\pi f_k t_i}$ for $f_k = a(f_0 + k\Delta f)$, by extirpolating the data on
+/// a regular grid whose transform gives all the sums at once.
+fn trig_sum(t: &[f64], h: &[f64], f0: f64, df: f64, n: usize, factor: f64) -> Vec<Complex64> {
+
+    let (f0, df): (f64, f64) = (factor * f0, factor * df);
+    let t0: f64 = t.iter().fold(f64::MAX, |m, v| m.min(*v));
+    let n_fft: usize = (n * OVERSAMPLING).next_power_of_two();
+
+    // The first frequency is brought to zero by a modulation
+    let mut grid: Vec<Complex64> = vec![Complex64::default(); n_fft];
+    for (ti, hi) in t.iter().zip(h) {
+        let val: Complex64 = Complex64::from_polar(*hi, TAU * f0 * (ti - t0));
+        let x: f64 = ((ti - t0) * df).fract() * n_fft as f64;
+        extirpolate(&mut grid, x, val);
+    }
+
+    FftPlan::new(n_fft, Direction::Inverse).process(&mut grid);
+
+    (0..n).map(|k| grid[k] * n_fft as f64 * Complex64::from_polar(1.0, TAU * t0 * (f0 + k as f64 * df))).collect()
+}
+
+/// # Spreading a value at a non integer position on the neighbouring points of a grid
+/// 
+/// Uses the Lagrange interpolation weights on `EXTIRPOLATION` points, such that the grid
+/// reproduces the sums of trigonometric functions of the position.
+fn extirpolate(grid: &mut [Complex64], x: f64, val: Complex64) {
+
+    let n: usize = grid.len();
+
+    if x.fract() == 0.0 {
+        grid[x as usize % n] += val;
+        return;
+    }
+
+    let m: usize = EXTIRPOLATION.min(n);
+    let i_lo: usize = ((x - (m / 2) as f64).max(0.0) as usize).min(n - m);
+
+    // Product of the distances to all the points, divided by each distance and the Lagrange denominator
+    let num: f64 = (0..m).map(|j| x - (i_lo + j) as f64).product();
+    let mut den: f64 = (1..m).map(|j| j as f64).product();
+    for j in 0..m {
+        if j > 0 {
+            den *= j as f64 / (j as f64 - m as f64);
+        }
+        let idx: usize = i_lo + m - 1 - j;
+        grid[idx] += val * num / (den * (x - idx as f64));
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
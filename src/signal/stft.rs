@@ -0,0 +1,499 @@
+//!
+//! # Short-time Fourier transform
+//! 
+//! Time-frequency analysis with the [short-time Fourier transform](https://en.wikipedia.org/wiki/Short-time_Fourier_transform):
+//! the signal is cut in windowed segments, shifted by a hop of a few samples, and each segment is transformed.
+//! - `stft` and `istft`: forward and inverse transforms, with boundary extension and padding
+//! - `check_cola` and `check_nola`: conditions on the window and hop for the reconstruction
+//! - `spectrogram`: power or magnitude of the segments on a time-frequency grid
+//! 
+//! The signal is exactly recovered by `istft` when the window and hop satisfy the non-zero overlap-add (NOLA)
+//! condition. With the stronger constant overlap-add (COLA) condition, modifications of the transform are also
+//! spread evenly across the segments.
+//! 
+//! ```
+//! # use scilib::signal::stft::{ stft, istft, check_cola, StftOptions };
+//! # use scilib::signal::window::{ hann, Symmetry };
+//! let x: Vec<f64> = (0..1000).map(|k| (0.05 * k as f64).sin() * (0.002 * k as f64).exp()).collect();
+//! let w = hann(128, Symmetry::Periodic);
+//! assert!(check_cola(&w, 32));
+//! 
+//! let transform = stft(&x, 1.0, &w, 32, StftOptions::default());
+//! let y = istft(&transform.values, &w, 32, StftOptions::default());
+//! for (a, b) in x.iter().zip(&y) {
+//!     assert!((a - b).abs() < 1.0e-12);
+//! }
+//! ```
+//! 
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use super::{                // Using the parent module
+    fft,                    // Forward transform
+    rfft,                   // Forward transform of real signals
+    ifft,                   // Inverse transform
+    irfft,                  // Inverse transform of real signals
+    spectral::{             // Spectral options
+        detrend,            // Detrending of the segments
+        Scaling,            // Density or spectrum
+        Sides,              // One or two sided
+        SpectralOptions     // Options of the spectrogram
+    }
+};
+
+use num_complex::Complex64; // Using complex numbers from the num crate
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Tolerance of the COLA and NOLA checks
+const OVERLAP_TOLERANCE: f64 = 1.0e-10;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Extension of the signal at its edges
+/// 
+/// The signal is extended by half a segment on both sides, such that the first and last samples are at the
+/// center of a segment.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Boundary {
+    /// No extension, the first segment starts at the first sample
+    None,
+    /// Extension with zeros
+    Zeros,
+    /// Even reflection around the edge samples
+    Even,
+    /// Odd reflection around the edge samples
+    Odd,
+    /// Repetition of the edge samples
+    Constant
+}
+
+/// # Options of the short-time Fourier transform
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StftOptions {
+    /// Length of the transform of each segment, at least the window length; the window length if `None`
+    pub nfft: Option<usize>,
+    /// Extension of the signal at its edges
+    pub boundary: Boundary,
+    /// Zero padding of the end of the signal, such that the last samples fill a complete segment
+    pub padded: bool,
+    /// Only the non-negative frequencies, or all of them
+    pub sides: Sides
+}
+
+impl Default for StftOptions {
+    fn default() -> Self {
+        Self {
+            nfft: None,
+            boundary: Boundary::Zeros,
+            padded: true,
+            sides: Sides::OneSided
+        }
+    }
+}
+
+/// # Short-time Fourier transform of a signal
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Stft {
+    /// Frequencies of the bins, in the units of the sampling rate
+    pub frequencies: Vec<f64>,
+    /// Times of the centers of the segments
+    pub times: Vec<f64>,
+    /// Transform of each segment, indexed as `values[time][frequency]`
+    pub values: Vec<Vec<Complex64>>
+}
+
+/// # Quantity of the spectrogram
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SpectrogramMode {
+    /// Power of each segment, scaled as a periodogram
+    Power,
+    /// Magnitude of the transform of each segment, normalized by the sum of the window
+    Magnitude
+}
+
+/// # Spectrogram of a signal
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Spectrogram {
+    /// Frequencies of the bins, in the units of the sampling rate
+    pub frequencies: Vec<f64>,
+    /// Times of the centers of the segments
+    pub times: Vec<f64>,
+    /// Power or magnitude of each segment, indexed as `values[time][frequency]`
+    pub values: Vec<Vec<f64>>
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Short-time Fourier transform
+/// 
+/// ## Definition
+/// Computes the transform of the windowed segments of the signal, starting every $h$ samples:
+/// $$
+/// X_m(k) = \frac{1}{\sum_n w_n}\sum_{n=0}^{M-1} w_nx_{n+mh}e^{-2i\pi kn/N}
+/// $$
+/// where $M$ is the window length and $N\geq M$ the transform length. With the normalization by the sum of the
+/// window, a sinusoid of amplitude $A$ on a bin has a magnitude of $A/2$.
+/// 
+/// ## Inputs
+/// - `data`: the signal
+/// - `fs`: the sampling rate
+/// - `window`: the window, from any generator of the `signal::window` module, whose length is the segment length
+/// - `hop`: the number of samples between the starts of consecutive segments ($h$), at most the window length
+/// - `options`: transform length, boundary extension, padding and sides
+/// 
+/// Returns the frequencies, the times of the centers of the segments, and the transforms.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::stft::{ stft, Boundary, StftOptions };
+/// # use scilib::signal::window::{ hann, Symmetry };
+/// // A tone at 100 Hz, on a bin, sampled at 1 kHz
+/// let x: Vec<f64> = (0..2000).map(|k| 2.0 * (std::f64::consts::TAU * 100.0 * k as f64 / 1000.0).cos()).collect();
+/// 
+/// let options = StftOptions { boundary: Boundary::None, padded: false, ..StftOptions::default() };
+/// let res = stft(&x, 1000.0, &hann(100, Symmetry::Periodic), 50, options);
+/// assert_eq!(res.values.len(), 39);
+/// assert_eq!(res.frequencies[10], 100.0);
+/// assert_eq!(res.times[0], 0.05);
+/// assert!(res.values.iter().all(|frame| (frame[10].norm() - 1.0).abs() < 1.0e-12));
+/// 
+/// // An empty signal has no segment
+/// assert!(stft(&[], 1000.0, &hann(100, Symmetry::Periodic), 50, StftOptions::default()).values.is_empty());
+/// ```
+pub fn stft(data: &[f64], fs: f64, window: &[f64], hop: usize, options: StftOptions) -> Stft {
+
+    let nperseg: usize = window.len();
+    let nfft: usize = options.nfft.unwrap_or(nperseg);
+    assert!(nperseg > 0, "The window cannot be empty!");
+    assert!(hop > 0 && hop <= nperseg, "The hop must be between 1 and the window length!");
+    assert!(nfft >= nperseg, "The transform length must be at least the window length!");
+
+    if data.is_empty() {
+        return Stft::default();
+    }
+
+    let x: Vec<f64> = extend(data, nperseg, hop, options);
+    let norm: f64 = window.iter().sum::<f64>();
+
+    // Segment starts, and times of their centers
+    let starts: Vec<usize> = (0..).map(|m| m * hop).take_while(|s| s + nperseg <= x.len()).collect();
+    let offset: f64 = if options.boundary == Boundary::None { 0.0 } else { (nperseg / 2) as f64 };
+    let times: Vec<f64> = starts.iter().map(|s| (*s as f64 + nperseg as f64 / 2.0 - offset) / fs).collect();
+
+    let values: Vec<Vec<Complex64>> = starts.iter().map(|s| {
+        let mut segment: Vec<f64> = x[*s..*s + nperseg].iter().zip(window).map(|(v, w)| v * w / norm).collect();
+        segment.resize(nfft, 0.0);
+        match options.sides {
+            Sides::OneSided => rfft(&segment),
+            Sides::TwoSided => fft(&segment)
+        }
+    }).collect();
+
+    Stft {
+        frequencies: bin_frequencies(nfft, fs, options.sides),
+        times,
+        values
+    }
+}
+
+/// # Inverse short-time Fourier transform
+/// 
+/// ## Definition
+/// Rebuilds the signal by the weighted overlap-add of the inverse transforms of the segments:
+/// $$
+/// x_n = \frac{\sum_m w_{n-mh}y_m(n - mh)}{\sum_m w^2_{n-mh}}
+/// $$
+/// where $y_m$ is the inverse transform of the segment $m$, scaled back by the sum of the window. The
+/// reconstruction is exact for an unmodified transform if the window and hop satisfy the NOLA condition.
+/// 
+/// The options must be the ones used for the forward transform. The boundary extension is removed, but
+/// not the zero padding at the end of the signal.
+/// 
+/// ## Inputs
+/// - `values`: the transforms of the segments, indexed as `values[time][frequency]`
+/// - `window`: the window of the forward transform
+/// - `hop`: the hop of the forward transform
+/// - `options`: the options of the forward transform
+/// 
+/// Returns the signal.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::stft::{ stft, istft, check_nola, check_cola, Boundary, StftOptions };
+/// # use scilib::signal::window::{ hamming, Symmetry };
+/// let x: Vec<f64> = (0..300).map(|k| ((k * k) % 17) as f64 - 8.0).collect();
+/// let w = hamming(64, Symmetry::Symmetric);
+/// 
+/// // Not COLA, but NOLA: the reconstruction is still exact
+/// assert!(!check_cola(&w, 20));
+/// assert!(check_nola(&w, 20));
+/// 
+/// let options = StftOptions { nfft: Some(100), boundary: Boundary::Even, ..StftOptions::default() };
+/// let res = stft(&x, 1.0, &w, 20, options);
+/// let y = istft(&res.values, &w, 20, options);
+/// assert!(y.len() >= x.len());
+/// for (a, b) in x.iter().zip(&y) {
+///     assert!((a - b).abs() < 1.0e-12);
+/// }
+/// ```
+pub fn istft(values: &[Vec<Complex64>], window: &[f64], hop: usize, options: StftOptions) -> Vec<f64> {
+
+    let nperseg: usize = window.len();
+    let nfft: usize = options.nfft.unwrap_or(nperseg);
+    assert!(check_nola(window, hop), "The window and hop do not satisfy the NOLA condition!");
+
+    if values.is_empty() {
+        return vec![];
+    }
+
+    let norm: f64 = window.iter().sum::<f64>();
+    let length: usize = nperseg + (values.len() - 1) * hop;
+    let mut x: Vec<f64> = vec![0.0; length];
+    let mut weights: Vec<f64> = vec![0.0; length];
+
+    for (m, frame) in values.iter().enumerate() {
+        let segment: Vec<f64> = match options.sides {
+            Sides::OneSided => irfft(frame, nfft),
+            Sides::TwoSided => ifft(frame).iter().map(|v| v.re).collect()
+        };
+        for (k, w) in window.iter().enumerate() {
+            x[m * hop + k] += segment[k] * norm * w;
+            weights[m * hop + k] += w * w;
+        }
+    }
+
+    // Removing the boundary extension
+    let (start, end): (usize, usize) = match options.boundary {
+        Boundary::None => (0, length),
+        _ => (nperseg / 2, length.saturating_sub(nperseg / 2).max(nperseg / 2))
+    };
+
+    x[start..end].iter().zip(&weights[start..end]).map(|(v, w)| {
+        if *w > OVERLAP_TOLERANCE { v / w } else { *v }
+    }).collect()
+}
+
+/// # Constant overlap-add condition
+/// 
+/// ## Definition
+/// Checks that the sum of the window shifted by multiples of the hop is constant:
+/// $$
+/// \sum_m w_{n - mh} = C
+/// $$
+/// 
+/// ## Inputs
+/// - `window`: the window
+/// - `hop`: the hop ($h$)
+/// 
+/// Returns `true` if the condition is satisfied.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::stft::check_cola;
+/// # use scilib::signal::window::{ hann, Symmetry };
+/// assert!(check_cola(&hann(64, Symmetry::Periodic), 32));
+/// assert!(check_cola(&hann(64, Symmetry::Periodic), 16));
+/// assert!(!check_cola(&hann(64, Symmetry::Symmetric), 32));
+/// assert!(check_cola(&[1.0; 10], 10));
+/// ```
+pub fn check_cola(window: &[f64], hop: usize) -> bool {
+
+    let sums: Vec<f64> = overlap_sums(window, hop, |w| w);
+    let mut sorted: Vec<f64> = sums.clone();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let median: f64 = sorted[sorted.len() / 2];
+
+    sums.iter().all(|s| (s - median).abs() < OVERLAP_TOLERANCE)
+}
+
+/// # Non-zero overlap-add condition
+/// 
+/// ## Definition
+/// Checks that the sum of the squared window shifted by multiples of the hop never vanishes:
+/// $$
+/// \sum_m w^2_{n - mh} > 0
+/// $$
+/// 
+/// ## Inputs
+/// - `window`: the window
+/// - `hop`: the hop ($h$)
+/// 
+/// Returns `true` if the condition is satisfied.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::stft::check_nola;
+/// # use scilib::signal::window::{ hann, Symmetry };
+/// assert!(check_nola(&hann(64, Symmetry::Symmetric), 32));
+/// assert!(check_nola(&hann(64, Symmetry::Periodic), 63));
+/// 
+/// // The zeros at the ends of the window are never covered
+/// assert!(!check_nola(&hann(64, Symmetry::Periodic), 64));
+/// assert!(!check_nola(&hann(64, Symmetry::Symmetric), 63));
+/// ```
+pub fn check_nola(window: &[f64], hop: usize) -> bool {
+    overlap_sums(window, hop, |w| w * w).iter().all(|s| *s > OVERLAP_TOLERANCE)
+}
+
+/// # Spectrogram
+/// 
+/// ## Definition
+/// Computes the power or magnitude of the segments of the signal, on a time-frequency grid. The segments
+/// start every $h$ samples, without extension or padding of the signal. In the `Power` mode, each segment is
+/// detrended and scaled as a modified periodogram, following the options of the `signal::spectral` module.
+/// In the `Magnitude` mode, the values are the magnitudes of the short-time Fourier transform.
+/// 
+/// ## Inputs
+/// - `data`: the signal
+/// - `fs`: the sampling rate
+/// - `window`: the window, whose length is the segment length
+/// - `hop`: the number of samples between the starts of consecutive segments ($h$)
+/// - `mode`: power or magnitude
+/// - `options`: detrend, sides and scaling of the segments
+/// 
+/// Returns the frequencies, the times of the centers of the segments, and the values.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::stft::{ spectrogram, SpectrogramMode };
+/// # use scilib::signal::spectral::{ periodogram, SpectralOptions };
+/// # use scilib::signal::window::{ hann, Symmetry };
+/// // Chirp with a frequency increasing from 50 to 250 Hz in one second
+/// let fs: f64 = 1000.0;
+/// let x: Vec<f64> = (0..1000).map(|k| {
+///     let t: f64 = k as f64 / fs;
+///     (std::f64::consts::TAU * (50.0 * t + 100.0 * t * t)).sin()
+/// }).collect();
+/// 
+/// let w = hann(128, Symmetry::Periodic);
+/// let spec = spectrogram(&x, fs, &w, 64, SpectrogramMode::Power, SpectralOptions::default());
+/// assert_eq!(spec.values.len(), 14);
+/// assert_eq!(spec.values[0].len(), 65);
+/// 
+/// // The frequency of the maximum follows the chirp
+/// for (t, frame) in spec.times.iter().zip(&spec.values) {
+///     let peak: usize = (0..frame.len()).fold(0, |m, k| if frame[k] > frame[m] { k } else { m });
+///     assert!((spec.frequencies[peak] - (50.0 + 200.0 * t)).abs() < 10.0);
+/// }
+/// 
+/// // Each frame is the periodogram of the segment
+/// let first = periodogram(&x[..128], fs, &w, SpectralOptions::default());
+/// for (a, b) in spec.values[0].iter().zip(&first.values) {
+///     assert!((a - b).abs() < 1.0e-12 * b.abs().max(1.0e-12));
+/// }
+/// ```
+pub fn spectrogram(data: &[f64], fs: f64, window: &[f64], hop: usize, mode: SpectrogramMode, options: SpectralOptions) -> Spectrogram {
+
+    let nperseg: usize = window.len();
+    let stft_options: StftOptions = StftOptions {
+        nfft: None,
+        boundary: Boundary::None,
+        padded: false,
+        sides: options.sides
+    };
+
+    let (values, times): (Vec<Vec<f64>>, Vec<f64>) = match mode {
+        SpectrogramMode::Magnitude => {
+            let res: Stft = stft(data, fs, window, hop, stft_options);
+            (res.values.iter().map(|frame| frame.iter().map(|v| v.norm()).collect()).collect(), res.times)
+        },
+        SpectrogramMode::Power => {
+            let sum: f64 = window.iter().sum::<f64>();
+            let norm: f64 = match options.scaling {
+                Scaling::Density => sum.powi(2) / (fs * window.iter().map(|w| w * w).sum::<f64>()),
+                Scaling::Spectrum => 1.0
+            };
+
+            // Transforms of the detrended segments, scaled as periodograms
+            let starts: Vec<usize> = (0..).map(|m| m * hop).take_while(|s| s + nperseg <= data.len()).collect();
+            let values: Vec<Vec<f64>> = starts.iter().map(|s| {
+                let segment: Vec<f64> = detrend(&data[*s..*s + nperseg], options.detrend);
+                let frame: Vec<Complex64> = stft(&segment, fs, window, nperseg, stft_options).values.remove(0);
+                let last: usize = if nperseg.is_multiple_of(2) { frame.len() - 1 } else { frame.len() };
+                frame.iter().enumerate().map(|(k, v)| {
+                    let fold: f64 = if options.sides == Sides::TwoSided || k == 0 || k == last { 1.0 } else { 2.0 };
+                    fold * norm * v.norm_sqr()
+                }).collect()
+            }).collect();
+            let times: Vec<f64> = starts.iter().map(|s| (*s as f64 + nperseg as f64 / 2.0) / fs).collect();
+
+            (values, times)
+        }
+    };
+
+    Spectrogram {
+        frequencies: bin_frequencies(nperseg, fs, options.sides),
+        times,
+        values
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Boundary extension and padding of the signal
+fn extend(data: &[f64], nperseg: usize, hop: usize, options: StftOptions) -> Vec<f64> {
+
+    let half: usize = nperseg / 2;
+    let n: usize = data.len();
+
+    let mut x: Vec<f64> = match options.boundary {
+        Boundary::None => data.to_vec(),
+        _ => {
+            if matches!(options.boundary, Boundary::Even | Boundary::Odd) {
+                assert!(n > half, "The signal is too short for the reflection at the boundaries!");
+            }
+            let (first, last): (f64, f64) = (data[0], data[n - 1]);
+            let (left, right): (Vec<f64>, Vec<f64>) = match options.boundary {
+                Boundary::Zeros => (vec![0.0; half], vec![0.0; half]),
+                Boundary::Constant => (vec![first; half], vec![last; half]),
+                Boundary::Even => (
+                    (1..=half).rev().map(|k| data[k]).collect(),
+                    (1..=half).map(|k| data[n - 1 - k]).collect()
+                ),
+                _ => (
+                    (1..=half).rev().map(|k| 2.0 * first - data[k]).collect(),
+                    (1..=half).map(|k| 2.0 * last - data[n - 1 - k]).collect()
+                )
+            };
+            [left, data.to_vec(), right].concat()
+        }
+    };
+
+    // Zero padding to complete the last segment
+    if options.padded {
+        let total: usize = if x.len() <= nperseg { nperseg } else { nperseg + (x.len() - nperseg).div_ceil(hop) * hop };
+        x.resize(total, 0.0);
+    }
+
+    x
+}
+
+/// # Sums of a function of the window, shifted by multiples of the hop, over one hop
+fn overlap_sums<F>(window: &[f64], hop: usize, f: F) -> Vec<f64>
+where F: Fn(f64) -> f64 {
+
+    assert!(hop > 0 && hop <= window.len(), "The hop must be between 1 and the window length!");
+
+    let mut sums: Vec<f64> = vec![0.0; hop];
+    for (k, w) in window.iter().enumerate() {
+        sums[k % hop] += f(*w);
+    }
+
+    sums
+}
+
+/// # Frequencies of the bins of a transform
+fn bin_frequencies(n: usize, fs: f64, sides: Sides) -> Vec<f64> {
+
+    let df: f64 = fs / n as f64;
+
+    match sides {
+        Sides::OneSided => (0..=n / 2).map(|k| k as f64 * df).collect(),
+        Sides::TwoSided => (0..n).map(|k| {
+            if k <= (n - 1) / 2 { k as f64 * df } else { (k as f64 - n as f64) * df }
+        }).collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,930 @@
+//!
+//! # Wavelet transforms
+//! 
+//! The [discrete wavelet transform](https://en.wikipedia.org/wiki/Discrete_wavelet_transform) (DWT) of a signal
+//! with orthogonal wavelets, in one or multiple levels, along with its inverse and wavelet denoising by
+//! thresholding of the detail coefficients. The
+//! [continuous wavelet transform](https://en.wikipedia.org/wiki/Continuous_wavelet_transform) (CWT) gives the
+//! time-frequency representation of a signal with the Morlet, Paul and derivative of Gaussian wavelets.
+//! 
+//! The available orthogonal families are:
+//! - **Haar**: the shortest wavelet, identical to the first Daubechies wavelet
+//! - **Daubechies**: orders 1 to 10, with the most vanishing moments for their length
+//! - **Symlet**: orders 2 to 10, the same moments as the Daubechies wavelets, but closer to symmetric
+//! - **Coiflet**: orders 1 to 5, with vanishing moments for both the wavelet and the scaling function
+//! 
+//! ```
+//! # use scilib::signal::wavelet::{ wavedec, waverec, Extension, Wavelet };
+//! let x: Vec<f64> = (0..100).map(|k| (k as f64 / 7.0).sin() + (k % 9) as f64 / 4.0).collect();
+//! let coefs = wavedec(&x, Wavelet::Daubechies(4), Extension::Symmetric, 3);
+//! assert_eq!(coefs.len(), 4);
+//! 
+//! // Perfect reconstruction
+//! let y = waverec(&coefs, Wavelet::Daubechies(4), Extension::Symmetric);
+//! for (a, b) in y.iter().zip(&x) {
+//!     assert!((a - b).abs() < 1.0e-12);
+//! }
+//! ```
+//! 
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use std::f64::consts::{     // Using std lib constants
+    FRAC_1_SQRT_2,          // Inverse of the square root of two
+    PI,                     // Pi
+    SQRT_2                  // Square root of two
+};
+
+use super::{                // Using the parent module
+    fft_convolve_complex,   // Convolution of complex vectors
+    Mode                    // Size of the convolution
+};
+
+use crate::math::polynomial::Poly;  // Hermite polynomials
+
+use num_complex::Complex64; // Using complex numbers from the num crate
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Value of the median absolute deviation of a standard normal distribution
+const MAD_NORMAL: f64 = 0.6744897501960817;
+
+/// Half-width of the Gaussian wavelets, in units of the scale
+const GAUSSIAN_SUPPORT: f64 = 8.0;
+
+/// Relative amplitude at which the Paul wavelets are truncated
+const PAUL_TRUNCATION: f64 = 1.0e-8;
+
+/// Scaling filter of the Daubechies wavelet of order 1
+const DB1: [f64; 2] = [
+    FRAC_1_SQRT_2, FRAC_1_SQRT_2,
+];
+
+/// Scaling filter of the Daubechies wavelet of order 2
+const DB2: [f64; 4] = [
+    0.48296291314453416, 0.8365163037378079, 0.2241438680420134, -0.12940952255126037,
+];
+
+/// Scaling filter of the Daubechies wavelet of order 3
+const DB3: [f64; 6] = [
+    0.33267055295008263, 0.8068915093110925, 0.45987750211849154, -0.13501102001025458, -0.08544127388202666,
+    0.03522629188570953,
+];
+
+/// Scaling filter of the Daubechies wavelet of order 4
+const DB4: [f64; 8] = [
+    0.2303778133088965, 0.7148465705529157, 0.6308807679298589, -0.027983769416859854, -0.18703481171909309,
+    0.030841381835560764, 0.0328830116668852, -0.010597401785069032,
+];
+
+/// Scaling filter of the Daubechies wavelet of order 5
+const DB5: [f64; 10] = [
+    0.16010239797419293, 0.6038292697971896, 0.7243085284377729, 0.13842814590132074, -0.24229488706638203,
+    -0.032244869584638375, 0.07757149384004572, -0.006241490212798274, -0.012580751999081999, 0.0033357252854737712,
+];
+
+/// Scaling filter of the Daubechies wavelet of order 6
+const DB6: [f64; 12] = [
+    0.11154074335010947, 0.49462389039845306, 0.7511339080210954, 0.31525035170919763, -0.22626469396543983,
+    -0.12976686756726194, 0.09750160558732304, 0.027522865530305727, -0.03158203931748603, 0.0005538422011614961,
+    0.004777257510945511, -0.0010773010853084796,
+];
+
+/// Scaling filter of the Daubechies wavelet of order 7
+const DB7: [f64; 14] = [
+    0.07785205408500918, 0.3965393194819173, 0.7291320908462351, 0.4697822874051931, -0.14390600392856498,
+    -0.22403618499387498, 0.07130921926683026, 0.08061260915108308, -0.03802993693501441, -0.01657454163066688,
+    0.01255099855609984, 0.0004295779729213665, -0.0018016407040474908, 0.00035371379997452024,
+];
+
+/// Scaling filter of the Daubechies wavelet of order 8
+const DB8: [f64; 16] = [
+    0.05441584224310401, 0.31287159091429995, 0.6756307362972898, 0.5853546836542067, -0.015829105256349306,
+    -0.2840155429615469, 0.0004724845739132828, 0.12874742662047847, -0.017369301001807547, -0.044088253930794755,
+    0.013981027917398282, 0.008746094047405777, -0.004870352993451574, -0.00039174037337694705, 0.0006754494064505693,
+    -0.00011747678412476953,
+];
+
+/// Scaling filter of the Daubechies wavelet of order 9
+const DB9: [f64; 18] = [
+    0.038077947363878345, 0.24383467461259034, 0.6048231236901112, 0.6572880780513005, 0.13319738582500756,
+    -0.2932737832791749, -0.09684078322297646, 0.14854074933810638, 0.03072568147933338, -0.06763282906132997,
+    0.00025094711483145197, 0.022361662123679096, -0.004723204757751397, -0.00428150368246343, 0.0018476468830562265,
+    0.00023038576352319597, -0.0002519631889427101, 3.93473203162716e-05,
+];
+
+/// Scaling filter of the Daubechies wavelet of order 10
+const DB10: [f64; 20] = [
+    0.026670057900555554, 0.1881768000776915, 0.5272011889317256, 0.6884590394536035, 0.2811723436605775,
+    -0.24984642432731538, -0.19594627437737705, 0.12736934033579325, 0.09305736460357235, -0.07139414716639708,
+    -0.029457536821875813, 0.033212674059341, 0.0036065535669561697, -0.010733175483330575, 0.001395351747052901,
+    0.001992405295185056, -0.0006858566949597116, -0.00011646685512928545, 9.358867032006959e-05,
+    -1.3264202894521244e-05,
+];
+
+/// Scaling filter of the Symlet of order 2
+const SYM2: [f64; 4] = [
+    0.48296291314453416, 0.8365163037378079, 0.2241438680420134, -0.12940952255126037,
+];
+
+/// Scaling filter of the Symlet of order 3
+const SYM3: [f64; 6] = [
+    0.33267055295008263, 0.8068915093110925, 0.45987750211849154, -0.13501102001025458, -0.08544127388202666,
+    0.03522629188570953,
+];
+
+/// Scaling filter of the Symlet of order 4
+const SYM4: [f64; 8] = [
+    0.032223100604051466, -0.012603967262031304, -0.09921954357663353, 0.29785779560530606, 0.8037387518051321,
+    0.497618667632775, -0.029635527646002493, -0.07576571478950221,
+];
+
+/// Scaling filter of the Symlet of order 5
+const SYM5: [f64; 10] = [
+    0.027333068344998768, 0.02951949092570626, -0.039134249302313844, 0.19939753397685558, 0.7234076904040407,
+    0.633978963456792, 0.01660210576451085, -0.17532808990805623, -0.021101834024689042, 0.019538882735249827,
+];
+
+/// Scaling filter of the Symlet of order 6
+const SYM6: [f64; 12] = [
+    0.015404109327044824, 0.0034907120842221626, -0.11799011114852002, -0.04831174258569806, 0.49105594192797375,
+    0.787641141028651, 0.3379294217281658, -0.07263752278637658, -0.02106029251237085, 0.04472490177078139,
+    0.0017677118642540077, -0.00780070832503238,
+];
+
+/// Scaling filter of the Symlet of order 7
+const SYM7: [f64; 14] = [
+    0.012015419283549189, 0.017213376300804502, -0.06490800354718848, -0.06413128980738582, 0.3602184609062602,
+    0.7819215932917282, 0.4836109156822677, -0.05680447688966697, -0.1010109208684203, 0.04474234946835238,
+    0.020464207577546033, -0.01812660513133846, -0.003283297847466811, 0.0022918339540537714,
+];
+
+/// Scaling filter of the Symlet of order 8
+const SYM8: [f64; 16] = [
+    0.001889950332767689, -0.0003029205147241331, -0.014952258337062199, 0.0038087520138944896, 0.04913717967373029,
+    -0.027219029917103486, -0.0519458381078818, 0.36444189483617895, 0.777185751699628, 0.4813596512590534,
+    -0.061273359067811076, -0.14329423835127267, 0.007607487324976609, 0.03169508781152599, -0.0005421323318000107,
+    -0.0033824159510050028,
+];
+
+/// Scaling filter of the Symlet of order 9
+const SYM9: [f64; 18] = [
+    0.001069490032908612, -0.00047315449868004354, -0.010264064027633121, 0.008859267493400267, 0.062077789302885746,
+    -0.018233770779395506, -0.19155083129728434, 0.03527248803527104, 0.6173384491409342, 0.7178970827644124,
+    0.23876091460730517, -0.05456895843083335, 0.0005834627461249819, 0.030224878858275187, -0.011528210207679187,
+    -0.013271967781817134, 0.0006197808889855071, 0.0014009155259146562,
+];
+
+/// Scaling filter of the Symlet of order 10
+const SYM10: [f64; 20] = [
+    0.0008625782262259724, 0.0007154205420543397, -0.007056764062587304, 0.0005956827837425191, 0.04968612664694288,
+    0.026240365058448987, -0.12155210554854895, -0.015019238839137859, 0.5137098733480263, 0.7669548365606096,
+    0.34021601302346216, -0.08787871151197514, -0.0670899078083818, 0.03384235466357522, -0.0008687521096892581,
+    -0.02300546135349751, -0.0011404297952173285, 0.005071649198531799, 0.00034014926631480987,
+    -0.0004101159158043983,
+];
+
+/// Scaling filter of the Coiflet of order 1
+const COIF1: [f64; 6] = [
+    -0.07273261951252645, 0.33789766245748176, 0.8525720202116004, 0.3848648468648577, -0.07273261951252645,
+    -0.015655728135791993,
+];
+
+/// Scaling filter of the Coiflet of order 2
+const COIF2: [f64; 12] = [
+    0.01638733646320364, -0.04146493678687178, -0.0673725547237256, 0.38611006682276283, 0.8127236354494135,
+    0.41700518442323903, -0.07648859907828076, -0.059434418646431085, 0.02368017194684777, 0.005611434819368834,
+    -0.001823208870911032, -0.000720549445520347,
+];
+
+/// Scaling filter of the Coiflet of order 3
+const COIF3: [f64; 18] = [
+    -0.0037935128643808015, 0.0077825964256727454, 0.023452696142077165, -0.06577191128146936, -0.06112339000297254,
+    0.4051769024091182, 0.7937772226260872, 0.42848347637737, -0.07179982161915484, -0.08230192710629981,
+    0.03455502757329773, 0.015880544863669452, -0.009007976136730624, -0.002574517688136797, 0.0011175187708306303,
+    0.0004662169598204029, -7.0983302506379e-05, -3.4599773197272774e-05,
+];
+
+/// Scaling filter of the Coiflet of order 4
+const COIF4: [f64; 24] = [
+    0.000892313902537003, -0.0016294924252267858, -0.00734616793626805, 0.016068947131575025, 0.026682304669604834,
+    -0.08126671024919373, -0.05607731960356926, 0.41530842700068227, 0.7822389344242826, 0.43438603311435653,
+    -0.06662747236681715, -0.09622042453595264, 0.03933442260558915, 0.025082253337949608, -0.015211728187697211,
+    -0.0056582838001308835, 0.003751434697146086, 0.0012665610789256603, -0.0005890202246332164,
+    -0.0002599743371222568, 6.233885431278718e-05, 3.1229861599195265e-05, -3.2596479400307506e-06,
+    -1.7849909144933466e-06,
+];
+
+/// Scaling filter of the Coiflet of order 5
+const COIF5: [f64; 30] = [
+    -0.000212081862067494, 0.0003585777411617577, 0.0021782943778456947, -0.004159312627578639, -0.010131584846900275,
+    0.023408322118927783, 0.028169744270532353, -0.09192158806008609, -0.05204667025355476, 0.42157126673075435,
+    0.7742936228603274, 0.4379823066591633, -0.06203775157498195, -0.10556315130733723, 0.041287530472117834,
+    0.03267479946705735, -0.019758391600965465, -0.009159507338676163, 0.006761520220620417, 0.0024315754425382886,
+    -0.0016616273039298788, -0.0006375589261258812, 0.00030185794166824473, 0.00014035632812373243,
+    -4.12198619242655e-05, -2.1270221672515614e-05, 3.7007277113394796e-06, 2.0612203985788783e-06,
+    -1.6237995172048335e-07, -9.604010112767892e-08,
+];
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Orthogonal wavelets
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Wavelet {
+    /// Haar wavelet, with a filter of length 2
+    Haar,
+    /// Daubechies wavelet of order 1 to 10, with a filter of length twice the order
+    Daubechies(usize),
+    /// Symlet of order 2 to 10, with a filter of length twice the order
+    Symlet(usize),
+    /// Coiflet of order 1 to 5, with a filter of length six times the order
+    Coiflet(usize)
+}
+
+/// # Filter bank of an orthogonal wavelet
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Filters {
+    /// Decomposition low-pass filter
+    pub dec_lo: Vec<f64>,
+    /// Decomposition high-pass filter
+    pub dec_hi: Vec<f64>,
+    /// Reconstruction low-pass filter
+    pub rec_lo: Vec<f64>,
+    /// Reconstruction high-pass filter
+    pub rec_hi: Vec<f64>
+}
+
+/// # Extension of the signal at its edges
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Extension {
+    /// Extension with zeros
+    Zero,
+    /// Repetition of the edge samples
+    Constant,
+    /// Even reflection around the edges, repeating the edge samples
+    Symmetric,
+    /// Even reflection around the edge samples
+    Reflect,
+    /// Periodic repetition of the signal
+    Periodic,
+    /// Periodic repetition, with exactly half the samples in each output
+    Periodization
+}
+
+/// # Thresholding of the coefficients
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Threshold {
+    /// Shrinks the coefficients towards zero by the threshold
+    Soft,
+    /// Zeroes the coefficients below the threshold
+    Hard
+}
+
+/// # Mother wavelets of the continuous transform
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Mother {
+    /// Morlet wavelet, a plane wave of angular frequency `omega0` in a Gaussian envelope, usually 6
+    Morlet {
+        omega0: f64
+    },
+    /// Paul wavelet of the given order, complex and well localized in time
+    Paul {
+        order: usize
+    },
+    /// Derivative of Gaussian of the given order, real-valued
+    Dog {
+        order: usize
+    }
+}
+
+/// # Continuous wavelet transform of a signal
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Cwt {
+    /// Scales of the transform, in the units of the inverse of the sampling rate
+    pub scales: Vec<f64>,
+    /// Equivalent Fourier frequencies of the scales, in the units of the sampling rate
+    pub frequencies: Vec<f64>,
+    /// Coefficients of the transform, indexed as `values[scale][time]`
+    pub values: Vec<Vec<Complex64>>
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Wavelet {
+
+    /// # Filter bank
+    /// 
+    /// ## Definition
+    /// The reconstruction low-pass filter $g$ is the scaling filter of the wavelet, of length $L$. The other
+    /// filters are derived from it:
+    /// $$
+    /// h_n = (-1)^ng_{L-1-n}
+    /// $$
+    /// for the reconstruction high-pass filter, and the decomposition filters are the reversed reconstruction
+    /// filters.
+    /// 
+    /// Returns the four filters of the wavelet.
+    /// 
+    /// ## Example
+    /// ```
+    /// # use scilib::signal::wavelet::Wavelet;
+    /// let filters = Wavelet::Daubechies(2).filters();
+    /// let s3: f64 = 3.0_f64.sqrt();
+    /// let expected: Vec<f64> = vec![1.0 + s3, 3.0 + s3, 3.0 - s3, 1.0 - s3];
+    /// 
+    /// for (a, b) in filters.rec_lo.iter().zip(&expected) {
+    ///     assert!((a - b / 32.0_f64.sqrt()).abs() < 1.0e-15);
+    /// }
+    /// assert!((filters.dec_hi[0] + filters.dec_lo[3]).abs() < 1.0e-15);
+    /// ```
+    pub fn filters(&self) -> Filters {
+
+        let rec_lo: Vec<f64> = self.scaling().to_vec();
+        let rec_hi: Vec<f64> = quadrature_mirror(&rec_lo);
+
+        Filters {
+            dec_lo: rec_lo.iter().rev().copied().collect(),
+            dec_hi: rec_hi.iter().rev().copied().collect(),
+            rec_lo,
+            rec_hi
+        }
+    }
+
+    /// # Length of the filters
+    /// 
+    /// ## Example
+    /// ```
+    /// # use scilib::signal::wavelet::Wavelet;
+    /// assert_eq!(Wavelet::Haar.length(), 2);
+    /// assert_eq!(Wavelet::Symlet(5).length(), 10);
+    /// assert_eq!(Wavelet::Coiflet(3).length(), 18);
+    /// ```
+    pub fn length(&self) -> usize {
+        self.scaling().len()
+    }
+
+    /// # Scaling filter of the wavelet, from the tables
+    fn scaling(&self) -> &'static [f64] {
+        match *self {
+            Self::Haar => &DB1,
+            Self::Daubechies(order) => match order {
+                1 => &DB1,
+                2 => &DB2,
+                3 => &DB3,
+                4 => &DB4,
+                5 => &DB5,
+                6 => &DB6,
+                7 => &DB7,
+                8 => &DB8,
+                9 => &DB9,
+                10 => &DB10,
+                _ => panic!("Daubechies wavelets are available for orders 1 to 10!")
+            },
+            Self::Symlet(order) => match order {
+                2 => &SYM2,
+                3 => &SYM3,
+                4 => &SYM4,
+                5 => &SYM5,
+                6 => &SYM6,
+                7 => &SYM7,
+                8 => &SYM8,
+                9 => &SYM9,
+                10 => &SYM10,
+                _ => panic!("Symlets are available for orders 2 to 10!")
+            },
+            Self::Coiflet(order) => match order {
+                1 => &COIF1,
+                2 => &COIF2,
+                3 => &COIF3,
+                4 => &COIF4,
+                5 => &COIF5,
+                _ => panic!("Coiflets are available for orders 1 to 5!")
+            }
+        }
+    }
+}
+
+impl Mother {
+
+    /// The Mexican hat wavelet, second derivative of Gaussian
+    pub const MEXICAN_HAT: Self = Self::Dog { order: 2 };
+
+    /// # Fourier factor
+    /// 
+    /// ## Definition
+    /// Ratio between the Fourier period of the wavelet at a given scale and that scale:
+    /// $$
+    /// \lambda_{\mathrm{Morlet}} = \frac{4\pi}{\omega_0+\sqrt{2+\omega_0^2}}, \quad
+    /// \lambda_{\mathrm{Paul}} = \frac{4\pi}{2m+1}, \quad
+    /// \lambda_{\mathrm{DOG}} = \frac{2\pi}{\sqrt{m+1/2}}
+    /// $$
+    /// 
+    /// ## Example
+    /// ```
+    /// # use scilib::signal::wavelet::Mother;
+    /// let morlet = Mother::Morlet { omega0: 6.0 };
+    /// assert!((morlet.fourier_factor() - 1.0330436477492537).abs() < 1.0e-15);
+    /// ```
+    pub fn fourier_factor(&self) -> f64 {
+        match *self {
+            Self::Morlet { omega0 } => 4.0 * PI / (omega0 + (2.0 + omega0.powi(2)).sqrt()),
+            Self::Paul { order } => 4.0 * PI / (2 * order + 1) as f64,
+            Self::Dog { order } => 2.0 * PI / (order as f64 + 0.5).sqrt()
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Discrete wavelet transform
+/// 
+/// ## Definition
+/// Filters the signal with the decomposition filters and keeps one sample out of two:
+/// $$
+/// a_k = \sum_{n=0}^{L-1}g_nx_{2k+n+2-L}, \quad d_k = \sum_{n=0}^{L-1}h_nx_{2k+n+2-L}
+/// $$
+/// where $g$ and $h$ are the reconstruction low and high-pass filters of length $L$. The samples outside the
+/// signal are given by the extension mode, and each output has $\lfloor(N+L-1)/2\rfloor$ coefficients.
+/// With the periodization mode, the signal is taken as periodic (repeating the last sample when the length is
+/// odd), and each output has $\lceil N/2\rceil$ coefficients.
+/// 
+/// ## Inputs
+/// - `x`: the signal
+/// - `wavelet`: the orthogonal wavelet
+/// - `mode`: the extension of the signal at its edges
+/// 
+/// Returns the approximation and detail coefficients.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::wavelet::{ dwt, Extension, Wavelet };
+/// let x: Vec<f64> = vec![1.0, 3.0, -2.0, 4.0, 0.5, 1.5];
+/// let (a, d) = dwt(&x, Wavelet::Haar, Extension::Periodization);
+/// 
+/// let s2: f64 = 2.0_f64.sqrt();
+/// let expected_a: Vec<f64> = vec![4.0 / s2, 2.0 / s2, 2.0 / s2];
+/// let expected_d: Vec<f64> = vec![-2.0 / s2, -6.0 / s2, -1.0 / s2];
+/// for (r, e) in a.iter().chain(&d).zip(expected_a.iter().chain(&expected_d)) {
+///     assert!((r - e).abs() < 1.0e-12);
+/// }
+/// ```
+pub fn dwt(x: &[f64], wavelet: Wavelet, mode: Extension) -> (Vec<f64>, Vec<f64>) {
+
+    assert!(!x.is_empty(), "The signal cannot be empty!");
+
+    let g: &[f64] = wavelet.scaling();
+    let h: Vec<f64> = quadrature_mirror(g);
+    let length: usize = g.len();
+
+    if mode == Extension::Periodization {
+        // Even length, repeating the last sample if needed
+        let mut xp: Vec<f64> = x.to_vec();
+        if !xp.len().is_multiple_of(2) {
+            xp.push(x[x.len() - 1]);
+        }
+
+        let n: isize = xp.len() as isize;
+        let shift: isize = length as isize / 2 - 1;
+        let mut approx: Vec<f64> = vec![0.0; xp.len() / 2];
+        let mut detail: Vec<f64> = vec![0.0; xp.len() / 2];
+
+        for k in 0..approx.len() {
+            for m in 0..length {
+                let v: f64 = xp[(2 * k as isize + m as isize - shift).rem_euclid(n) as usize];
+                approx[k] += g[m] * v;
+                detail[k] += h[m] * v;
+            }
+        }
+
+        return (approx, detail);
+    }
+
+    let count: usize = (x.len() + length - 1) / 2;
+    let mut approx: Vec<f64> = vec![0.0; count];
+    let mut detail: Vec<f64> = vec![0.0; count];
+
+    for k in 0..count {
+        for m in 0..length {
+            let v: f64 = extended(x, (2 * k + m) as isize + 2 - length as isize, mode);
+            approx[k] += g[m] * v;
+            detail[k] += h[m] * v;
+        }
+    }
+
+    (approx, detail)
+}
+
+/// # Inverse discrete wavelet transform
+/// 
+/// ## Definition
+/// Upsamples the coefficients and filters them with the reconstruction filters:
+/// $$
+/// x_n = \sum_k a_kg_{n+L-2-2k} + d_kh_{n+L-2-2k}
+/// $$
+/// keeping the $2K-L+2$ samples that do not depend on the extension, for $K$ coefficients. With the
+/// periodization mode, the output has $2K$ samples.
+/// 
+/// ## Inputs
+/// - `approx`: the approximation coefficients
+/// - `detail`: the detail coefficients, of the same length
+/// - `wavelet`: the orthogonal wavelet
+/// - `mode`: the extension used for the transform
+/// 
+/// Returns the reconstructed signal.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::wavelet::{ dwt, idwt, Extension, Wavelet };
+/// let x: Vec<f64> = vec![0.3, -1.2, 2.5, 4.0, -0.7, 1.1, 0.9];
+/// 
+/// for mode in [Extension::Zero, Extension::Reflect, Extension::Periodization] {
+///     let (a, d) = dwt(&x, Wavelet::Coiflet(1), mode);
+///     let y = idwt(&a, &d, Wavelet::Coiflet(1), mode);
+///     for (r, e) in y.iter().zip(&x) {
+///         assert!((r - e).abs() < 1.0e-12);
+///     }
+/// }
+/// ```
+pub fn idwt(approx: &[f64], detail: &[f64], wavelet: Wavelet, mode: Extension) -> Vec<f64> {
+
+    assert_eq!(approx.len(), detail.len(), "The coefficients must have the same length!");
+
+    let g: &[f64] = wavelet.scaling();
+    let h: Vec<f64> = quadrature_mirror(g);
+    let length: usize = g.len();
+
+    if mode == Extension::Periodization {
+        let n: isize = 2 * approx.len() as isize;
+        let shift: isize = length as isize / 2 - 1;
+        let mut res: Vec<f64> = vec![0.0; n as usize];
+
+        for k in 0..approx.len() {
+            for m in 0..length {
+                let idx: usize = (2 * k as isize + m as isize - shift).rem_euclid(n) as usize;
+                res[idx] += g[m] * approx[k] + h[m] * detail[k];
+            }
+        }
+
+        return res;
+    }
+
+    let count: usize = (2 * approx.len() + 2).saturating_sub(length);
+    let mut res: Vec<f64> = vec![0.0; count];
+
+    // Upsampled coefficients spread by the filters, keeping the samples independent of the extension
+    for k in 0..approx.len() {
+        for m in 0..length {
+            if let Some(idx) = (2 * k + m).checked_sub(length - 2).filter(|&i| i < count) {
+                res[idx] += g[m] * approx[k] + h[m] * detail[k];
+            }
+        }
+    }
+
+    res
+}
+
+/// # Maximum decomposition level
+/// 
+/// ## Definition
+/// The deepest level for which the last approximation still has at least as many samples as the filter:
+/// $$
+/// J = \left\lfloor\log_2\frac{N}{L-1}\right\rfloor
+/// $$
+/// 
+/// ## Inputs
+/// - `length`: the length of the signal ($N$)
+/// - `wavelet`: the orthogonal wavelet
+/// 
+/// Returns the maximum level, zero if the signal is shorter than the filter.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::wavelet::{ max_level, Wavelet };
+/// assert_eq!(max_level(1000, Wavelet::Haar), 9);
+/// assert_eq!(max_level(1000, Wavelet::Daubechies(4)), 7);
+/// assert_eq!(max_level(5, Wavelet::Coiflet(1)), 0);
+/// ```
+pub fn max_level(length: usize, wavelet: Wavelet) -> usize {
+
+    let width: usize = wavelet.length() - 1;
+    let mut level: usize = 0;
+
+    while width << (level + 1) <= length {
+        level += 1;
+    }
+
+    level
+}
+
+/// # Multilevel discrete wavelet transform
+/// 
+/// ## Definition
+/// Applies the transform repeatedly to the approximation coefficients, `level` times.
+/// 
+/// ## Inputs
+/// - `x`: the signal
+/// - `wavelet`: the orthogonal wavelet
+/// - `mode`: the extension of the signal at its edges
+/// - `level`: the number of decompositions, usually at most `max_level`
+/// 
+/// Returns the coefficients as `[a_J, d_J, d_J-1, ..., d_1]`, from the coarsest to the finest level.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::wavelet::{ wavedec, Extension, Wavelet };
+/// // The details of a constant signal vanish
+/// let x: Vec<f64> = vec![2.0; 32];
+/// let coefs = wavedec(&x, Wavelet::Haar, Extension::Periodization, 3);
+/// 
+/// assert_eq!(coefs.iter().map(|c| c.len()).collect::<Vec<usize>>(), vec![4, 4, 8, 16]);
+/// assert!(coefs[0].iter().all(|a| (a - 2.0 * 8.0_f64.sqrt()).abs() < 1.0e-12));
+/// assert!(coefs[1..].iter().flatten().all(|d| d.abs() < 1.0e-12));
+/// ```
+pub fn wavedec(x: &[f64], wavelet: Wavelet, mode: Extension, level: usize) -> Vec<Vec<f64>> {
+
+    let mut approx: Vec<f64> = x.to_vec();
+    let mut details: Vec<Vec<f64>> = Vec::with_capacity(level);
+
+    for _ in 0..level {
+        let (a, d) = dwt(&approx, wavelet, mode);
+        approx = a;
+        details.push(d);
+    }
+
+    details.push(approx);
+    details.reverse();
+    details
+}
+
+/// # Multilevel inverse discrete wavelet transform
+/// 
+/// ## Definition
+/// Reconstructs the approximation of each level from the coarsest one, dropping the last sample of the
+/// approximation when it is one sample longer than the details (signals of odd length).
+/// 
+/// ## Inputs
+/// - `coefs`: the coefficients, as returned by `wavedec`
+/// - `wavelet`: the orthogonal wavelet
+/// - `mode`: the extension used for the transform
+/// 
+/// Returns the reconstructed signal, with one extra sample when the original length was odd.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::wavelet::{ wavedec, waverec, Extension, Wavelet };
+/// let x: Vec<f64> = (0..75).map(|k| ((k * k) % 13) as f64).collect();
+/// 
+/// let coefs = wavedec(&x, Wavelet::Symlet(3), Extension::Periodic, 3);
+/// let y = waverec(&coefs, Wavelet::Symlet(3), Extension::Periodic);
+/// assert_eq!(y.len(), 76);
+/// for (a, b) in y.iter().zip(&x) {
+///     assert!((a - b).abs() < 1.0e-12);
+/// }
+/// ```
+pub fn waverec(coefs: &[Vec<f64>], wavelet: Wavelet, mode: Extension) -> Vec<f64> {
+
+    assert!(!coefs.is_empty(), "The coefficients cannot be empty!");
+
+    let mut approx: Vec<f64> = coefs[0].clone();
+
+    for detail in &coefs[1..] {
+        if approx.len() == detail.len() + 1 {
+            approx.pop();
+        }
+        approx = idwt(&approx, detail, wavelet, mode);
+    }
+
+    approx
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Thresholding
+/// 
+/// ## Definition
+/// The hard and soft thresholding of a coefficient $c$ by a threshold $\lambda$ are:
+/// $$
+/// \rho_{\mathrm{hard}}(c) = c\,\mathbb{1}_{|c|>\lambda}, \quad \rho_{\mathrm{soft}}(c) = \mathrm{sgn}(c)\max(|c|-\lambda, 0)
+/// $$
+/// 
+/// ## Inputs
+/// - `x`: the coefficients
+/// - `value`: the threshold ($\lambda$)
+/// - `kind`: hard or soft thresholding
+/// 
+/// Returns the thresholded coefficients.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::wavelet::{ threshold, Threshold };
+/// let x: Vec<f64> = vec![-3.0, -0.5, 0.2, 1.5, 4.0];
+/// assert_eq!(threshold(&x, 1.0, Threshold::Hard), vec![-3.0, 0.0, 0.0, 1.5, 4.0]);
+/// assert_eq!(threshold(&x, 1.0, Threshold::Soft), vec![-2.0, 0.0, 0.0, 0.5, 3.0]);
+/// ```
+pub fn threshold(x: &[f64], value: f64, kind: Threshold) -> Vec<f64> {
+    x.iter().map(|&c| match kind {
+        Threshold::Hard => if c.abs() > value { c } else { 0.0 },
+        Threshold::Soft => c.signum() * (c.abs() - value).max(0.0)
+    }).collect()
+}
+
+/// # Wavelet denoising
+/// 
+/// ## Definition
+/// Decomposes the signal, thresholds the detail coefficients of all levels with the universal threshold,
+/// and reconstructs the signal. The threshold is:
+/// $$
+/// \lambda = \hat\sigma\sqrt{2\ln N}, \quad \hat\sigma = \frac{\mathrm{median}(|d_1|)}{0.6745}
+/// $$
+/// where the noise level $\hat\sigma$ is estimated from the median absolute deviation of the finest details.
+/// 
+/// ## Inputs
+/// - `x`: the noisy signal
+/// - `wavelet`: the orthogonal wavelet
+/// - `mode`: the extension of the signal at its edges
+/// - `level`: the number of decompositions, at least one
+/// - `kind`: hard or soft thresholding
+/// 
+/// Returns the denoised signal, of the same length as the input.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::wavelet::{ denoise, Extension, Threshold, Wavelet };
+/// // A smooth signal with a deterministic pseudo-random noise
+/// let clean: Vec<f64> = (0..512).map(|k| (std::f64::consts::TAU * k as f64 / 256.0).sin()).collect();
+/// let mut seed: u64 = 42;
+/// let noisy: Vec<f64> = clean.iter().map(|c| {
+///     seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+///     c + 0.2 * ((seed >> 11) as f64 / (1u64 << 53) as f64 - 0.5)
+/// }).collect();
+/// 
+/// let res = denoise(&noisy, Wavelet::Symlet(8), Extension::Symmetric, 4, Threshold::Soft);
+/// let error = |v: &[f64]| v.iter().zip(&clean).map(|(a, b)| (a - b).powi(2)).sum::<f64>();
+/// assert_eq!(res.len(), noisy.len());
+/// assert!(error(&res) < error(&noisy) / 3.0);
+/// ```
+pub fn denoise(x: &[f64], wavelet: Wavelet, mode: Extension, level: usize, kind: Threshold) -> Vec<f64> {
+
+    assert!(level > 0, "At least one decomposition level is needed!");
+
+    let mut coefs: Vec<Vec<f64>> = wavedec(x, wavelet, mode, level);
+
+    // Noise level from the finest details
+    let mut finest: Vec<f64> = coefs[level].iter().map(|d| d.abs()).collect();
+    finest.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let mid: usize = finest.len() / 2;
+    let median: f64 = if finest.len().is_multiple_of(2) {
+        (finest[mid - 1] + finest[mid]) / 2.0
+    } else {
+        finest[mid]
+    };
+    let sigma: f64 = median / MAD_NORMAL;
+    let value: f64 = sigma * (2.0 * (x.len() as f64).ln()).sqrt();
+
+    for detail in coefs.iter_mut().skip(1) {
+        *detail = threshold(detail, value, kind);
+    }
+
+    let mut res: Vec<f64> = waverec(&coefs, wavelet, mode);
+    res.truncate(x.len());
+    res
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Continuous wavelet transform
+/// 
+/// ## Definition
+/// Correlates the signal with the scaled mother wavelet $\psi_0$, at each scale $s$:
+/// $$
+/// W_n(s) = \sum_{k=0}^{N-1}x_k\sqrt{\frac{\delta t}{s}}\psi_0^*\left(\frac{(k-n)\delta t}{s}\right)
+/// $$
+/// where $\delta t$ is the sampling period, such that the wavelet has a unit energy at every scale. The mother
+/// wavelets are:
+/// $$
+/// \begin{aligned}
+/// \mathrm{Morlet}&: \psi_0(\eta) = \pi^{-1/4}e^{i\omega_0\eta}e^{-\eta^2/2} \\\\
+/// \mathrm{Paul}&: \psi_0(\eta) = \frac{2^mi^mm!}{\sqrt{\pi(2m)!}}(1-i\eta)^{-(m+1)} \\\\
+/// \mathrm{DOG}&: \psi_0(\eta) = \frac{(-1)^{m+1}}{\sqrt{\Gamma(m+1/2)}}\frac{d^m}{d\eta^m}e^{-\eta^2/2}
+///     = \frac{-1}{\sqrt{\Gamma(m+1/2)}}2^{-m/2}H_m\left(\frac{\eta}{\sqrt{2}}\right)e^{-\eta^2/2}
+/// \end{aligned}
+/// $$
+/// where $H_m$ are the Hermite polynomials. The signal is taken as zero outside of its edges, and the Morlet
+/// wavelet is only admissible for $\omega_0\gtrsim5$.
+/// 
+/// ## Inputs
+/// - `x`: the signal
+/// - `fs`: the sampling rate
+/// - `scales`: the scales, in the units of the inverse of the sampling rate
+/// - `mother`: the mother wavelet
+/// 
+/// Returns the scales, their equivalent Fourier frequencies, and the coefficients.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::wavelet::{ cwt, Mother };
+/// // A tone at 50 Hz, sampled at 1 kHz
+/// let x: Vec<f64> = (0..1000).map(|k| (std::f64::consts::TAU * 50.0 * k as f64 / 1000.0).sin()).collect();
+/// let scales: Vec<f64> = (1..=40).map(|k| k as f64 * 1.0e-3).collect();
+/// 
+/// let res = cwt(&x, 1000.0, &scales, Mother::Morlet { omega0: 6.0 });
+/// // Rectified power at the middle of the signal
+/// let power: Vec<f64> = res.values.iter().zip(&scales).map(|(w, s)| w[500].norm_sqr() / s).collect();
+/// let best: usize = (0..40).max_by(|&a, &b| power[a].total_cmp(&power[b])).unwrap();
+/// assert!((res.frequencies[best] - 50.0).abs() < 2.0);
+/// ```
+pub fn cwt(x: &[f64], fs: f64, scales: &[f64], mother: Mother) -> Cwt {
+
+    let n: usize = x.len();
+    let signal: Vec<Complex64> = x.iter().map(|&v| Complex64::new(v, 0.0)).collect();
+    let factor: f64 = mother.fourier_factor();
+
+    // Polynomial and normalization of the derivatives of Gaussian
+    let hermite: Poly = match mother {
+        Mother::Dog { order } => Poly::hermite(order),
+        _ => Poly::from(&[(0, 1.0)])
+    };
+    let norm: f64 = match mother {
+        Mother::Morlet { .. } => PI.powf(-0.25),
+        Mother::Paul { order } => {
+            // 2^m m! / sqrt(pi (2m)!)
+            let ratio: f64 = (1..=order).map(|k| 4.0 * k as f64 / (k + order) as f64).product();
+            (ratio / PI).sqrt()
+        },
+        Mother::Dog { order } => {
+            // Gamma(m + 1/2) = sqrt(pi) (2m)! / (4^m m!)
+            let gamma: f64 = (1..=order).map(|k| k as f64 - 0.5).product::<f64>() * PI.sqrt();
+            -(2.0_f64.powi(order as i32) * gamma).sqrt().recip()
+        }
+    };
+    let support: f64 = match mother {
+        Mother::Paul { order } => PAUL_TRUNCATION.powf(-1.0 / (order + 1) as f64),
+        _ => GAUSSIAN_SUPPORT
+    };
+
+    let values: Vec<Vec<Complex64>> = scales.iter().map(|&s| {
+        assert!(s > 0.0, "The scales must be positive!");
+
+        let ratio: f64 = 1.0 / (fs * s);
+        let half: usize = ((support / ratio).ceil() as usize).min(n);
+
+        // Conjugate wavelet, reversed for the convolution
+        let kernel: Vec<Complex64> = (0..=2 * half).map(|j| {
+            let eta: f64 = (half as f64 - j as f64) * ratio;
+            let psi: Complex64 = match mother {
+                Mother::Morlet { omega0 } => Complex64::from_polar(norm * (-eta * eta / 2.0).exp(), omega0 * eta),
+                Mother::Paul { order } => {
+                    Complex64::i().powu(order as u32) * norm / Complex64::new(1.0, -eta).powu(order as u32 + 1)
+                },
+                Mother::Dog { .. } => {
+                    Complex64::new(norm * hermite.compute(eta / SQRT_2) * (-eta * eta / 2.0).exp(), 0.0)
+                }
+            };
+            psi.conj() * ratio.sqrt()
+        }).collect();
+
+        fft_convolve_complex(&signal, &kernel, Mode::Full)[half..half + n].to_vec()
+    }).collect();
+
+    Cwt {
+        scales: scales.to_vec(),
+        frequencies: scales.iter().map(|s| 1.0 / (factor * s)).collect(),
+        values
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # High-pass filter from the low-pass filter of an orthogonal wavelet
+fn quadrature_mirror(g: &[f64]) -> Vec<f64> {
+    g.iter().rev().enumerate().map(|(n, v)| if n.is_multiple_of(2) { *v } else { -v }).collect()
+}
+
+/// # Value of the extended signal at any index
+fn extended(x: &[f64], idx: isize, mode: Extension) -> f64 {
+
+    let n: isize = x.len() as isize;
+
+    if (0..n).contains(&idx) {
+        return x[idx as usize];
+    }
+
+    match mode {
+        Extension::Zero => 0.0,
+        Extension::Constant => if idx < 0 { x[0] } else { x[x.len() - 1] },
+        Extension::Symmetric => {
+            let m: isize = idx.rem_euclid(2 * n);
+            x[(if m < n { m } else { 2 * n - 1 - m }) as usize]
+        },
+        Extension::Reflect => {
+            if n == 1 {
+                return x[0];
+            }
+            let m: isize = idx.rem_euclid(2 * n - 2);
+            x[(if m < n { m } else { 2 * n - 2 - m }) as usize]
+        },
+        Extension::Periodic | Extension::Periodization => x[idx.rem_euclid(n) as usize]
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,631 @@
+//!
+//! # Window functions
+//! 
+//! Windows are used to taper a signal before computing its spectrum, which reduces the
+//! [spectral leakage](https://en.wikipedia.org/wiki/Spectral_leakage) caused by the finite length of the signal.
+//! 
+//! Every window can be generated in two variants:
+//! - **Symmetric**: the window is symmetric around its center, and is used for filter design
+//! - **Periodic**: the window is one period of a periodic function, which is the one to use for spectral analysis
+//! 
+//! The periodic window of length $N$ is the symmetric window of length $N+1$ without its last point.
+//! 
+//! ```
+//! # use scilib::signal::window::{ hann, Symmetry, coherent_gain, enbw };
+//! # use scilib::signal::fft;
+//! let w = hann(64, Symmetry::Periodic);
+//! let s: Vec<f64> = (0..64).map(|k| (0.3 * k as f64).sin() * w[k]).collect();
+//! let spectrum = fft(&s);
+//! 
+//! // Amplitude correction and noise bandwidth of the window
+//! assert!((coherent_gain(&w) - 0.5).abs() < 1.0e-12);
+//! assert!((enbw(&w) - 1.5).abs() < 1.0e-12);
+//! ```
+//! 
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use std::f64::consts::{     // Using std lib constants
+    PI,                     // Pi
+    TAU                     // Tau
+};
+
+use super::fft;             // Transform used by the Dolph-Chebyshev window
+
+use crate::math::bessel;    // Modified Bessel function for Kaiser
+
+use num_complex::Complex64; // Using complex numbers from the num crate
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Symmetry of a window
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Symmetry {
+    /// Symmetric around its center, for filter design
+    Symmetric,
+    /// One period of a periodic window, for spectral analysis
+    Periodic
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Hann window
+/// 
+/// ## Definition
+/// The [Hann window](https://en.wikipedia.org/wiki/Hann_function) is defined as:
+/// $$
+/// w(k) = \frac{1}{2} - \frac{1}{2}\cos\left(\frac{2\pi k}{M-1}\right)
+/// $$
+/// where $M$ is the length of the symmetric window.
+/// 
+/// ## Inputs
+/// - `n`: the number of points
+/// - `sym`: symmetric or periodic variant
+/// 
+/// Returns the window.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::window::{ hann, Symmetry };
+/// let w = hann(5, Symmetry::Symmetric);
+/// let expected: Vec<f64> = vec![0.0, 0.5, 1.0, 0.5, 0.0];
+/// for (v, e) in w.iter().zip(&expected) {
+///     assert!((v - e).abs() < 1.0e-15);
+/// }
+/// 
+/// let p = hann(4, Symmetry::Periodic);
+/// let expected_p: Vec<f64> = vec![0.0, 0.5, 1.0, 0.5];
+/// for (v, e) in p.iter().zip(&expected_p) {
+///     assert!((v - e).abs() < 1.0e-15);
+/// }
+/// ```
+pub fn hann(n: usize, sym: Symmetry) -> Vec<f64> {
+    general_cosine(n, &[0.5, 0.5], sym)
+}
+
+/// # Hamming window
+/// 
+/// ## Definition
+/// The [Hamming window](https://en.wikipedia.org/wiki/Window_function#Hann_and_Hamming_windows) is defined as:
+/// $$
+/// w(k) = 0.54 - 0.46\cos\left(\frac{2\pi k}{M-1}\right)
+/// $$
+/// where $M$ is the length of the symmetric window.
+/// 
+/// ## Inputs
+/// - `n`: the number of points
+/// - `sym`: symmetric or periodic variant
+/// 
+/// Returns the window.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::window::{ hamming, Symmetry };
+/// let w = hamming(5, Symmetry::Symmetric);
+/// let expected: Vec<f64> = vec![0.08, 0.54, 1.0, 0.54, 0.08];
+/// for (v, e) in w.iter().zip(&expected) {
+///     assert!((v - e).abs() < 1.0e-15);
+/// }
+/// ```
+pub fn hamming(n: usize, sym: Symmetry) -> Vec<f64> {
+    general_cosine(n, &[0.54, 0.46], sym)
+}
+
+/// # Blackman window
+/// 
+/// ## Definition
+/// The [Blackman window](https://en.wikipedia.org/wiki/Window_function#Blackman_window) is defined as:
+/// $$
+/// w(k) = 0.42 - 0.5\cos\left(\frac{2\pi k}{M-1}\right) + 0.08\cos\left(\frac{4\pi k}{M-1}\right)
+/// $$
+/// where $M$ is the length of the symmetric window.
+/// 
+/// ## Inputs
+/// - `n`: the number of points
+/// - `sym`: symmetric or periodic variant
+/// 
+/// Returns the window.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::window::{ blackman, Symmetry };
+/// let w = blackman(5, Symmetry::Symmetric);
+/// let expected: Vec<f64> = vec![0.0, 0.34, 1.0, 0.34, 0.0];
+/// for (v, e) in w.iter().zip(&expected) {
+///     assert!((v - e).abs() < 1.0e-15);
+/// }
+/// ```
+pub fn blackman(n: usize, sym: Symmetry) -> Vec<f64> {
+    general_cosine(n, &[0.42, 0.5, 0.08], sym)
+}
+
+/// # Blackman-Harris window
+/// 
+/// ## Definition
+/// The four terms [Blackman-Harris window](https://en.wikipedia.org/wiki/Window_function#Blackman%E2%80%93Harris_window)
+/// is defined as:
+/// $$
+/// w(k) = a_0 - a_1\cos\left(\frac{2\pi k}{M-1}\right) + a_2\cos\left(\frac{4\pi k}{M-1}\right) - a_3\cos\left(\frac{6\pi k}{M-1}\right)
+/// $$
+/// with $a_0 = 0.35875$, $a_1 = 0.48829$, $a_2 = 0.14128$, $a_3 = 0.01168$, and $M$ the length of the symmetric window.
+/// Its side lobes are below -92 dB.
+/// 
+/// ## Inputs
+/// - `n`: the number of points
+/// - `sym`: symmetric or periodic variant
+/// 
+/// Returns the window.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::window::{ blackman_harris, Symmetry };
+/// let w = blackman_harris(5, Symmetry::Symmetric);
+/// assert!((w[0] - 6.0e-5).abs() < 1.0e-15);
+/// assert!((w[1] - 0.21747).abs() < 1.0e-15);
+/// assert!((w[2] - 1.0).abs() < 1.0e-15);
+/// ```
+pub fn blackman_harris(n: usize, sym: Symmetry) -> Vec<f64> {
+    general_cosine(n, &[0.35875, 0.48829, 0.14128, 0.01168], sym)
+}
+
+/// # Flat top window
+/// 
+/// ## Definition
+/// The [flat top window](https://en.wikipedia.org/wiki/Window_function#Flat_top_window) is a five terms cosine
+/// window, with coefficients $a_0 = 0.21557895$, $a_1 = 0.41663158$, $a_2 = 0.277263158$,
+/// $a_3 = 0.083578947$ and $a_4 = 0.006947368$. Its very flat main lobe gives accurate
+/// amplitudes for sinusoids that fall between frequency bins.
+/// 
+/// ## Inputs
+/// - `n`: the number of points
+/// - `sym`: symmetric or periodic variant
+/// 
+/// Returns the window.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::window::{ flat_top, Symmetry };
+/// let w = flat_top(5, Symmetry::Symmetric);
+/// assert!((w[0] - -0.000421051).abs() < 1.0e-9);
+/// assert!((w[1] - -0.05473684).abs() < 1.0e-9);
+/// assert!((w[2] - 1.000000003).abs() < 1.0e-9);
+/// ```
+pub fn flat_top(n: usize, sym: Symmetry) -> Vec<f64> {
+    general_cosine(n, &[0.21557895, 0.41663158, 0.277263158, 0.083578947, 0.006947368], sym)
+}
+
+/// # Kaiser window
+/// 
+/// ## Definition
+/// The [Kaiser window](https://en.wikipedia.org/wiki/Kaiser_window) is defined as:
+/// $$
+/// w(k) = \frac{I_0\left(\beta\sqrt{1 - \left(\frac{2k}{M-1} - 1\right)^2}\right)}{I_0(\beta)}
+/// $$
+/// where $I_0$ is the zeroth order modified Bessel function from `math::bessel`, and $M$ the length of the symmetric window.
+/// The shape parameter $\beta$ trades the width of the main lobe for the level of the side lobes:
+/// $\beta = 0$ is the rectangular window, $\beta\approx 8.6$ is close to Blackman.
+/// 
+/// ## Inputs
+/// - `n`: the number of points
+/// - `beta`: the shape parameter ($\beta$)
+/// - `sym`: symmetric or periodic variant
+/// 
+/// Returns the window.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::window::{ kaiser, Symmetry };
+/// let w = kaiser(5, 6.0, Symmetry::Symmetric);
+/// assert!((w[0] - 0.014873337).abs() < 1.0e-8);
+/// assert!((w[1] - 0.48295561).abs() < 1.0e-8);
+/// assert!((w[2] - 1.0).abs() < 1.0e-12);
+/// ```
+pub fn kaiser(n: usize, beta: f64, sym: Symmetry) -> Vec<f64> {
+
+    let norm: f64 = bessel::i(beta, 0).re;
+
+    generate(n, sym, |k, m| {
+        let x: f64 = 2.0 * k as f64 / (m - 1) as f64 - 1.0;
+        bessel::i(beta * (1.0 - x.powi(2)).max(0.0).sqrt(), 0).re / norm
+    })
+}
+
+/// # Tukey window
+/// 
+/// ## Definition
+/// The [Tukey window](https://en.wikipedia.org/wiki/Window_function#Tukey_window), or tapered cosine window,
+/// is flat in its center and has cosine tapers over a fraction $\alpha$ of its length.
+/// With $x = k / (M-1)$:
+/// $$
+/// w(k) = \begin{cases}
+/// \frac{1}{2}\left(1 - \cos\left(\frac{2\pi x}{\alpha}\right)\right) & x < \frac{\alpha}{2} \\\\
+/// 1 & \frac{\alpha}{2} \le x \le 1 - \frac{\alpha}{2} \\\\
+/// \frac{1}{2}\left(1 - \cos\left(\frac{2\pi(1 - x)}{\alpha}\right)\right) & x > 1 - \frac{\alpha}{2}
+/// \end{cases}
+/// $$
+/// Such that $\alpha = 0$ gives the rectangular window, and $\alpha = 1$ the Hann window.
+/// 
+/// ## Inputs
+/// - `n`: the number of points
+/// - `alpha`: the fraction of the window in the tapers ($\alpha$), between 0 and 1
+/// - `sym`: symmetric or periodic variant
+/// 
+/// Returns the window.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::window::{ tukey, hann, Symmetry };
+/// let w = tukey(9, 0.5, Symmetry::Symmetric);
+/// let expected: Vec<f64> = vec![0.0, 0.5, 1.0, 1.0, 1.0, 1.0, 1.0, 0.5, 0.0];
+/// for (v, e) in w.iter().zip(&expected) {
+///     assert!((v - e).abs() < 1.0e-15);
+/// }
+/// 
+/// // The limit cases
+/// assert!(tukey(9, 0.0, Symmetry::Periodic).iter().all(|v| *v == 1.0));
+/// for (t, h) in tukey(9, 1.0, Symmetry::Periodic).iter().zip(&hann(9, Symmetry::Periodic)) {
+///     assert!((t - h).abs() < 1.0e-15);
+/// }
+/// ```
+pub fn tukey(n: usize, alpha: f64, sym: Symmetry) -> Vec<f64> {
+
+    assert!((0.0..=1.0).contains(&alpha), "alpha must be between 0 and 1!");
+
+    generate(n, sym, |k, m| {
+        let x: f64 = k as f64 / (m - 1) as f64;
+        if x < alpha / 2.0 {
+            0.5 * (1.0 - (TAU * x / alpha).cos())
+        } else if x > 1.0 - alpha / 2.0 {
+            0.5 * (1.0 - (TAU * (1.0 - x) / alpha).cos())
+        } else {
+            1.0
+        }
+    })
+}
+
+/// # Gaussian window
+/// 
+/// ## Definition
+/// The [Gaussian window](https://en.wikipedia.org/wiki/Window_function#Gaussian_window) is defined as:
+/// $$
+/// w(k) = \exp\left(-\frac{1}{2}\left(\frac{k - (M-1)/2}{\sigma}\right)^2\right)
+/// $$
+/// where $M$ is the length of the symmetric window.
+/// 
+/// ## Inputs
+/// - `n`: the number of points
+/// - `std`: the standard deviation, in samples ($\sigma$)
+/// - `sym`: symmetric or periodic variant
+/// 
+/// Returns the window.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::window::{ gaussian, Symmetry };
+/// let w = gaussian(5, 1.0, Symmetry::Symmetric);
+/// assert!((w[0] - (-2.0_f64).exp()).abs() < 1.0e-15);
+/// assert!((w[1] - (-0.5_f64).exp()).abs() < 1.0e-15);
+/// assert_eq!(w[2], 1.0);
+/// ```
+pub fn gaussian(n: usize, std: f64, sym: Symmetry) -> Vec<f64> {
+
+    generate(n, sym, |k, m| {
+        let x: f64 = (k as f64 - (m - 1) as f64 / 2.0) / std;
+        (-0.5 * x.powi(2)).exp()
+    })
+}
+
+/// # Dolph-Chebyshev window
+/// 
+/// ## Definition
+/// The [Dolph-Chebyshev window](https://en.wikipedia.org/wiki/Window_function#Dolph%E2%80%93Chebyshev_window)
+/// minimizes the width of the main lobe for a given side lobe attenuation, all side lobes having the same level.
+/// It is defined by its spectrum, from the Chebyshev polynomial $T_{M-1}$:
+/// $$
+/// W(k) = \frac{T_{M-1}\left(\beta\cos\left(\frac{\pi k}{M}\right)\right)}{T_{M-1}(\beta)},~~
+/// \beta = \cosh\left(\frac{1}{M-1}\cosh^{-1}\left(10^{A/20}\right)\right)
+/// $$
+/// which is brought back in the time domain with `fft`. The window is normalized to a maximum of 1.
+/// 
+/// ## Inputs
+/// - `n`: the number of points
+/// - `attenuation`: the attenuation of the side lobes, in decibels ($A$)
+/// - `sym`: symmetric or periodic variant
+/// 
+/// Returns the window.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::window::{ chebyshev, Symmetry };
+/// let w = chebyshev(5, 50.0, Symmetry::Symmetric);
+/// let expected: Vec<f64> = vec![0.2054942, 0.7010463, 1.0, 0.7010463, 0.2054942];
+/// for (v, e) in w.iter().zip(&expected) {
+///     assert!((v - e).abs() < 1.0e-6);
+/// }
+/// ```
+pub fn chebyshev(n: usize, attenuation: f64, sym: Symmetry) -> Vec<f64> {
+
+    let m: usize = match sym {
+        Symmetry::Symmetric => n,
+        Symmetry::Periodic => n + 1
+    };
+    if n <= 1 {
+        return vec![1.0; n];
+    }
+
+    // Sampling the spectrum from the Chebyshev polynomial
+    let order: f64 = (m - 1) as f64;
+    let beta: f64 = ((10.0_f64.powf(attenuation / 20.0)).acosh() / order).cosh();
+    let spectrum: Vec<f64> = (0..m).map(|k| {
+        let x: f64 = beta * (PI * k as f64 / m as f64).cos();
+        if x > 1.0 {
+            (order * x.acosh()).cosh()
+        } else if x < -1.0 {
+            (1.0 - 2.0 * ((m - 1) % 2) as f64) * (order * (-x).acosh()).cosh()
+        } else {
+            (order * x.acos()).cos()
+        }
+    }).collect();
+
+    // Back in the time domain, the even lengths need a half sample shift
+    let half: Vec<f64> = if !m.is_multiple_of(2) {
+        fft(&spectrum).iter().take(m.div_ceil(2)).map(|val| val.re).collect()
+    } else {
+        let shifted: Vec<Complex64> = spectrum.iter().enumerate().map(|(k, val)| {
+            Complex64::from_polar(*val, PI * k as f64 / m as f64)
+        }).collect();
+        fft(&shifted).iter().take(m / 2 + 1).map(|val| val.re).collect()
+    };
+
+    // Mirroring around the center
+    let mut res: Vec<f64> = half[1..].iter().rev().copied().collect();
+    if !m.is_multiple_of(2) {
+        res.extend_from_slice(&half);
+    } else {
+        res.extend_from_slice(&half[1..]);
+    }
+
+    let max: f64 = res.iter().fold(f64::MIN, |acc, val| acc.max(*val));
+    res.truncate(n);
+    res.iter().map(|val| val / max).collect()
+}
+
+/// # Discrete prolate spheroidal sequences
+/// 
+/// ## Definition
+/// The [DPSS](https://en.wikipedia.org/wiki/Multitaper), or Slepian sequences, are the sequences of length $N$
+/// whose spectrum is the most concentrated in the band $[-W, W]$, with $W = NW / N$ in cycles per sample.
+/// They are the eigenvectors, sorted by decreasing eigenvalue, of the symmetric tridiagonal matrix:
+/// $$
+/// T_{i,i} = \left(\frac{N - 1 - 2i}{2}\right)^2\cos(2\pi W),~~ T_{i,i+1} = \frac{(i+1)(N-i-1)}{2}
+/// $$
+/// The eigenvalues are found by bisection on Sturm sequences, and the eigenvectors by inverse iteration.
+/// 
+/// The sequences are normalized to unit energy, the symmetric ones having a positive sum and the
+/// antisymmetric ones starting with a positive lobe. They are the tapers used by multitaper spectral estimation,
+/// where about $2NW - 1$ of them are well concentrated.
+/// 
+/// ## Inputs
+/// - `n`: the number of points ($N$)
+/// - `nw`: the time-bandwidth product ($NW$)
+/// - `k`: the number of sequences to compute
+/// 
+/// Returns the `k` first sequences.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::window::dpss;
+/// let tapers = dpss(64, 3.0, 5);
+/// assert_eq!(tapers.len(), 5);
+/// 
+/// // The tapers are orthonormal
+/// for (i, t1) in tapers.iter().enumerate() {
+///     for (j, t2) in tapers.iter().enumerate() {
+///         let dot: f64 = t1.iter().zip(t2).map(|(a, b)| a * b).sum();
+///         let expected: f64 = if i == j { 1.0 } else { 0.0 };
+///         assert!((dot - expected).abs() < 1.0e-10);
+///     }
+/// }
+/// 
+/// // Alternatively symmetric and antisymmetric
+/// assert!((tapers[0][10] - tapers[0][53]).abs() < 1.0e-10);
+/// assert!((tapers[1][10] + tapers[1][53]).abs() < 1.0e-10);
+/// ```
+pub fn dpss(n: usize, nw: f64, k: usize) -> Vec<Vec<f64>> {
+
+    assert!(k <= n, "There cannot be more sequences than points!");
+
+    let w: f64 = nw / n as f64;
+    let diag: Vec<f64> = (0..n).map(|i| ((n as f64 - 1.0 - 2.0 * i as f64) / 2.0).powi(2) * (TAU * w).cos()).collect();
+    let off: Vec<f64> = (1..n).map(|i| (i * (n - i)) as f64 / 2.0).collect();
+
+    // Gershgorin bounds of the spectrum
+    let (mut low, mut high): (f64, f64) = (f64::MAX, f64::MIN);
+    for (i, d) in diag.iter().enumerate() {
+        let radius: f64 = if i > 0 { off[i - 1] } else { 0.0 } + off.get(i).copied().unwrap_or(0.0);
+        low = low.min(d - radius);
+        high = high.max(d + radius);
+    }
+
+    (0..k).map(|order| {
+
+        // Bisection on the number of eigenvalues below the guess
+        let target: usize = n - 1 - order;
+        let (mut a, mut b): (f64, f64) = (low, high);
+        for _ in 0..200 {
+            let mid: f64 = 0.5 * (a + b);
+            if mid <= a || mid >= b {
+                break;
+            }
+            if sturm_count(&diag, &off, mid) > target {
+                b = mid;
+            } else {
+                a = mid;
+            }
+        }
+        let lambda: f64 = 0.5 * (a + b);
+
+        // Inverse iteration from an arbitrary start
+        let mut v: Vec<f64> = (0..n).map(|i| (i as f64 * 0.618_033_988_749_895).fract() - 0.5).collect();
+        for _ in 0..3 {
+            v = solve_shifted(&diag, &off, lambda, &v);
+            let norm: f64 = v.iter().map(|x| x.powi(2)).sum::<f64>().sqrt();
+            v.iter_mut().for_each(|x| *x /= norm);
+        }
+
+        // Sign convention
+        let sign: f64 = if order % 2 == 0 {
+            v.iter().sum()
+        } else {
+            v.iter().enumerate().map(|(i, x)| (n as f64 - 1.0 - 2.0 * i as f64) * x).sum()
+        };
+        if sign < 0.0 {
+            v.iter_mut().for_each(|x| *x = -*x);
+        }
+
+        v
+    }).collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Coherent gain
+/// 
+/// ## Definition
+/// The coherent gain is the mean of the window:
+/// $$
+/// G = \frac{1}{N}\sum_k w(k)
+/// $$
+/// which is the factor applied by the window to the amplitude of a sinusoid at a bin frequency.
+/// Dividing a windowed spectrum by $G$ restores the amplitudes.
+/// 
+/// ## Inputs
+/// - `window`: the window
+/// 
+/// Returns the coherent gain of the window.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::window::{ hamming, coherent_gain, Symmetry };
+/// let w = hamming(100, Symmetry::Periodic);
+/// assert!((coherent_gain(&w) - 0.54).abs() < 1.0e-12);
+/// ```
+pub fn coherent_gain(window: &[f64]) -> f64 {
+    window.iter().sum::<f64>() / window.len() as f64
+}
+
+/// # Equivalent noise bandwidth
+/// 
+/// ## Definition
+/// The [equivalent noise bandwidth](https://en.wikipedia.org/wiki/Window_function#Spectral_analysis)
+/// is the width of the rectangular filter that lets through the same noise power as the window:
+/// $$
+/// \mathrm{ENBW} = N\frac{\sum_k w(k)^2}{\left(\sum_k w(k)\right)^2}
+/// $$
+/// It is expressed in frequency bins; multiply by $f_s / N$ to get it in the units of the sampling rate $f_s$.
+/// 
+/// ## Inputs
+/// - `window`: the window
+/// 
+/// Returns the equivalent noise bandwidth, in bins.
+/// 
+/// ## Example
+/// ```
+/// # use scilib::signal::window::{ hann, blackman_harris, enbw, Symmetry };
+/// // The rectangular window has the narrowest possible bandwidth
+/// assert!((enbw(&[1.0; 10]) - 1.0).abs() < 1.0e-15);
+/// 
+/// let w = blackman_harris(256, Symmetry::Periodic);
+/// assert!((enbw(&w) - 2.0044).abs() < 1.0e-4);
+/// ```
+pub fn enbw(window: &[f64]) -> f64 {
+
+    let sum: f64 = window.iter().sum();
+    let sum_sq: f64 = window.iter().map(|val| val.powi(2)).sum();
+
+    window.len() as f64 * sum_sq / sum.powi(2)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// # Window from a point-wise definition
+/// 
+/// The closure receives the index of the point and the length of the symmetric window it is taken from.
+fn generate<F>(n: usize, sym: Symmetry, f: F) -> Vec<f64>
+where F: Fn(usize, usize) -> f64 {
+
+    let m: usize = match sym {
+        Symmetry::Symmetric => n,
+        Symmetry::Periodic => n + 1
+    };
+
+    // A single point cannot be tapered
+    if n <= 1 {
+        return vec![1.0; n];
+    }
+
+    (0..n).map(|k| f(k, m)).collect()
+}
+
+/// # Sum of cosines window
+fn general_cosine(n: usize, coefs: &[f64], sym: Symmetry) -> Vec<f64> {
+
+    generate(n, sym, |k, m| {
+        let x: f64 = TAU * k as f64 / (m - 1) as f64;
+        coefs.iter().enumerate().fold(0.0, |res, (j, a)| {
+            let sign: f64 = if j % 2 == 0 { 1.0 } else { -1.0 };
+            res + sign * a * (j as f64 * x).cos()
+        })
+    })
+}
+
+/// # Number of eigenvalues of a symmetric tridiagonal matrix below `x`
+fn sturm_count(diag: &[f64], off: &[f64], x: f64) -> usize {
+
+    let mut count: usize = 0;
+    let mut q: f64 = 1.0;
+
+    for (i, d) in diag.iter().enumerate() {
+        let e2: f64 = if i > 0 { off[i - 1].powi(2) } else { 0.0 };
+        q = d - x - if i > 0 { e2 / q } else { 0.0 };
+        if q == 0.0 {
+            q = -f64::EPSILON * (d.abs() + x.abs()).max(f64::MIN_POSITIVE);
+        }
+        if q < 0.0 {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+/// # Solving $(T - \lambda I)x = b$ for a symmetric tridiagonal $T$
+fn solve_shifted(diag: &[f64], off: &[f64], lambda: f64, b: &[f64]) -> Vec<f64> {
+
+    let n: usize = diag.len();
+    let tiny: f64 = f64::EPSILON * diag.iter().chain(off).fold(0.0_f64, |m, v| m.max(v.abs())).max(1.0);
+
+    // Forward elimination of the Thomas algorithm
+    let mut c: Vec<f64> = vec![0.0; n];
+    let mut x: Vec<f64> = vec![0.0; n];
+    let mut pivot: f64 = diag[0] - lambda;
+    for i in 0..n {
+        if i > 0 {
+            pivot = diag[i] - lambda - off[i - 1] * c[i - 1];
+        }
+        if pivot.abs() < tiny {
+            pivot = tiny;
+        }
+        c[i] = if i + 1 < n { off[i] / pivot } else { 0.0 };
+        x[i] = (b[i] - if i > 0 { off[i - 1] * x[i - 1] } else { 0.0 }) / pivot;
+    }
+
+    // Back substitution
+    for i in (0..n.saturating_sub(1)).rev() {
+        x[i] -= c[i] * x[i + 1];
+    }
+
+    x
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////